@@ -0,0 +1,11 @@
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+
+export default component$(() => {
+	return (
+		<DummyComp.Root __qwik_analyzer_has_Checkbox_Description={false} __qwik_analyzer_has_Description={false} __qwik_analyzer_has_Title={true}>
+			<button type="button">Some trigger</button>
+			<DummyComp.Title />
+		</DummyComp.Root>
+	);
+});
@@ -0,0 +1,11 @@
+import { component$ } from "@builder.io/qwik";
+import { Field } from "../components/field";
+
+export default component$(() => {
+	return (
+		<Field.Root __qwik_analyzer_has_Description_or_HelperText={true}>
+			<input type="text" />
+			<Field.HelperText>At least 8 characters</Field.HelperText>
+		</Field.Root>
+	);
+});
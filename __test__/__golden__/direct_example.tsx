@@ -0,0 +1,22 @@
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+
+export default component$(() => {
+	return (
+		<DummyComp.Root __qwik_analyzer_has_Checkbox_Description={false} __qwik_analyzer_has_Description={true} __qwik_analyzer_has_Title={true}>
+			<div
+				style={{
+					display: "flex",
+					alignItems: "center",
+					gap: "8px",
+					marginBottom: "8px",
+				}}
+			>
+				<button type="button">Some trigger</button>
+				<label>I accept the Terms and Conditions</label>
+			</div>
+			<DummyComp.Description />
+			<DummyComp.Title />
+		</DummyComp.Root>
+	);
+});
@@ -0,0 +1,20 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { Description } from "./description";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { Title } from "./title";
+import { Checkbox } from "@kunai-consulting/qwik";
+
+export const Root = component$((props) => {
+	const isDescription = isComponentPresent(Description, props.__qwik_analyzer_has_Description);
+	const isTitle = isComponentPresent(Title, props.__qwik_analyzer_has_Title);
+	const isCheckbox = isComponentPresent(Checkbox.Description, props.__qwik_analyzer_has_Checkbox_Description);
+
+	return (
+		<div>
+			<Slot />
+			<p>Description present: {isDescription ? "true" : "false"}</p>
+			<p>Title present: {isTitle ? "true" : "false"}</p>
+			<p>Checkbox description present: {isCheckbox ? "true" : "false"}</p>
+		</div>
+	);
+});
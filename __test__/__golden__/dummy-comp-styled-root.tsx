@@ -0,0 +1,22 @@
+import { component$, Slot, type Component } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { Description } from "./description";
+
+const withStyles = <T,>(Wrapped: Component<T>, className = "styled"): Component<T> => {
+	console.log(`styling with ${className}`);
+	return Wrapped;
+};
+
+export const StyledRoot = withStyles(
+	component$((props) => {
+		const isDescription = isComponentPresent(Description, props.__qwik_analyzer_has_Description);
+
+		return (
+			<div>
+				<Slot />
+				<p>Description present: {isDescription ? "true" : "false"}</p>
+			</div>
+		);
+	}),
+	"root",
+);
@@ -0,0 +1,14 @@
+import { component$, Slot, type JSXOutput } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { Description } from "./description";
+
+export const TypedRoot = component$((props): JSXOutput => {
+	const isDescription = isComponentPresent(Description, props.__qwik_analyzer_has_Description);
+
+	return (
+		<div>
+			<Slot />
+			<p>Description present: {isDescription ? "true" : "false"}</p>
+		</div>
+	);
+});
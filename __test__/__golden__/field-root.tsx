@@ -0,0 +1,14 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { Description } from "./description";
+import { HelperText } from "./helper-text";
+
+export const Root = component$((props) => {
+	const hasHint = isComponentPresent([Description, HelperText], props.__qwik_analyzer_has_Description_or_HelperText);
+
+	return (
+		<div aria-describedby={hasHint ? "field-hint" : undefined}>
+			<Slot />
+		</div>
+	);
+});
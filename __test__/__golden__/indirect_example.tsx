@@ -0,0 +1,13 @@
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+import { Heyo } from "./heyo";
+
+export default component$(() => {
+	return (
+		<DummyComp.Root __qwik_analyzer_has_Checkbox_Description={false} __qwik_analyzer_has_Description={true} __qwik_analyzer_has_Title={true}>
+			<button type="button">Some trigger</button>
+			<Heyo />
+			<DummyComp.Title />
+		</DummyComp.Root>
+	);
+});
@@ -0,0 +1,38 @@
+import { test, expect } from "vitest";
+import { classifyFile } from "../index.cjs";
+import path from "node:path";
+import { fileURLToPath } from "node:url";
+
+const __filename = fileURLToPath(import.meta.url);
+const __dirname = path.dirname(__filename);
+
+const srcDir = path.resolve(__dirname, "../qwik-app/src");
+
+test("a Root that checks for its children is a definition", () => {
+	expect(classifyFile(path.join(srcDir, "components/checkbox-kit/checkbox-root.tsx"))).toBe(
+		"definition",
+	);
+	expect(classifyFile(path.join(srcDir, "examples/toggle.tsx"))).toBe("definition");
+});
+
+test("a page rendering such a Root is a consumer", () => {
+	expect(classifyFile(path.join(srcDir, "examples/checkbox_kit_example.tsx"))).toBe("consumer");
+	expect(classifyFile(path.join(srcDir, "examples/single_file_kit_example.tsx"))).toBe("consumer");
+});
+
+test("a Root composed from another library's Root is both", () => {
+	expect(classifyFile(path.join(srcDir, "components/consent-kit/root.tsx"))).toBe("both");
+});
+
+test("files without presence checks or Roots are neither", () => {
+	expect(classifyFile(path.join(srcDir, "components/consent-kit/note.tsx"))).toBe("neither");
+	expect(classifyFile(path.join(srcDir, "components/checkbox-kit/index.ts"))).toBe("neither");
+	// A locally declared isComponentPresent isn't the analyzer's
+	expect(classifyFile(path.join(srcDir, "examples/local_presence.tsx"))).toBe("neither");
+});
+
+test("classifyFile throws for a file that can't be read", () => {
+	expect(() => classifyFile(path.join(srcDir, "examples/missing.tsx"))).toThrow(
+		/Classifying file failed/,
+	);
+});
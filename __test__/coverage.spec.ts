@@ -0,0 +1,115 @@
+import { test, expect, beforeAll, afterAll } from "vitest";
+import { presenceCoverage, presenceCoverageJson } from "../index.cjs";
+import fs from "node:fs";
+import path from "node:path";
+import { fileURLToPath } from "node:url";
+
+const __filename = fileURLToPath(import.meta.url);
+const __dirname = path.dirname(__filename);
+
+let tempDir: string;
+
+function writeFile(relativePath: string, contents: string) {
+	const filePath = path.join(tempDir, relativePath);
+	fs.mkdirSync(path.dirname(filePath), { recursive: true });
+	fs.writeFileSync(filePath, contents.trim());
+}
+
+function page(children: string, extraImport = "") {
+	return `
+import { component$ } from "@builder.io/qwik";
+import { Kit } from "../components/kit";
+${extraImport}
+
+export default component$(() => {
+  return <Kit.Root>${children}</Kit.Root>;
+});
+  `;
+}
+
+beforeAll(() => {
+	tempDir = fs.mkdtempSync(path.join(__dirname, "temp-test-"));
+
+	writeFile("package.json", JSON.stringify({ name: "coverage-fixture" }));
+	writeFile(
+		"src/components/kit/index.ts",
+		`
+import { Description } from "./description";
+import { Root } from "./root";
+import { Title } from "./title";
+
+export const Kit = { Root, Description, Title };
+  `,
+	);
+	writeFile(
+		"src/components/kit/root.tsx",
+		`
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+import { Description } from "./description";
+import { Title } from "./title";
+
+export const Root = component$(() => {
+  const hasDescription = isComponentPresent(Description);
+  const hasTitle = isComponentPresent(Title);
+  return <div data-described={hasDescription} data-titled={hasTitle}><Slot /></div>;
+});
+  `,
+	);
+	writeFile(
+		"src/components/kit/description.tsx",
+		`
+import { component$ } from "@builder.io/qwik";
+
+export const Description = component$(() => <p>Description</p>);
+  `,
+	);
+	writeFile(
+		"src/components/kit/title.tsx",
+		`
+import { component$ } from "@builder.io/qwik";
+
+export const Title = component$(() => <h3>Title</h3>);
+  `,
+	);
+
+	writeFile("src/routes/both.tsx", page("<Kit.Title /><Kit.Description />"));
+	writeFile("src/routes/description.tsx", page("<Kit.Description />"));
+	writeFile("src/routes/empty.tsx", page("<input type=\"checkbox\" />"));
+	writeFile(
+		"src/routes/unknown.tsx",
+		page("<Missing /><Kit.Title />", 'import { Missing } from "./missing";'),
+	);
+
+	// Dependencies are never part of the report
+	writeFile("node_modules/ext/index.tsx", page("<Kit.Description />"));
+});
+
+afterAll(() => {
+	if (tempDir) {
+		fs.rmSync(tempDir, { recursive: true, force: true });
+	}
+});
+
+test("presenceCoverage tallies each Root's checks across a project", () => {
+	const report = presenceCoverage([tempDir]);
+
+	expect(report.filesAnalyzed).toBe(8);
+	expect(report.components).toEqual([
+		{
+			rootFile: path.join(tempDir, "src/components/kit/root.tsx"),
+			usages: 4,
+			children: [
+				{ component: "Description", alternatives: [], present: 2, absent: 1, unknown: 1 },
+				{ component: "Title", alternatives: [], present: 2, absent: 2, unknown: 0 },
+			],
+		},
+	]);
+});
+
+test("presenceCoverageJson serializes the same report", () => {
+	const paths = [path.join(tempDir, "src/routes")];
+
+	expect(JSON.parse(presenceCoverageJson(paths))).toEqual(presenceCoverage(paths));
+	expect(presenceCoverage(paths).filesAnalyzed).toBe(4);
+});
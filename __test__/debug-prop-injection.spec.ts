@@ -32,7 +32,7 @@ export const MyTestChild = component$(() => {
     path.join(componentsDir, "my-test-root.tsx"),
     `
 import { component$ } from "@builder.io/qwik";
-import { isComponentPresent } from "../../utils/qwik-analyzer";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
 import { MyTestChild } from "./my-test-child";
 
 export const MyTestRoot = component$(() => {
@@ -178,7 +178,7 @@ export const head: DocumentHead = {
     
     const myTestRootCode = `
 import { component$ } from "@builder.io/qwik";
-import { isComponentPresent } from "../../utils/qwik-analyzer";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
 import { MyTestChild } from "./my-test-child";
 
 export const MyTestRoot = component$(() => {
@@ -0,0 +1,69 @@
+import { test, expect } from "vitest";
+import { analyzeFileChanged, collectDependencies, dependentsOf } from "../index.cjs";
+import path from "node:path";
+import { fileURLToPath } from "node:url";
+
+const __filename = fileURLToPath(import.meta.url);
+const __dirname = path.dirname(__filename);
+
+const srcDir = path.resolve(__dirname, "../qwik-app/src");
+
+function dependenciesOf(file: string): string[] {
+	return collectDependencies(path.join(srcDir, file)).map((dependency) =>
+		path.relative(srcDir, dependency),
+	);
+}
+
+test("a page depends on the barrel and the files defining the components it renders", () => {
+	expect(dependenciesOf("examples/typed_barrel_example.tsx")).toEqual([
+		"components/tip-kit/hint.tsx",
+		"components/tip-kit/index.ts",
+		"components/tip-kit/root.tsx",
+	]);
+});
+
+test("components rendered by a library's own components are followed", () => {
+	// The consent Root renders CheckboxKit, so changes there can change the page's transformation
+	expect(dependenciesOf("examples/consent_example.tsx")).toEqual([
+		"components/checkbox-kit/checkbox-root.tsx",
+		"components/checkbox-kit/description.tsx",
+		"components/checkbox-kit/index.ts",
+		"components/consent-kit/note.tsx",
+		"components/consent-kit/root.tsx",
+	]);
+});
+
+test("files rendering no imported components have no dependencies", () => {
+	expect(dependenciesOf("components/consent-kit/note.tsx")).toEqual([]);
+});
+
+test("dependentsOf lists the pages a changed child's file affects", () => {
+	for (const page of [
+		"examples/checkbox_kit_example.tsx",
+		"examples/consent_example.tsx",
+		"examples/typed_barrel_example.tsx",
+	]) {
+		collectDependencies(path.join(srcDir, page));
+	}
+	const dependentsOfFile = (file: string) =>
+		dependentsOf(path.join(srcDir, file)).map((dependent) => path.relative(srcDir, dependent));
+
+	expect(dependentsOfFile("components/checkbox-kit/description.tsx")).toEqual([
+		"examples/checkbox_kit_example.tsx",
+		"examples/consent_example.tsx",
+	]);
+	expect(dependentsOfFile("components/tip-kit/hint.tsx")).toEqual([
+		"examples/typed_barrel_example.tsx",
+	]);
+
+	analyzeFileChanged(path.join(srcDir, "examples/consent_example.tsx"), "delete");
+	expect(dependentsOfFile("components/checkbox-kit/description.tsx")).toEqual([
+		"examples/checkbox_kit_example.tsx",
+	]);
+});
+
+test("collectDependencies throws for a file that can't be read", () => {
+	expect(() => collectDependencies(path.join(srcDir, "examples/missing.tsx"))).toThrow(
+		/Collecting dependencies failed/,
+	);
+});
@@ -0,0 +1,595 @@
+import { test, expect, describe, beforeAll, afterAll } from "vitest";
+import {
+	analyzeFile,
+	analyzeAndTransformCode,
+	configureAnalyzer,
+	transformWithDiagnostics,
+} from "../index.cjs";
+import fs from "node:fs";
+import path from "node:path";
+import { fileURLToPath } from "node:url";
+
+const __filename = fileURLToPath(import.meta.url);
+const __dirname = path.dirname(__filename);
+
+let tempDir: string;
+
+beforeAll(() => {
+	tempDir = fs.mkdtempSync(path.join(__dirname, "temp-test-"));
+});
+
+afterAll(() => {
+	if (tempDir) {
+		fs.rmSync(tempDir, { recursive: true, force: true });
+	}
+});
+
+describe("Diagnostics", () => {
+	test("isComponentPresent with a string argument reports a diagnostic and is not transformed", () => {
+		const code = `
+import { component$ } from "@builder.io/qwik";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+export const Root = component$(() => {
+  const isDescription = isComponentPresent("Description");
+  return <div>Test</div>;
+});
+    `.trim();
+
+		const testFilePath = path.join(tempDir, "string-argument.tsx");
+		fs.writeFileSync(testFilePath, code);
+
+		const result = analyzeFile(testFilePath);
+		expect(result.transformations).toHaveLength(0);
+		expect(result.diagnostics).toHaveLength(1);
+		expect(result.diagnostics[0].code).toBe("QA004");
+		expect(result.diagnostics[0].message).toContain(
+			"isComponentPresent expects a component reference",
+		);
+		expect(
+			code.slice(result.diagnostics[0].start, result.diagnostics[0].end),
+		).toBe('"Description"');
+
+		expect(analyzeAndTransformCode(code, testFilePath)).toBe(code);
+	});
+
+	test("transformWithDiagnostics returns the diagnostics with the transformed code", () => {
+		const code = `
+import { component$ } from "@builder.io/qwik";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+export const Root = component$(() => {
+  const isDescription = isComponentPresent("Description");
+  return <div>Test</div>;
+});
+    `.trim();
+
+		const output = transformWithDiagnostics(code, path.join(tempDir, "with-diagnostics.tsx"));
+		expect(output.code).toBe(code);
+		expect(output.diagnostics.map((diagnostic) => diagnostic.code)).toEqual(["QA004"]);
+	});
+
+	test("isComponentPresent without arguments reports a diagnostic and is left untouched", () => {
+		const examplePath = path.resolve(
+			__dirname,
+			"../qwik-app/src/examples/empty_presence_call.tsx",
+		);
+		const code = fs.readFileSync(examplePath, "utf-8");
+
+		const result = analyzeFile(examplePath);
+		const missing = result.diagnostics.filter((d) => d.code === "QA009");
+		expect(missing).toHaveLength(1);
+		expect(code.slice(missing[0].start, missing[0].end)).toBe("isComponentPresent()");
+
+		const transformed = analyzeAndTransformCode(code, examplePath);
+		expect(transformed).toContain("const isAnything = isComponentPresent();");
+		expect(transformed).toContain(
+			"isComponentPresent(Description, props.__qwik_analyzer_has_Description)",
+		);
+	});
+
+	test("sources over the size limit are skipped with a diagnostic", () => {
+		const code = `
+import { component$ } from "@builder.io/qwik";
+import { Description } from "./description";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+export const Root = component$(() => {
+  const isDescription = isComponentPresent(Description);
+  return <div>Test</div>;
+});
+${"// generated padding\n".repeat(150_000)}
+    `.trim();
+
+		const testFilePath = path.join(tempDir, "oversized.tsx");
+		fs.writeFileSync(testFilePath, code);
+
+		const result = analyzeFile(testFilePath);
+		expect(result.transformations).toHaveLength(0);
+		expect(result.diagnostics).toHaveLength(1);
+		expect(result.diagnostics[0].code).toBe("QA006");
+		expect(result.diagnostics[0].message).toContain("Skipped analysis");
+		expect(analyzeAndTransformCode(code, testFilePath)).toBe(code);
+
+		configureAnalyzer({ maxSourceBytes: 0 });
+		try {
+			expect(analyzeFile(testFilePath).transformations).not.toHaveLength(0);
+		} finally {
+			configureAnalyzer({});
+		}
+	});
+
+	test("unresolved local imports and external compound components carry their codes", () => {
+		const packageDir = path.join(tempDir, "node_modules", "ext-kit");
+		fs.mkdirSync(packageDir, { recursive: true });
+		fs.writeFileSync(
+			path.join(packageDir, "package.json"),
+			JSON.stringify({ name: "ext-kit", main: "index.js" }),
+		);
+		fs.writeFileSync(
+			path.join(packageDir, "index.js"),
+			"export const Kit = { Root: () => null };",
+		);
+
+		const code = `
+import { component$ } from "@builder.io/qwik";
+import { Kit } from "ext-kit";
+import { Missing } from "./missing";
+
+export default component$(() => {
+  return (
+    <Kit.Root>
+      <Missing />
+    </Kit.Root>
+  );
+});
+    `.trim();
+
+		const testFilePath = path.join(tempDir, "imports.tsx");
+		fs.writeFileSync(testFilePath, code);
+
+		const result = analyzeFile(testFilePath);
+		expect(result.diagnostics.map((diagnostic) => diagnostic.code).sort()).toEqual([
+			"QA001",
+			"QA002",
+		]);
+
+		const unresolved = result.diagnostics.find((diagnostic) => diagnostic.code === "QA001");
+		expect(code.slice(unresolved?.start, unresolved?.end)).toBe("Missing");
+
+		const external = result.diagnostics.find((diagnostic) => diagnostic.code === "QA002");
+		expect(code.slice(external?.start, external?.end)).toBe("Kit.Root");
+	});
+
+	test("repeated checks for the same child in one component carry QA003", () => {
+		const code = `
+import { component$ } from "@builder.io/qwik";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+import { Description } from "./description";
+
+export const Root = component$(() => {
+  const first = isComponentPresent(Description);
+  const second = isComponentPresent(Description);
+  return <div>{first && second}</div>;
+});
+
+export const Other = component$(() => {
+  const own = isComponentPresent(Description);
+  return <div>{own}</div>;
+});
+    `.trim();
+
+		const testFilePath = path.join(tempDir, "duplicate.tsx");
+		fs.writeFileSync(testFilePath, code);
+
+		const result = analyzeFile(testFilePath);
+		expect(result.diagnostics).toHaveLength(1);
+		expect(result.diagnostics[0].code).toBe("QA003");
+		expect(
+			code.slice(result.diagnostics[0].start, result.diagnostics[0].end),
+		).toBe("isComponentPresent(Description)");
+		expect(result.diagnostics[0].start).toBe(
+			code.indexOf("isComponentPresent(Description)", code.indexOf("second")),
+		);
+	});
+
+	test("checks naming one default export under two local names carry QA003", () => {
+		const code = `
+import { component$ } from "@builder.io/qwik";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+import Caption from "../../qwik-app/src/components/legend-kit/legend";
+import Legend from "../../qwik-app/src/components/legend-kit/legend";
+
+export const Root = component$(() => {
+  const hasCaption = isComponentPresent(Caption);
+  const hasLegend = isComponentPresent(Legend);
+  return <fieldset>{hasCaption && hasLegend}</fieldset>;
+});
+    `.trim();
+
+		const testFilePath = path.join(tempDir, "duplicate-default.tsx");
+		fs.writeFileSync(testFilePath, code);
+
+		const result = analyzeFile(testFilePath);
+		expect(result.diagnostics).toHaveLength(1);
+		expect(result.diagnostics[0].code).toBe("QA003");
+		expect(
+			code.slice(result.diagnostics[0].start, result.diagnostics[0].end),
+		).toBe("isComponentPresent(Legend)");
+	});
+
+	test("a local name imported from two modules carries QA007 and resolves through the value import", () => {
+		const examplePath = path.resolve(
+			__dirname,
+			"../qwik-app/src/examples/ambiguous_import_example.tsx",
+		);
+		const code = fs.readFileSync(examplePath, "utf-8");
+
+		const result = analyzeFile(examplePath);
+		const ambiguous = result.diagnostics.filter((d) => d.code === "QA007");
+		expect(ambiguous).toHaveLength(1);
+		expect(code.slice(ambiguous[0].start, ambiguous[0].end)).toBe("DummyComp");
+		expect(ambiguous[0].start).toBe(code.indexOf("{ DummyComp }") + 2);
+
+		// The type-only import from ../components/field is ignored for resolution
+		expect(analyzeAndTransformCode(code, examplePath)).toContain(
+			"__qwik_analyzer_has_Description={true}",
+		);
+		expect(analyzeAndTransformCode(code, examplePath)).not.toContain(
+			"HelperText",
+		);
+	});
+
+	test("strict scoping reports children outside their Root and module-scan matches with QA008", () => {
+		const examplesDir = path.resolve(__dirname, "../qwik-app/src/examples");
+		const siblingPath = path.join(examplesDir, "sibling_example.tsx");
+		const deepModulePath = path.join(examplesDir, "deep_module_example.tsx");
+		const directPath = path.join(examplesDir, "direct_example.tsx");
+		const siblingCode = fs.readFileSync(siblingPath, "utf-8");
+
+		// The default heuristics count a sibling of the Root as present
+		expect(analyzeAndTransformCode(siblingCode, siblingPath)).toContain(
+			"__qwik_analyzer_has_Description={true}",
+		);
+
+		configureAnalyzer({ strictScoping: true });
+		try {
+			const sibling = analyzeFile(siblingPath);
+			const unscoped = sibling.diagnostics.filter((d) => d.code === "QA008");
+			expect(unscoped).toHaveLength(1);
+			expect(siblingCode.slice(unscoped[0].start, unscoped[0].end)).toBe(
+				"DummyComp.Description",
+			);
+			expect(
+				sibling.presenceChecks.find((c) => c.component === "Description")
+					?.presence,
+			).toBe("unknown");
+			expect(analyzeAndTransformCode(siblingCode, siblingPath)).not.toContain(
+				"__qwik_analyzer_has_Description",
+			);
+
+			const deepModule = analyzeFile(deepModulePath);
+			expect(deepModule.diagnostics.some((d) => d.code === "QA008")).toBe(true);
+			expect(deepModule.presenceChecks).toEqual([
+				expect.objectContaining({ component: "Item", presence: "unknown" }),
+			]);
+
+			// Children inside their Root are unaffected
+			const direct = analyzeFile(directPath);
+			expect(direct.diagnostics.filter((d) => d.code === "QA008")).toHaveLength(0);
+			expect(
+				direct.presenceChecks.find((c) => c.component === "Description")
+					?.presence,
+			).toBe("present");
+		} finally {
+			configureAnalyzer({});
+		}
+	});
+
+	test("imports resolving outside the configured sandboxRoot are refused unread", () => {
+		const appRoot = path.join(tempDir, "sandboxed-app");
+		const outsideDir = path.join(tempDir, "outside-kit");
+		fs.mkdirSync(path.join(appRoot, "src"), { recursive: true });
+		fs.mkdirSync(outsideDir, { recursive: true });
+
+		fs.writeFileSync(
+			path.join(appRoot, "package.json"),
+			JSON.stringify({ name: "sandboxed-app" }),
+		);
+		fs.writeFileSync(
+			path.join(outsideDir, "description.tsx"),
+			`
+import { component$ } from "@builder.io/qwik";
+
+export const Description = component$(() => <p>Description</p>);
+    `.trim(),
+		);
+		fs.writeFileSync(
+			path.join(outsideDir, "root.tsx"),
+			`
+import { component$, Slot } from "@builder.io/qwik";
+import { Description } from "./description";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+export const Root = component$(() => {
+  const hasDescription = isComponentPresent(Description);
+  return <div><Slot /></div>;
+});
+    `.trim(),
+		);
+
+		const code = `
+import { component$ } from "@builder.io/qwik";
+import { Root } from "../../outside-kit/root";
+import { Description } from "../../outside-kit/description";
+
+export default component$(() => {
+  return (
+    <Root>
+      <Description />
+    </Root>
+  );
+});
+    `.trim();
+
+		const testFilePath = path.join(appRoot, "src", "page.tsx");
+		fs.writeFileSync(testFilePath, code);
+
+		expect(analyzeAndTransformCode(code, testFilePath)).toContain(
+			"<Root __qwik_analyzer_has_Description={true}>",
+		);
+
+		configureAnalyzer({ sandboxRoot: appRoot });
+		try {
+			expect(analyzeAndTransformCode(code, testFilePath)).toBe(code);
+
+			const result = analyzeFile(testFilePath);
+			const refused = result.diagnostics.filter((d) => d.code === "QA010");
+			expect(refused.map((d) => code.slice(d.start, d.end)).sort()).toEqual([
+				"Description",
+				"Root",
+			]);
+			expect(refused[0].message).toContain("outside the sandbox root");
+		} finally {
+			configureAnalyzer({});
+		}
+	});
+
+	test("scanning a module directory skips symlinked directories", () => {
+		const appRoot = path.join(tempDir, "scanned-app");
+		const kitDir = path.join(appRoot, "src", "kit");
+		const outsideParts = path.join(tempDir, "outside-parts");
+		fs.mkdirSync(kitDir, { recursive: true });
+		fs.mkdirSync(outsideParts, { recursive: true });
+
+		fs.writeFileSync(
+			path.join(appRoot, "package.json"),
+			JSON.stringify({ name: "scanned-app" }),
+		);
+		fs.writeFileSync(
+			path.join(kitDir, "index.ts"),
+			`export * from "./parts/item";\nexport * from "./parts/root";\n`,
+		);
+		fs.writeFileSync(
+			path.join(outsideParts, "item.tsx"),
+			`
+import { component$, Slot } from "@builder.io/qwik";
+
+export const Item = component$(() => <li><Slot /></li>);
+    `.trim(),
+		);
+		fs.writeFileSync(
+			path.join(outsideParts, "root.tsx"),
+			`
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+import { Item } from "./item";
+
+export const Root = component$(() => {
+  const hasItem = isComponentPresent(Item);
+  return <ul><Slot /></ul>;
+});
+    `.trim(),
+		);
+		// The parts live outside the app, linked into the kit
+		fs.symlinkSync(outsideParts, path.join(kitDir, "parts"), "dir");
+
+		const code = `
+import { component$ } from "@builder.io/qwik";
+import * as Kit from "./kit";
+
+export default component$(() => <Kit.Root><Kit.Item /></Kit.Root>);
+    `.trim();
+		const testFilePath = path.join(appRoot, "src", "page.tsx");
+		fs.writeFileSync(testFilePath, code);
+
+		configureAnalyzer({ sandboxRoot: appRoot });
+		try {
+			expect(analyzeFile(testFilePath).presenceChecks).toEqual([]);
+			expect(analyzeAndTransformCode(code, testFilePath)).toBe(code);
+		} finally {
+			configureAnalyzer({});
+		}
+	});
+
+	test("a component picked by a module-level feature flag leaves the check unresolved", () => {
+		const examplePath = path.resolve(
+			__dirname,
+			"../qwik-app/src/examples/flagged_description_example.tsx",
+		);
+		const code = fs.readFileSync(examplePath, "utf-8");
+
+		const result = analyzeFile(examplePath);
+		const conditional = result.diagnostics.filter((d) => d.code === "QA011");
+		expect(conditional).toHaveLength(1);
+		expect(conditional[0].message).toContain("RealDescription or Title");
+		expect(code.slice(conditional[0].start, conditional[0].end)).toBe(
+			"Description",
+		);
+		expect(
+			result.presenceChecks.find((c) => c.component === "Description")
+				?.presence,
+		).toBe("unknown");
+
+		// Neither `true` nor `false` is injected for a check that can't be known
+		expect(analyzeAndTransformCode(code, examplePath)).not.toContain(
+			"__qwik_analyzer_has_Description",
+		);
+	});
+
+	test("element types mapped from an array leave the checks they may satisfy unresolved", () => {
+		const examplePath = path.resolve(
+			__dirname,
+			"../qwik-app/src/examples/mapped_sections_example.tsx",
+		);
+		const code = fs.readFileSync(examplePath, "utf-8");
+
+		const result = analyzeFile(examplePath);
+		const dynamic = result.diagnostics.filter((d) => d.code === "QA015");
+		expect(dynamic).toHaveLength(2);
+		expect(dynamic[0].message).toContain("taken from `sections` by `.map`");
+		expect(dynamic[0].message).toContain("DummyComp.Title or DummyComp.Description");
+		expect(code.slice(dynamic[0].start, dynamic[0].end)).toBe("Section");
+
+		const presence = (component: string) =>
+			result.presenceChecks.find((c) => c.component === component)?.presence;
+		expect(presence("Title")).toBe("unknown");
+		expect(presence("Description")).toBe("unknown");
+		// A component the array can't hold is still settled
+		expect(presence("Checkbox.Description")).toBe("absent");
+
+		const transformed = analyzeAndTransformCode(code, examplePath);
+		expect(transformed).not.toContain("__qwik_analyzer_has_Title");
+		expect(transformed).not.toContain("__qwik_analyzer_has_Description");
+		expect(transformed).toContain("__qwik_analyzer_has_Checkbox_Description={false}");
+	});
+
+	test("a file outside the JS/TS family is skipped with a diagnostic", () => {
+		const code = ".root {\n  display: flex;\n}\n";
+		const testFilePath = path.join(tempDir, "styles.css");
+		fs.writeFileSync(testFilePath, code);
+
+		const result = analyzeFile(testFilePath);
+		expect(result.transformations).toHaveLength(0);
+		expect(result.presenceChecks).toHaveLength(0);
+		expect(result.diagnostics).toHaveLength(1);
+		expect(result.diagnostics[0].code).toBe("QA012");
+		expect(result.diagnostics[0].message).toContain(
+			"unsupported file type, .css files",
+		);
+
+		expect(analyzeAndTransformCode(code, testFilePath)).toBe(code);
+	});
+
+	test("a file with syntax errors is skipped with a diagnostic", () => {
+		const code = `
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../../qwik-app/src/components/dummy-comp";
+
+export default component$(() => {
+  return <DummyComp.Root><DummyComp.Description /></div>;
+});
+`;
+		const testFilePath = path.join(tempDir, "unparsable.tsx");
+		fs.writeFileSync(testFilePath, code);
+
+		const result = analyzeFile(testFilePath);
+		expect(result.transformations).toHaveLength(0);
+		expect(result.presenceChecks).toHaveLength(0);
+		expect(result.diagnostics).toHaveLength(1);
+		expect(result.diagnostics[0].code).toBe("QA016");
+		expect(result.diagnostics[0].message).toContain("syntax error");
+
+		expect(analyzeAndTransformCode(code, testFilePath)).toBe(code);
+	});
+
+	test("a rendered component that can't be read leaves the check unresolved instead of failing the page", () => {
+		const tipKit = path.resolve(__dirname, "../qwik-app/src/components/tip-kit");
+		// Not UTF-8, so reading it fails like a file deleted or locked mid-build would
+		fs.writeFileSync(
+			path.join(tempDir, "broken.tsx"),
+			Buffer.from([0xff, 0xfe, 0x3c, 0x64, 0x69, 0x76, 0x3e]),
+		);
+		const code = `
+import { component$ } from "@builder.io/qwik";
+import { Tip } from "${tipKit}";
+import { Broken } from "./broken";
+
+export default component$(() => {
+  return (
+    <Tip.Root>
+      <Broken />
+    </Tip.Root>
+  );
+});
+`;
+		const testFilePath = path.join(tempDir, "unreadable-dependency.tsx");
+		fs.writeFileSync(testFilePath, code);
+
+		const result = analyzeFile(testFilePath);
+		const unreadable = result.diagnostics.filter((d) => d.code === "QA013");
+		expect(unreadable).toHaveLength(1);
+		expect(unreadable[0].message).toContain("broken.tsx can't be read");
+		expect(result.presenceChecks).toEqual([
+			expect.objectContaining({ component: "Hint", presence: "unknown" }),
+		]);
+
+		expect(analyzeAndTransformCode(code, testFilePath)).not.toContain(
+			"__qwik_analyzer_has_Hint",
+		);
+	});
+
+	test("a Root rendering a component that can't be read still gets its props instead of failing the analysis", () => {
+		const kitDir = path.join(tempDir, "unreadable-root-kit");
+		fs.mkdirSync(kitDir, { recursive: true });
+		fs.writeFileSync(
+			path.join(kitDir, "broken.tsx"),
+			Buffer.from([0xff, 0xfe, 0x3c, 0x64, 0x69, 0x76, 0x3e]),
+		);
+		fs.writeFileSync(
+			path.join(kitDir, "description.tsx"),
+			"export const Description = () => <p />;",
+		);
+		fs.writeFileSync(
+			path.join(kitDir, "root.tsx"),
+			`
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+import { Description } from "./description";
+import { Broken } from "./broken";
+
+export const Root = component$(() => {
+  const hasDescription = isComponentPresent(Description);
+  return <div data-description={hasDescription}><Broken /><Slot /></div>;
+});
+`,
+		);
+		fs.writeFileSync(
+			path.join(kitDir, "index.ts"),
+			`
+import { Description } from "./description";
+import { Root } from "./root";
+
+export const Kit = { Root, Description };
+`,
+		);
+		const code = `
+import { component$ } from "@builder.io/qwik";
+import { Kit } from "./unreadable-root-kit";
+
+export default component$(() => <Kit.Root><Kit.Description /></Kit.Root>);
+`;
+		const testFilePath = path.join(tempDir, "unreadable-root.tsx");
+		fs.writeFileSync(testFilePath, code);
+
+		// Looking into the Root's own children used to throw while injecting
+		const result = analyzeFile(testFilePath);
+		expect(result.diagnostics.map((d) => d.code)).not.toContain("QA014");
+		expect(result.presenceChecks).toEqual([
+			expect.objectContaining({ component: "Description", presence: "present" }),
+		]);
+		expect(analyzeAndTransformCode(code, testFilePath)).toContain(
+			"<Kit.Root __qwik_analyzer_has_Description={true}>",
+		);
+	});
+});
@@ -0,0 +1,76 @@
+/**
+ * Golden tests for the full analyze + transform pipeline.
+ * Each case transforms a fixture from qwik-app and compares the output with __golden__/<name>.tsx.
+ * Run `vitest -u` to update the golden files after an intended behavior change.
+ */
+
+import { describe, test, expect } from "vitest";
+import { analyzeAndTransformCode } from "../index.cjs";
+import fs from "node:fs";
+import path from "node:path";
+import { fileURLToPath } from "node:url";
+
+const __filename = fileURLToPath(import.meta.url);
+const __dirname = path.dirname(__filename);
+
+interface GoldenCase {
+	name: string;
+	file: string;
+	golden: string;
+}
+
+const goldenCases: GoldenCase[] = [
+	{
+		name: "direct presence",
+		file: "../qwik-app/src/examples/direct_example.tsx",
+		golden: "__golden__/direct_example.tsx",
+	},
+	{
+		name: "indirect (cross-file) presence",
+		file: "../qwik-app/src/examples/indirect_example.tsx",
+		golden: "__golden__/indirect_example.tsx",
+	},
+	{
+		name: "absence",
+		file: "../qwik-app/src/examples/absent_example.tsx",
+		golden: "__golden__/absent_example.tsx",
+	},
+	{
+		name: "props parameter insertion",
+		file: "../qwik-app/src/components/dummy-comp/root.tsx",
+		golden: "__golden__/dummy-comp-root.tsx",
+	},
+	{
+		name: "props parameter insertion with a typed return",
+		file: "../qwik-app/src/components/dummy-comp/typed-root.tsx",
+		golden: "__golden__/dummy-comp-typed-root.tsx",
+	},
+	{
+		name: "props parameter insertion inside a higher-order wrapper",
+		file: "../qwik-app/src/components/dummy-comp/styled-root.tsx",
+		golden: "__golden__/dummy-comp-styled-root.tsx",
+	},
+	{
+		name: "any-of presence with an array argument",
+		file: "../qwik-app/src/examples/alternatives_example.tsx",
+		golden: "__golden__/alternatives_example.tsx",
+	},
+	{
+		name: "array argument forwarding in the Root",
+		file: "../qwik-app/src/components/field/root.tsx",
+		golden: "__golden__/field-root.tsx",
+	},
+];
+
+describe("transform pipeline golden files", () => {
+	for (const goldenCase of goldenCases) {
+		test(goldenCase.name, async () => {
+			const filePath = path.resolve(__dirname, goldenCase.file);
+			const code = fs.readFileSync(filePath, "utf-8");
+
+			const result = analyzeAndTransformCode(code, filePath);
+
+			await expect(result).toMatchFileSnapshot(goldenCase.golden);
+		});
+	}
+});
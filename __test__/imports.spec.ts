@@ -0,0 +1,150 @@
+import { test, expect, beforeAll, afterAll } from "vitest";
+import { checkImports } from "../index.cjs";
+import { spawnSync } from "node:child_process";
+import fs from "node:fs";
+import path from "node:path";
+import { fileURLToPath } from "node:url";
+
+const __filename = fileURLToPath(import.meta.url);
+const __dirname = path.dirname(__filename);
+
+let tempDir: string;
+
+beforeAll(() => {
+	tempDir = fs.mkdtempSync(path.join(__dirname, "temp-test-"));
+});
+
+afterAll(() => {
+	fs.rmSync(tempDir, { recursive: true, force: true });
+});
+
+test("checkImports reports how each rendered component's import resolves", () => {
+	const packageDir = path.join(tempDir, "node_modules", "ui-kit");
+	fs.mkdirSync(packageDir, { recursive: true });
+	fs.writeFileSync(
+		path.join(packageDir, "package.json"),
+		JSON.stringify({ name: "ui-kit", main: "index.js" }),
+	);
+	fs.writeFileSync(
+		path.join(packageDir, "index.js"),
+		"export const Tooltip = { Root: () => null };",
+	);
+	fs.writeFileSync(
+		path.join(tempDir, "panel.tsx"),
+		"export const Panel = () => <div />;",
+	);
+
+	const filePath = path.join(tempDir, "page.tsx");
+	fs.writeFileSync(
+		filePath,
+		`
+import { component$ } from "@builder.io/qwik";
+import { Panel } from "./panel";
+import { Checkbox } from "./missing-checkbox";
+import { Tooltip } from "ui-kit";
+import type { PanelProps } from "./missing-types";
+import { helper } from "./missing-helper";
+
+export default component$(() => {
+  helper();
+  return (
+    <Panel>
+      <Checkbox.Root />
+      <Tooltip.Root />
+    </Panel>
+  );
+});
+`,
+	);
+
+	const resolutions = checkImports(filePath);
+
+	// Imports that bind no rendered component don't affect presence analysis
+	expect(resolutions.map((r) => r.source)).toEqual([
+		"./panel",
+		"./missing-checkbox",
+		"ui-kit",
+	]);
+	expect(resolutions[0]).toMatchObject({
+		localNames: ["Panel"],
+		status: "local",
+		resolvedPath: fs.realpathSync(path.join(tempDir, "panel.tsx")),
+	});
+	expect(resolutions[1].status).toBe("unresolved");
+	expect(resolutions[1].resolvedPath).toBeUndefined();
+	expect(resolutions[2]).toMatchObject({
+		localNames: ["Tooltip"],
+		status: "package",
+	});
+});
+
+test("an import that failed to resolve isn't probed again within the same analysis", () => {
+	const filePath = path.join(tempDir, "missing-kit-page.tsx");
+	fs.writeFileSync(
+		filePath,
+		`
+import { component$ } from "@builder.io/qwik";
+import { Kit } from "./missing-kit";
+
+export default component$(() => {
+  return (
+    <Kit.Root>
+      <Kit.Description />
+      <Kit.Title />
+    </Kit.Root>
+  );
+});
+`,
+	);
+
+	// Analysis logs go to the native stdout, so run it in a child process to read them
+	const script = `require(${JSON.stringify(path.resolve(__dirname, "../index.cjs"))}).analyzeFile(${JSON.stringify(filePath)})`;
+	const { stdout, status } = spawnSync(process.execPath, ["-e", script], {
+		encoding: "utf-8",
+	});
+	expect(status).toBe(0);
+
+	const lookups = stdout
+		.split("\n")
+		.filter((line) => line.includes("Import resolution failed for './missing-kit'"));
+	expect(lookups).toHaveLength(1);
+	expect(stdout).toMatch(/[1-9]\d* import resolutions served from the negative cache/);
+});
+
+test("analysis logs one summary line per rendered element", () => {
+	const srcDir = path.resolve(__dirname, "../qwik-app/src");
+	const filePath = path.join(tempDir, "summary-page.tsx");
+	fs.writeFileSync(
+		filePath,
+		`
+import { component$ } from "@builder.io/qwik";
+import { Tip } from "${path.join(srcDir, "components/tip-kit")}";
+import { Kit } from "./missing-kit";
+
+export default component$(() => {
+  return (
+    <>
+      <Tip.Root>
+        <Tip.Hint />
+      </Tip.Root>
+      <Kit.Root />
+    </>
+  );
+});
+`,
+	);
+
+	const script = `require(${JSON.stringify(path.resolve(__dirname, "../index.cjs"))}).analyzeFile(${JSON.stringify(filePath)})`;
+	const { stdout, status } = spawnSync(process.execPath, ["-e", script], {
+		encoding: "utf-8",
+	});
+	expect(status).toBe(0);
+
+	const summaries = stdout.split("\n").filter((line) => line.startsWith("🧾"));
+	expect(summaries).toContain(
+		`🧾 element=Tip.Root file=${path.join(srcDir, "components/tip-kit/root.tsx")} origin=local injected=[__qwik_analyzer_has_Hint=true]`,
+	);
+	expect(summaries).toContain(
+		`🧾 element=Kit.Root origin=unresolved reason="import './missing-kit' doesn't resolve" injected=[]`,
+	);
+});
@@ -0,0 +1,109 @@
+import { test, expect, describe, beforeAll, afterAll } from "vitest";
+import { analyzeFile, adjustTransformationsForEdit } from "../index.cjs";
+import fs from "node:fs";
+import path from "node:path";
+import { fileURLToPath } from "node:url";
+
+const __filename = fileURLToPath(import.meta.url);
+const __dirname = path.dirname(__filename);
+
+let tempDir: string;
+let rootFilePath: string;
+
+const rootCode = `
+import { component$, Slot } from "@builder.io/qwik";
+import { Description } from "./components/description";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+export const Root = component$(() => {
+  const isDescription = isComponentPresent(Description);
+  return <div><Slot /></div>;
+});
+`.trimStart();
+
+beforeAll(() => {
+	tempDir = fs.mkdtempSync(path.join(__dirname, "temp-test-"));
+
+	fs.mkdirSync(path.join(tempDir, "components"), { recursive: true });
+	fs.writeFileSync(
+		path.join(tempDir, "components", "description.tsx"),
+		`
+import { component$ } from "@builder.io/qwik";
+
+export const Description = component$(() => {
+  return <div>Description Component</div>;
+});
+  `.trim(),
+	);
+
+	rootFilePath = path.join(tempDir, "root.tsx");
+	fs.writeFileSync(rootFilePath, rootCode);
+});
+
+afterAll(() => {
+	if (tempDir) {
+		fs.rmSync(tempDir, { recursive: true, force: true });
+	}
+});
+
+describe("adjustTransformationsForEdit", () => {
+	test("edits before every transformation shift them by the inserted length", () => {
+		const previous = analyzeFile(rootFilePath);
+		expect(previous.transformations).toHaveLength(2);
+
+		const offset = rootCode.indexOf("export const Root");
+		const update = adjustTransformationsForEdit(rootCode, rootFilePath, previous, {
+			offset,
+			removedLength: 0,
+			insertedText: "// note\n",
+		});
+
+		expect(update.requiresReanalysis).toBe(false);
+		expect(update.transformations).toEqual(
+			previous.transformations.map((transformation) => ({
+				...transformation,
+				start: transformation.start + 8,
+				end: transformation.end + 8,
+			})),
+		);
+	});
+
+	test("edits inside a transformed span require re-analysis", () => {
+		const previous = analyzeFile(rootFilePath);
+
+		const offset = rootCode.indexOf("(Description)") + 1;
+		const update = adjustTransformationsForEdit(rootCode, rootFilePath, previous, {
+			offset,
+			removedLength: "Description".length,
+			insertedText: "Title",
+		});
+
+		expect(update.requiresReanalysis).toBe(true);
+	});
+
+	test("edits after every transformation leave them untouched", () => {
+		const previous = analyzeFile(rootFilePath);
+
+		const update = adjustTransformationsForEdit(rootCode, rootFilePath, previous, {
+			offset: rootCode.length,
+			removedLength: 0,
+			insertedText: "export const answer = 42;\n",
+		});
+
+		expect(update.requiresReanalysis).toBe(false);
+		expect(update.transformations).toEqual(previous.transformations);
+	});
+
+	test("edits to an import require re-analysis", () => {
+		const previous = analyzeFile(rootFilePath);
+
+		const offset = rootCode.indexOf("Slot }");
+		const update = adjustTransformationsForEdit(rootCode, rootFilePath, previous, {
+			offset,
+			removedLength: 4,
+			insertedText: "Sl",
+		});
+
+		expect(update.requiresReanalysis).toBe(true);
+	});
+});
@@ -16,6 +16,7 @@ interface TestCase {
 	name: string;
 	file: string;
 	expectedHasComponent: boolean;
+	expectedFoundDirectly?: boolean;
 	description: string;
 	moduleSpecifier?: string;
 }
@@ -25,6 +26,7 @@ const testCases: TestCase[] = [
 		name: "Direct Example",
 		file: "../qwik-app/src/examples/direct_example.tsx",
 		expectedHasComponent: true,
+		expectedFoundDirectly: true,
 		description:
 			"Should detect DummyComp.Description directly within DummyComp.Root",
 		moduleSpecifier: "../components/dummy-comp",
@@ -37,6 +39,115 @@ const testCases: TestCase[] = [
 			"Should detect DummyComp.Description via imported Heyo component with recursive analysis",
 		moduleSpecifier: "../components/dummy-comp",
 	},
+	{
+		name: "Nested Example",
+		file: "../qwik-app/src/examples/nested_example.tsx",
+		expectedHasComponent: true,
+		expectedFoundDirectly: false,
+		description:
+			"Should detect DummyComp.Description two custom-component levels below DummyComp.Root (Panel -> Heyo)",
+		moduleSpecifier: "../components/dummy-comp",
+	},
+	{
+		name: "Decorated Example",
+		file: "../qwik-app/src/examples/decorated_example.tsx",
+		expectedHasComponent: true,
+		description:
+			"Should still analyze a file that uses TypeScript decorators",
+		moduleSpecifier: "../components/dummy-comp",
+	},
+	{
+		name: "Reverse Example",
+		file: "../qwik-app/src/examples/reverse_example.tsx",
+		expectedHasComponent: true,
+		description:
+			"Should detect Tabs.Panel when the consumer renders a directly imported Panel",
+		moduleSpecifier: "../components/tabs",
+	},
+	{
+		name: "Default Export Example",
+		file: "../qwik-app/src/examples/default_export_example.tsx",
+		expectedHasComponent: true,
+		description:
+			"Should detect Accordion.Item when Accordion.Root is a default export re-exported by name",
+		moduleSpecifier: "../components/accordion",
+	},
+	{
+		name: "Mixed Import Example",
+		file: "../qwik-app/src/examples/mixed_import_example.tsx",
+		expectedHasComponent: true,
+		description:
+			"Should detect Tabs.Panel when Tabs and Panel are imported from the same barrel",
+		moduleSpecifier: "../components/tabs",
+	},
+	{
+		name: "Spread Children Example",
+		file: "../qwik-app/src/examples/spread_children_example.tsx",
+		expectedHasComponent: true,
+		description:
+			"Should detect DummyComp.Description passed as children through a spread props object",
+		moduleSpecifier: "../components/dummy-comp",
+	},
+	{
+		name: "Spread Indirect Example",
+		file: "../qwik-app/src/examples/spread_indirect_example.tsx",
+		expectedHasComponent: true,
+		description:
+			"Should detect DummyComp.Description rendered through a JSX spread child of an imported component",
+		moduleSpecifier: "../components/dummy-comp",
+	},
+	{
+		name: "Alternatives Example",
+		file: "../qwik-app/src/examples/alternatives_example.tsx",
+		expectedHasComponent: true,
+		description:
+			"Should report Field.Root's [Description, HelperText] check as present when only HelperText is rendered",
+		moduleSpecifier: "../components/field",
+	},
+	{
+		name: "Deep Module Example",
+		file: "../qwik-app/src/examples/deep_module_example.tsx",
+		expectedHasComponent: true,
+		description:
+			"Should find DeepKit.Root's presence check in a nested folder of the module",
+		moduleSpecifier: "../components/deep-kit",
+	},
+	{
+		name: "Function Child Example",
+		file: "../qwik-app/src/examples/function_child_example.tsx",
+		expectedHasComponent: true,
+		expectedFoundDirectly: true,
+		description:
+			"Should detect DummyComp.Description rendered by an arrow function child of DummyComp.Root",
+		moduleSpecifier: "../components/dummy-comp",
+	},
+	{
+		name: "Function Child Indirect Example",
+		file: "../qwik-app/src/examples/function_child_indirect_example.tsx",
+		expectedHasComponent: true,
+		expectedFoundDirectly: false,
+		description:
+			"Should detect DummyComp.Description rendered by a function child inside an imported component",
+		moduleSpecifier: "../components/dummy-comp",
+	},
+	{
+		name: "Identifier Reference Example",
+		file: "../qwik-app/src/examples/identifier_reference_example.tsx",
+		expectedHasComponent: true,
+		expectedFoundDirectly: true,
+		description:
+			"Should detect a directly imported <Description /> (a JSX identifier reference) inside DummyComp.Root",
+		moduleSpecifier: "../components/dummy-comp",
+	},
+	{
+		name: "Wrapped Root Example",
+		file: "../qwik-app/src/examples/wrapped_root_example.tsx",
+		expectedHasComponent: true,
+		expectedFoundDirectly: true,
+		description:
+			"Should follow a wrapper that forwards props to DummyComp.Root around a <Slot /> and detect the Description passed to it",
+		moduleSpecifier: "./wrapped_root",
+	},
 	{
 		name: "Heyo Component",
 		file: "../qwik-app/src/examples/heyo.tsx",
@@ -88,6 +199,9 @@ describe("qwik-analyzer integration tests", () => {
 
 			const result = await analyzeFile(filePath);
 			expect(result.hasComponent).toBe(testCase.expectedHasComponent);
+			if (testCase.expectedFoundDirectly !== undefined) {
+				expect(result.foundDirectly).toBe(testCase.expectedFoundDirectly);
+			}
 		});
 
 		it(`${testCase.name}: should handle file change events`, () => {
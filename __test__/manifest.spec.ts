@@ -0,0 +1,219 @@
+import { test, expect, describe, beforeAll, afterAll } from "vitest";
+import { analyzeFile, analyzeAndTransformCode, configureAnalyzer } from "../index.cjs";
+import fs from "node:fs";
+import path from "node:path";
+import { fileURLToPath } from "node:url";
+
+const __filename = fileURLToPath(import.meta.url);
+const __dirname = path.dirname(__filename);
+
+let tempDir: string;
+
+beforeAll(() => {
+	tempDir = fs.mkdtempSync(path.join(__dirname, "temp-test-"));
+
+	fs.mkdirSync(path.join(tempDir, "src"), { recursive: true });
+	fs.writeFileSync(
+		path.join(tempDir, "package.json"),
+		JSON.stringify({ name: "manifest-fixture" }),
+	);
+
+	// The library is never installed, so its source can't be scanned
+	fs.writeFileSync(
+		path.join(tempDir, "qwik-analyzer.json"),
+		JSON.stringify(
+			{
+				components: {
+					"Checkbox.Root": {
+						source: "@kunai-consulting/qwik",
+						children: ["Checkbox.Description", "Checkbox.Label"],
+					},
+					"Toggle.Root": {
+						source: "./toggle",
+						children: ["Description", "Title"],
+					},
+				},
+			},
+			null,
+			2,
+		),
+	);
+
+	// A local library whose Root only checks for Description
+	const toggleDir = path.join(tempDir, "src", "toggle");
+	fs.mkdirSync(toggleDir, { recursive: true });
+	fs.writeFileSync(
+		path.join(toggleDir, "index.ts"),
+		`
+import { Description } from "./description";
+import { Root } from "./root";
+import { Title } from "./title";
+
+export const Toggle = { Root, Description, Title };
+  `.trim(),
+	);
+	for (const name of ["Description", "Title"]) {
+		fs.writeFileSync(
+			path.join(toggleDir, `${name.toLowerCase()}.tsx`),
+			`
+import { component$ } from "@builder.io/qwik";
+
+export const ${name} = component$(() => <p>${name}</p>);
+    `.trim(),
+		);
+	}
+	fs.writeFileSync(
+		path.join(toggleDir, "root.tsx"),
+		`
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+import { Description } from "./description";
+
+export const Root = component$(() => {
+  const hasDescription = isComponentPresent(Description);
+  return <div><Slot /></div>;
+});
+  `.trim(),
+	);
+});
+
+afterAll(() => {
+	if (tempDir) {
+		fs.rmSync(tempDir, { recursive: true, force: true });
+	}
+});
+
+describe("qwik-analyzer.json manifest", () => {
+	test("manifest-declared children are injected without parsing the library", () => {
+		const code = `
+import { component$ } from "@builder.io/qwik";
+import { Checkbox } from "@kunai-consulting/qwik";
+
+export default component$(() => {
+  return (
+    <Checkbox.Root>
+      <Checkbox.Description />
+    </Checkbox.Root>
+  );
+});
+    `.trim();
+
+		const testFilePath = path.join(tempDir, "src", "page.tsx");
+		fs.writeFileSync(testFilePath, code);
+		const result = analyzeAndTransformCode(code, testFilePath);
+
+		expect(result).toContain(
+			"<Checkbox.Root __qwik_analyzer_has_Checkbox_Description={true} __qwik_analyzer_has_Checkbox_Label={false}>",
+		);
+	});
+
+	test("package sources match subpath imports but not packages sharing a prefix", () => {
+		const pageFor = (specifier: string) => `
+import { component$ } from "@builder.io/qwik";
+import { Checkbox } from "${specifier}";
+
+export default component$(() => {
+  return (
+    <Checkbox.Root>
+      <Checkbox.Description />
+    </Checkbox.Root>
+  );
+});
+    `.trim();
+		const injected =
+			"<Checkbox.Root __qwik_analyzer_has_Checkbox_Description={true} __qwik_analyzer_has_Checkbox_Label={false}>";
+		const testFilePath = path.join(tempDir, "src", "specifier-page.tsx");
+
+		for (const specifier of ["@kunai-consulting/qwik", "@kunai-consulting/qwik/checkbox"]) {
+			const code = pageFor(specifier);
+			fs.writeFileSync(testFilePath, code);
+			expect(analyzeAndTransformCode(code, testFilePath)).toContain(injected);
+		}
+
+		for (const specifier of ["@kunai-consulting/qwik-icons", "@kunai-consulting/qwikest/checkbox"]) {
+			const code = pageFor(specifier);
+			fs.writeFileSync(testFilePath, code);
+			expect(analyzeAndTransformCode(code, testFilePath)).toBe(code);
+		}
+	});
+
+	test("aliased imports of a manifest root are matched by their exported name", () => {
+		const code = `
+import { component$ } from "@builder.io/qwik";
+import { Checkbox as CB } from "@kunai-consulting/qwik";
+
+export default component$(() => {
+  return (
+    <CB.Root>
+      <CB.Label />
+    </CB.Root>
+  );
+});
+    `.trim();
+
+		const testFilePath = path.join(tempDir, "src", "aliased.tsx");
+		fs.writeFileSync(testFilePath, code);
+		const result = analyzeAndTransformCode(code, testFilePath);
+
+		expect(result).toContain("__qwik_analyzer_has_Checkbox_Description={false}");
+		expect(result).toContain("__qwik_analyzer_has_Checkbox_Label={true}");
+	});
+
+	test("injected props the Root never checks for are reported under verifyManifest", () => {
+		const code = `
+import { component$ } from "@builder.io/qwik";
+import { Toggle } from "./toggle";
+
+export default component$(() => {
+  return (
+    <Toggle.Root>
+      <Toggle.Description />
+    </Toggle.Root>
+  );
+});
+    `.trim();
+
+		const testFilePath = path.join(tempDir, "src", "unused-prop.tsx");
+		fs.writeFileSync(testFilePath, code);
+
+		// The definition isn't parsed unless asked for
+		expect(analyzeFile(testFilePath).diagnostics).toHaveLength(0);
+
+		configureAnalyzer({ verifyManifest: true });
+		try {
+			const result = analyzeFile(testFilePath);
+
+			expect(result.diagnostics).toHaveLength(1);
+			expect(result.diagnostics[0].code).toBe("QA005");
+			expect(result.diagnostics[0].message).toContain(
+				"`Toggle.Root` receives `__qwik_analyzer_has_Title`",
+			);
+			expect(
+				code.slice(result.diagnostics[0].start, result.diagnostics[0].end),
+			).toBe("Toggle.Root");
+		} finally {
+			configureAnalyzer({});
+		}
+	});
+
+	test("roots from unresolvable libraries are not cross-checked", () => {
+		const code = `
+import { component$ } from "@builder.io/qwik";
+import { Checkbox } from "@kunai-consulting/qwik";
+
+export default component$(() => {
+  return <Checkbox.Root />;
+});
+    `.trim();
+
+		const testFilePath = path.join(tempDir, "src", "unresolved.tsx");
+		fs.writeFileSync(testFilePath, code);
+
+		configureAnalyzer({ verifyManifest: true });
+		try {
+			expect(analyzeFile(testFilePath).diagnostics).toHaveLength(0);
+		} finally {
+			configureAnalyzer({});
+		}
+	});
+});
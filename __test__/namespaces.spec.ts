@@ -0,0 +1,42 @@
+import { test, expect } from "vitest";
+import { collectNamespaces } from "../index.cjs";
+import path from "node:path";
+import { fileURLToPath } from "node:url";
+
+const __filename = fileURLToPath(import.meta.url);
+const __dirname = path.dirname(__filename);
+
+test("collectNamespaces lists each imported compound-component namespace once", () => {
+	const code = `
+import { component$ } from "@builder.io/qwik";
+import { Checkbox } from "@kunai-consulting/qwik";
+import * as Form from "~/components/form";
+import { Button } from "~/components/button";
+
+const Layout = { Stack: (props: any) => <div {...props} /> };
+
+export default component$(() => {
+  return (
+    <Form.Root>
+      <Layout.Stack>
+        <Form.Field>
+          <Checkbox.Root>
+            <Checkbox.Indicator />
+          </Checkbox.Root>
+        </Form.Field>
+        <Button />
+      </Layout.Stack>
+    </Form.Root>
+  );
+});
+  `.trim();
+
+	const filePath = path.join(__dirname, "namespaces.tsx");
+	expect(collectNamespaces(code, filePath)).toEqual(["Checkbox", "Form"]);
+});
+
+test("collectNamespaces rejects code that fails to parse", () => {
+	expect(() => collectNamespaces("<Checkbox.Root>", "broken.tsx")).toThrow(
+		/Namespace collection failed/,
+	);
+});
@@ -0,0 +1,512 @@
+import { test, expect, describe, beforeAll, afterAll } from "vitest";
+import {
+	analyzeFile,
+	analyzeAndTransformCode,
+	configureAnalyzer,
+	declaredOptionalChildren,
+	explainPresence,
+} from "../index.cjs";
+import fs from "node:fs";
+import path from "node:path";
+import { fileURLToPath } from "node:url";
+
+const __filename = fileURLToPath(import.meta.url);
+const __dirname = path.dirname(__filename);
+
+const examplesDir = path.resolve(__dirname, "../qwik-app/src/examples");
+
+let tempDir: string;
+
+function presenceOf(filePath: string, component: string) {
+	const result = analyzeFile(filePath);
+	return result.presenceChecks.find(
+		(check) =>
+			check.component === component &&
+			check.sourceFile.endsWith(path.join("dummy-comp", "root.tsx")),
+	)?.presence;
+}
+
+function rootRenderingUnanalyzableChild(importLine: string, childTag: string) {
+	return `
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../../qwik-app/src/components/dummy-comp";
+${importLine}
+
+export default component$(() => {
+  return (
+    <DummyComp.Root>
+      ${childTag}
+      <DummyComp.Title />
+    </DummyComp.Root>
+  );
+});
+  `.trim();
+}
+
+beforeAll(() => {
+	tempDir = fs.mkdtempSync(path.join(__dirname, "temp-test-"));
+
+	// Its own project, so `mapped/*` is a bare specifier mapped back into it
+	fs.writeFileSync(path.join(tempDir, "package.json"), JSON.stringify({ name: "presence-app" }));
+	fs.writeFileSync(
+		path.join(tempDir, "tsconfig.json"),
+		JSON.stringify({ compilerOptions: { baseUrl: ".", paths: { "mapped/*": ["./*"] } } }),
+	);
+	writeDeclarationOnlyLibrary();
+
+	fs.writeFileSync(
+		path.join(tempDir, "broken.tsx"),
+		`
+import { component$ } from "@builder.io/qwik";
+
+export const Broken = component$(() => {
+  return <div><p></div>;
+});
+  `.trim(),
+	);
+});
+
+/** A package shipping compiled JS without JSX and the type declarations of its members */
+function writeDeclarationOnlyLibrary() {
+	const packageDir = path.join(tempDir, "node_modules", "decl-kit");
+	fs.mkdirSync(packageDir, { recursive: true });
+	fs.writeFileSync(
+		path.join(packageDir, "package.json"),
+		JSON.stringify({ name: "decl-kit", main: "index.js", types: "index.d.ts" }),
+	);
+	fs.writeFileSync(
+		path.join(packageDir, "index.js"),
+		`
+import { componentQrl, inlinedQrl, _jsxC, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+const Description = componentQrl(inlinedQrl(() => _jsxC("p", { children: _jsxC(Slot) }), "d"));
+const Panel = componentQrl(inlinedQrl(() => _jsxC(Description, {}), "p"));
+const Root = componentQrl(
+  inlinedQrl((props) => {
+    const hasDescription = isComponentPresent(Description, props.__qwik_analyzer_has_Description);
+    return _jsxC("div", { "data-description": hasDescription, children: _jsxC(Slot) });
+  }, "r"),
+);
+
+export const Checkbox = { Root, Description, Panel };
+  `.trim(),
+	);
+	fs.writeFileSync(
+		path.join(packageDir, "index.d.ts"),
+		`
+import type { Component } from "@builder.io/qwik";
+
+export declare const Checkbox: {
+  Root: Component<{}>;
+  Description: Component<{}>;
+  Panel: Component<{}>;
+};
+  `.trim(),
+	);
+}
+
+afterAll(() => {
+	if (tempDir) {
+		fs.rmSync(tempDir, { recursive: true, force: true });
+	}
+});
+
+describe("presence checks", () => {
+	test("a child rendered in the Root is present", () => {
+		expect(presenceOf(path.join(examplesDir, "direct_example.tsx"), "Description")).toBe(
+			"present",
+		);
+	});
+
+	test("a child that is nowhere in the tree is absent", () => {
+		expect(presenceOf(path.join(examplesDir, "absent_example.tsx"), "Description")).toBe(
+			"absent",
+		);
+	});
+
+	for (const [name, importLine, childTag] of [
+		["an unresolvable local import", 'import { Missing } from "./missing";', "<Missing />"],
+		["a local import that fails to parse", 'import { Broken } from "./broken";', "<Broken />"],
+		["a path-mapped import that fails to parse", 'import { Broken as Mapped } from "mapped/broken";', "<Mapped />"],
+	]) {
+		test(`a child hidden behind ${name} is unknown and gets no prop`, () => {
+			const code = rootRenderingUnanalyzableChild(importLine, childTag);
+			const testFilePath = path.join(tempDir, `${childTag.slice(1, -3).toLowerCase()}.page.tsx`);
+			fs.writeFileSync(testFilePath, code);
+
+			expect(presenceOf(testFilePath, "Description")).toBe("unknown");
+			expect(presenceOf(testFilePath, "Title")).toBe("present");
+
+			const result = analyzeAndTransformCode(code, testFilePath);
+			expect(result).toContain("<DummyComp.Root __qwik_analyzer_has_Title={true}>");
+			expect(result).not.toContain("__qwik_analyzer_has_Description");
+		});
+	}
+});
+
+test("members a declaration-only library declares may render the child, so it is unknown", () => {
+	const pageWith = (child: string) => `
+import { component$ } from "@builder.io/qwik";
+import { Checkbox } from "decl-kit";
+
+export default component$(() => {
+  return (
+    <Checkbox.Root>
+      ${child}
+    </Checkbox.Root>
+  );
+});
+  `.trim();
+	const presenceFor = (name: string, child: string) => {
+		const filePath = path.join(tempDir, `decl-kit-${name}.tsx`);
+		fs.writeFileSync(filePath, pageWith(child));
+		const check = analyzeFile(filePath).presenceChecks.find(
+			(check) => check.component === "Description",
+		);
+		return [check?.presence, analyzeAndTransformCode(pageWith(child), filePath)];
+	};
+
+	// Panel's compiled source renders Description, but only its declaration can be read
+	const [panelPresence, panelCode] = presenceFor("panel", "<Checkbox.Panel />");
+	expect(panelPresence).toBe("unknown");
+	expect(panelCode).not.toContain("__qwik_analyzer_has_Description");
+
+	const [directPresence, directCode] = presenceFor("direct", "<Checkbox.Description />");
+	expect(directPresence).toBe("present");
+	expect(directCode).toContain("<Checkbox.Root __qwik_analyzer_has_Description={true}>");
+
+	// Members the declarations don't list and plain elements can't render it
+	for (const child of ["<Checkbox.Extra />", "<span />"]) {
+		const [presence, code] = presenceFor("absent", child);
+		expect(presence).toBe("absent");
+		expect(code).toContain("<Checkbox.Root __qwik_analyzer_has_Description={false}>");
+	}
+});
+
+test("declaredOptionalChildren lists what a Root checks for, sorted", () => {
+	const rootFile = path.resolve(
+		__dirname,
+		"../qwik-app/src/components/dummy-comp/root.tsx",
+	);
+
+	expect(declaredOptionalChildren(rootFile)).toEqual([
+		"Checkbox.Description",
+		"Description",
+		"Title",
+	]);
+
+	const alternativesRoot = path.join(tempDir, "alternatives-root.tsx");
+	fs.writeFileSync(
+		alternativesRoot,
+		`
+import { component$ } from "@builder.io/qwik";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+import { Description, HelperText } from "./parts";
+
+export const Root = component$(() => {
+  const hasHint = isComponentPresent([HelperText, Description]);
+  const hasDescription = isComponentPresent(Description);
+  return <div data-hint={hasHint && hasDescription} />;
+});
+`,
+	);
+	expect(declaredOptionalChildren(alternativesRoot)).toEqual([
+		"Description",
+		"HelperText",
+	]);
+});
+
+test("nested components are searched once each and only up to maxComponentDepth", () => {
+	const cycleDir = path.join(tempDir, "cycle");
+	fs.mkdirSync(cycleDir, { recursive: true });
+	const files = {
+		"page.tsx": `
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../../../qwik-app/src/components/dummy-comp";
+import { Outer } from "./outer";
+
+export default component$(() => {
+  return (
+    <DummyComp.Root>
+      <Outer />
+    </DummyComp.Root>
+  );
+});
+`,
+		"outer.tsx": `
+import { component$ } from "@builder.io/qwik";
+import { Middle } from "./middle";
+
+export const Outer = component$(() => <Middle />);
+`,
+		// Middle renders Outer back, a cycle that is only followed once
+		"middle.tsx": `
+import { component$ } from "@builder.io/qwik";
+import { Outer } from "./outer";
+import { Inner } from "./inner";
+
+export const Middle = component$(() => (
+  <div>
+    <Outer />
+    <Inner />
+  </div>
+));
+`,
+		"inner.tsx": `
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../../../qwik-app/src/components/dummy-comp";
+
+export const Inner = component$(() => <DummyComp.Description />);
+`,
+	};
+	for (const [name, content] of Object.entries(files)) {
+		fs.writeFileSync(path.join(cycleDir, name), content);
+	}
+	const pagePath = path.join(cycleDir, "page.tsx");
+
+	expect(presenceOf(pagePath, "Description")).toBe("present");
+
+	configureAnalyzer({ maxComponentDepth: 2 });
+	try {
+		// Outer and Middle are searched, Inner is one level too deep
+		expect(presenceOf(pagePath, "Description")).toBe("absent");
+	} finally {
+		configureAnalyzer({});
+	}
+
+	expect(() => configureAnalyzer({ maxComponentDepth: 0 })).toThrow(/maxComponentDepth/);
+});
+
+test("a child reached deep first is searched again when reached at a shallower depth", () => {
+	const detourDir = path.join(tempDir, "detour");
+	fs.mkdirSync(detourDir, { recursive: true });
+	const files = {
+		"page.tsx": `
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../../../qwik-app/src/components/dummy-comp";
+import { Outer } from "./outer";
+
+export default component$(() => {
+  return (
+    <DummyComp.Root>
+      <Outer />
+    </DummyComp.Root>
+  );
+});
+`,
+		// Detour comes first and reaches Middle a level deeper than Outer does
+		"outer.tsx": `
+import { component$ } from "@builder.io/qwik";
+import { Detour } from "./detour";
+import { Middle } from "./middle";
+
+export const Outer = component$(() => (
+  <div>
+    <Detour />
+    <Middle />
+  </div>
+));
+`,
+		"detour.tsx": `
+import { component$ } from "@builder.io/qwik";
+import { Middle } from "./middle";
+
+export const Detour = component$(() => <Middle />);
+`,
+		"middle.tsx": `
+import { component$ } from "@builder.io/qwik";
+import { Inner } from "./inner";
+
+export const Middle = component$(() => <Inner />);
+`,
+		"inner.tsx": `
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../../../qwik-app/src/components/dummy-comp";
+
+export const Inner = component$(() => <DummyComp.Description />);
+`,
+	};
+	for (const [name, content] of Object.entries(files)) {
+		fs.writeFileSync(path.join(detourDir, name), content);
+	}
+	const pagePath = path.join(detourDir, "page.tsx");
+
+	configureAnalyzer({ maxComponentDepth: 3 });
+	try {
+		// Through Detour, Inner is too deep; through Outer directly it's within the limit
+		expect(presenceOf(pagePath, "Description")).toBe("present");
+	} finally {
+		configureAnalyzer({});
+	}
+});
+
+test("explainPresence shows the element that matched a present child", () => {
+	const explanation = explainPresence(
+		path.join(examplesDir, "indirect_example.tsx"),
+		"DummyComp.Root",
+		"Description",
+	);
+
+	expect(explanation.presence).toBe("present");
+	expect(explanation.rootFile).toMatch(/dummy-comp[\\/]root\.tsx$/);
+	expect(explanation.matchedElement).toBe("DummyComp.Description");
+	expect(explanation.depth).toBe(1);
+	expect(explanation.steps).toContainEqual(
+		expect.stringContaining("heyo.tsx for <Heyo>"),
+	);
+});
+
+test("explainPresence says where an absent child was looked for", () => {
+	const explanation = explainPresence(
+		path.join(examplesDir, "attributed_example.tsx"),
+		"DummyComp.Root",
+		"Title",
+	);
+
+	expect(explanation.presence).toBe("absent");
+	expect(explanation.matchedElement).toBeUndefined();
+	expect(explanation.steps).toContainEqual(
+		expect.stringContaining("attributed_example.tsx resolves to Title"),
+	);
+	expect(explanation.steps).toContainEqual(
+		expect.stringMatching(/^searching the Root in .*root\.tsx for Title$/),
+	);
+
+	const unchecked = explainPresence(
+		path.join(examplesDir, "attributed_example.tsx"),
+		"DummyComp.Root",
+		"Footer",
+	);
+	expect(unchecked.presence).toBeUndefined();
+});
+
+test("injectedProps explains each consumer-side transformation", () => {
+	const examplePath = path.join(examplesDir, "attributed_example.tsx");
+	const code = fs.readFileSync(examplePath, "utf-8");
+	const result = analyzeFile(examplePath);
+
+	expect(
+		result.injectedProps.map(({ element, component, propName, value }) => ({
+			element,
+			component,
+			propName,
+			value,
+		})),
+	).toEqual([
+		{
+			element: "DummyComp.Root",
+			component: "Checkbox.Description",
+			propName: "__qwik_analyzer_has_Checkbox_Description",
+			value: false,
+		},
+		{
+			element: "DummyComp.Root",
+			component: "Description",
+			propName: "__qwik_analyzer_has_Description",
+			value: true,
+		},
+		{
+			element: "DummyComp.Root",
+			component: "Title",
+			propName: "__qwik_analyzer_has_Title",
+			value: false,
+		},
+	]);
+
+	result.injectedProps.forEach((prop, index) => {
+		const tag = code.slice(prop.start, prop.end);
+		expect(tag.startsWith("<DummyComp.Root")).toBe(true);
+		expect(tag.endsWith(">")).toBe(true);
+
+		const transformation = result.transformations[index];
+		expect(transformation.start).toBeGreaterThanOrEqual(prop.start);
+		expect(transformation.end).toBeLessThanOrEqual(prop.end);
+		expect(transformation.replacement).toBe(` ${prop.propName}={${prop.value}}`);
+	});
+});
+
+test("configured assumePresent children are injected as true without a JSX match", () => {
+	const examplePath = path.join(examplesDir, "absent_example.tsx");
+	const code = fs.readFileSync(examplePath, "utf-8");
+	const injected =
+		"<DummyComp.Root __qwik_analyzer_has_Checkbox_Description={false} __qwik_analyzer_has_Description={true} __qwik_analyzer_has_Title={true}>";
+
+	expect(presenceOf(examplePath, "Description")).toBe("absent");
+
+	configureAnalyzer({ assumePresent: ["Description"] });
+	try {
+		expect(presenceOf(examplePath, "Description")).toBe("present");
+		expect(presenceOf(examplePath, "Checkbox.Description")).toBe("absent");
+		expect(analyzeAndTransformCode(code, examplePath)).toContain(injected);
+	} finally {
+		configureAnalyzer({});
+	}
+});
+
+test("configured onlyComponents leaves components outside the list unanalyzed", () => {
+	const examplePath = path.join(examplesDir, "design_system_page.tsx");
+	const code = fs.readFileSync(examplePath, "utf-8");
+
+	configureAnalyzer({ onlyComponents: ["CheckboxKit"] });
+	try {
+		const result = analyzeAndTransformCode(code, examplePath);
+		expect(result).toContain("<CheckboxKit.Root __qwik_analyzer_has_Description={true}>");
+		expect(result).toContain("<Radio.Root>");
+		expect(analyzeFile(examplePath).presenceChecks.map((check) => check.component)).toEqual([
+			"Description",
+		]);
+	} finally {
+		configureAnalyzer({});
+	}
+
+	configureAnalyzer({ onlyComponents: ["Radio.Root"] });
+	try {
+		const result = analyzeAndTransformCode(code, examplePath);
+		expect(result).toContain("<CheckboxKit.Root>");
+		expect(result).toContain("<Radio.Root __qwik_analyzer_has_Legend={true}>");
+	} finally {
+		configureAnalyzer({});
+	}
+});
+
+test("Roots rendered in one file are analyzed in the same order on every run", () => {
+	const testFilePath = path.join(tempDir, "several-roots.tsx");
+	fs.writeFileSync(
+		testFilePath,
+		`
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../../qwik-app/src/components/dummy-comp";
+import { CardKit } from "../../qwik-app/src/components/card-kit";
+import { ComposedKit } from "../../qwik-app/src/components/composed-kit";
+
+export default component$(() => {
+  return (
+    <div>
+      <DummyComp.Root>
+        <DummyComp.Description />
+      </DummyComp.Root>
+      <CardKit.Root>
+        <CardKit.Title />
+      </CardKit.Root>
+      <ComposedKit.Root />
+    </div>
+  );
+});
+`,
+	);
+
+	const checkOrder = () =>
+		analyzeFile(testFilePath).presenceChecks.map(
+			(check) => `${path.basename(path.dirname(check.sourceFile))}:${check.component}`,
+		);
+
+	const first = checkOrder();
+	expect(checkOrder()).toEqual(first);
+	// Roots are visited by element name, CardKit before ComposedKit before DummyComp
+	expect([...new Set(first.map((check) => check.split(":")[0]))]).toEqual([
+		"card-kit",
+		"composed-kit",
+		"dummy-comp",
+	]);
+});
@@ -1,5 +1,5 @@
 import { test, expect, describe, beforeAll, afterAll } from "vitest";
-import { analyzeAndTransformCode } from "../index.cjs";
+import { analyzeAndTransformCode, configureAnalyzer } from "../index.cjs";
 import fs from "node:fs";
 import path from "node:path";
 import { fileURLToPath } from "node:url";
@@ -59,7 +59,7 @@ describe("Enhanced Prop Injection", () => {
 		const componentWithoutProps = `
 import { component$ } from "@builder.io/qwik";
 import { Description } from "./components/description";
-import { isComponentPresent } from "./utils/qwik-analyzer";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
 
 export const Root = component$(() => {
   const isDescription = isComponentPresent(Description);
@@ -105,7 +105,7 @@ export const Root = component$(() => {
 		const componentWithProps = `
 import { component$ } from "@builder.io/qwik";
 import { Description } from "./components/description";
-import { isComponentPresent } from "./utils/qwik-analyzer";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
 
 export const Root = component$((props) => {
   const isDescription = isComponentPresent(Description);
@@ -173,4 +173,125 @@ export const Root = component$(() => {
 			expect(true).toBe(false);
 		}
 	});
+
+	test("inserted props parameter should not use an explicit any", async () => {
+		const componentWithoutProps = `
+import { component$ } from "@builder.io/qwik";
+import { Description } from "./components/description";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+export const Root = component$(() => {
+  const isDescription = isComponentPresent(Description);
+  return <div>Test</div>;
+});
+    `.trim();
+
+		const testFilePath = path.join(tempDir, "root5.tsx");
+		fs.writeFileSync(testFilePath, componentWithoutProps);
+		const result5 = analyzeAndTransformCode(componentWithoutProps, testFilePath);
+
+		expect(result5).toContain("component$((props) =>");
+		expect(result5).not.toContain(": any");
+	});
+
+	test("configured props parameter should be inserted verbatim", async () => {
+		const componentWithoutProps = `
+import { component$, type PropsOf } from "@builder.io/qwik";
+import { Description } from "./components/description";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+export const Root = component$(() => {
+  const isDescription = isComponentPresent(Description);
+  return <div>Test</div>;
+});
+    `.trim();
+
+		const testFilePath = path.join(tempDir, "root6.tsx");
+		fs.writeFileSync(testFilePath, componentWithoutProps);
+
+		configureAnalyzer({ propsParameter: "props: PropsOf<typeof Root>" });
+		try {
+			const result6 = analyzeAndTransformCode(
+				componentWithoutProps,
+				testFilePath,
+			);
+
+			expect(result6).toContain(
+				"component$((props: PropsOf<typeof Root>) =>",
+			);
+			expect(result6).toContain("props.__qwik_analyzer_has_Description");
+		} finally {
+			configureAnalyzer({});
+		}
+	});
+
+	test("propsParameter must bind a single name", () => {
+		for (const propsParameter of ["{ label }: Props", "props = {}", "a, b", "props) => {}; (x"]) {
+			expect(() => configureAnalyzer({ propsParameter })).toThrow(/propsParameter/);
+		}
+		configureAnalyzer({});
+	});
+
+	test("propsType annotates the inserted props parameter without `any`", async () => {
+		const rootPath = path.resolve(
+			__dirname,
+			"../qwik-app/src/components/dummy-comp/labelled-root.tsx",
+		);
+		const rootCode = fs.readFileSync(rootPath, "utf-8");
+		const cases = [
+			{ propsType: undefined, parameter: "component$((props) =>" },
+			{ propsType: "record", parameter: "component$((props: Record<string, unknown>) =>" },
+			{ propsType: "declared", parameter: "component$((props: LabelledRootProps) =>" },
+			{ propsType: "PropsOf<typeof LabelledRoot>", parameter: "component$((props: PropsOf<typeof LabelledRoot>) =>" },
+		];
+
+		for (const { propsType, parameter } of cases) {
+			configureAnalyzer({ propsType });
+			try {
+				const result = analyzeAndTransformCode(rootCode, rootPath);
+				expect(result).toContain(parameter);
+				// @typescript-eslint/no-explicit-any
+				expect(result).not.toMatch(/:\s*any\b/);
+			} finally {
+				configureAnalyzer({});
+			}
+		}
+	});
+
+	test("declared propsType prefers the component$ type argument and skips JavaScript files", async () => {
+		const component = `
+import { component$ } from "@builder.io/qwik";
+import { Description } from "./components/description";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+type SwitchOptions = { checked?: boolean };
+
+export const Root = component$<SwitchOptions>(() => {
+  const isDescription = isComponentPresent(Description);
+  return <div>Test</div>;
+});
+    `.trim();
+		const tsxPath = path.join(tempDir, "declared-root.tsx");
+		const jsxPath = path.join(tempDir, "declared-root.jsx");
+		const jsxComponent = component
+			.replace(/^type .*$/m, "")
+			.replace("<SwitchOptions>", "");
+		fs.writeFileSync(tsxPath, component);
+		fs.writeFileSync(jsxPath, jsxComponent);
+
+		configureAnalyzer({ propsType: "declared" });
+		try {
+			expect(analyzeAndTransformCode(component, tsxPath)).toContain(
+				"component$<SwitchOptions>((props: SwitchOptions) =>",
+			);
+			expect(analyzeAndTransformCode(jsxComponent, jsxPath)).toContain(
+				"component$((props) =>",
+			);
+		} finally {
+			configureAnalyzer({});
+		}
+
+		expect(() => configureAnalyzer({ propsType: "" })).toThrow(/propsType/);
+		configureAnalyzer({});
+	});
 });
@@ -1,5 +1,10 @@
 import { test, expect, describe, beforeAll, afterAll } from "vitest";
-import { analyzeAndTransformCode } from "../index.cjs";
+import {
+  analyzeAndTransformCode,
+  analyzeFile,
+  collectNamespaces,
+  configureAnalyzer,
+} from "../index.cjs";
 import fs from "node:fs";
 import path from "node:path";
 import { fileURLToPath } from "node:url";
@@ -138,4 +143,457 @@ describe("Real World Scenarios", () => {
     const hasPropInjection = result.includes("__qwik_analyzer_has_");
     console.log("🔍 Has prop injection:", hasPropInjection);
   });
-});
\ No newline at end of file
+
+  test("user-supplied presence props are respected by default", async () => {
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/user_prop_example.tsx");
+    const exampleCode = fs.readFileSync(examplePath, "utf-8");
+
+    const result = analyzeAndTransformCode(exampleCode, examplePath);
+
+    // The hand-written value wins and no duplicate attribute is injected
+    expect(result).toContain("__qwik_analyzer_has_Description={false}");
+    expect(result).not.toContain("__qwik_analyzer_has_Description={true}");
+    expect(result.match(/__qwik_analyzer_has_Description=/g)).toHaveLength(1);
+  });
+
+  test("user-supplied presence props are replaced in override mode", async () => {
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/user_prop_example.tsx");
+    const exampleCode = fs.readFileSync(examplePath, "utf-8");
+
+    configureAnalyzer({ existingProps: "override" });
+    try {
+      const result = analyzeAndTransformCode(exampleCode, examplePath);
+
+      expect(result).toContain("__qwik_analyzer_has_Description={true}");
+      expect(result).not.toContain("__qwik_analyzer_has_Description={false}");
+      expect(result.match(/__qwik_analyzer_has_Description=/g)).toHaveLength(1);
+    } finally {
+      configureAnalyzer({});
+    }
+  });
+
+  test("a Root with no present children is left untouched when injectOnlyWhenPresent is set", async () => {
+    const emptyPath = path.resolve(__dirname, "../qwik-app/src/examples/empty_root_example.tsx");
+    const emptyCode = fs.readFileSync(emptyPath, "utf-8");
+    const absentPath = path.resolve(__dirname, "../qwik-app/src/examples/absent_example.tsx");
+    const absentCode = fs.readFileSync(absentPath, "utf-8");
+
+    expect(analyzeAndTransformCode(emptyCode, emptyPath)).toContain(
+      "__qwik_analyzer_has_Description={false}",
+    );
+
+    configureAnalyzer({ injectOnlyWhenPresent: true });
+    try {
+      expect(analyzeAndTransformCode(emptyCode, emptyPath)).toBe(emptyCode);
+
+      // A single present child still gets every prop, including the false ones
+      const absentResult = analyzeAndTransformCode(absentCode, absentPath);
+      expect(absentResult).toContain("__qwik_analyzer_has_Title={true}");
+      expect(absentResult).toContain("__qwik_analyzer_has_Description={false}");
+    } finally {
+      configureAnalyzer({});
+    }
+  });
+
+  test("the Root is found through the index export, not files that merely mention root", async () => {
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/checkbox_kit_example.tsx");
+    const code = fs.readFileSync(examplePath, "utf-8");
+
+    const result = analyzeAndTransformCode(code, examplePath);
+    expect(result).toContain("<CheckboxKit.Root __qwik_analyzer_has_Description={true}>");
+    // routes.ts checks for Title but isn't where the Root is defined
+    expect(result).not.toContain("__qwik_analyzer_has_Title");
+
+    const checks = analyzeFile(examplePath).presenceChecks;
+    expect(checks.map((check) => path.basename(check.sourceFile))).toEqual(["checkbox-root.tsx"]);
+  });
+
+  test("a Root from a default-exported namespace object is resolved through the index", async () => {
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/default_object_example.tsx");
+    const code = fs.readFileSync(examplePath, "utf-8");
+
+    expect(analyzeAndTransformCode(code, examplePath)).toContain(
+      "<Switch.Root __qwik_analyzer_has_Description={true}>",
+    );
+
+    const checks = analyzeFile(examplePath).presenceChecks;
+    expect(checks.map((check) => path.basename(check.sourceFile))).toEqual(["root.tsx"]);
+  });
+
+  test("a default and a named import of one module resolve to their own exports", async () => {
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/default_and_named_import_example.tsx");
+    const code = fs.readFileSync(examplePath, "utf-8");
+
+    // `Toggle.Root` is the default export's Root, not the module's named `Root` export
+    expect(analyzeAndTransformCode(code, examplePath)).toContain(
+      "<Toggle.Root __qwik_analyzer_has_Description={true}>",
+    );
+
+    const checks = analyzeFile(examplePath).presenceChecks;
+    expect(checks.map((check) => path.basename(check.sourceFile))).toEqual(["root.tsx"]);
+  });
+
+  test("a namespace object declared `as const` is followed to the Root's file", async () => {
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/const_object_example.tsx");
+    const code = fs.readFileSync(examplePath, "utf-8");
+
+    // Strict scoping leaves checks found only by scanning the module directory unresolved
+    configureAnalyzer({ strictScoping: true });
+    try {
+      expect(analyzeAndTransformCode(code, examplePath)).toContain(
+        "<Radio.Root __qwik_analyzer_has_Legend={true}>",
+      );
+      expect(analyzeFile(examplePath).diagnostics).toEqual([]);
+    } finally {
+      configureAnalyzer({});
+    }
+  });
+
+  test("a barrel's source-less export list is followed through the imports it re-exports", async () => {
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/export_list_example.tsx");
+    const code = fs.readFileSync(examplePath, "utf-8");
+
+    configureAnalyzer({ strictScoping: true });
+    try {
+      expect(analyzeAndTransformCode(code, examplePath)).toContain("<Root __qwik_analyzer_has_Hint={true}>");
+      expect(analyzeFile(examplePath).diagnostics).toEqual([]);
+    } finally {
+      configureAnalyzer({});
+    }
+  });
+
+  test("type-only re-exports in a barrel don't shadow the components they share a name with", async () => {
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/typed_barrel_example.tsx");
+    const code = fs.readFileSync(examplePath, "utf-8");
+
+    expect(analyzeAndTransformCode(code, examplePath)).toContain("<Tip.Root __qwik_analyzer_has_Hint={true}>");
+
+    const result = analyzeFile(examplePath);
+    expect(result.presenceChecks.map((check) => path.basename(check.sourceFile))).toEqual(["root.tsx"]);
+    expect(result.diagnostics).toEqual([]);
+  });
+
+  test("a library imported under two names transforms the Roots of both namespaces", async () => {
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/aliased_namespaces_example.tsx");
+    const result = analyzeAndTransformCode(fs.readFileSync(examplePath, "utf-8"), examplePath);
+
+    // Each Root gets the presence of its own children
+    expect(result).toContain("<CheckboxKit.Root __qwik_analyzer_has_Description={false}>");
+    expect(result.match(/<CB\.Root __qwik_analyzer_has_Description=\{(true|false)\}>/g)).toEqual([
+      "<CB.Root __qwik_analyzer_has_Description={true}>",
+      "<CB.Root __qwik_analyzer_has_Description={false}>",
+    ]);
+    expect(analyzeFile(examplePath).diagnostics).toEqual([]);
+  });
+
+  test("a child rendered from a <Resource> callback prop counts as present", async () => {
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/resource_example.tsx");
+    const code = fs.readFileSync(examplePath, "utf-8");
+
+    expect(analyzeAndTransformCode(code, examplePath)).toContain(
+      "<CheckboxKit.Root __qwik_analyzer_has_Description={true}>",
+    );
+
+    configureAnalyzer({ strictScoping: true });
+    try {
+      expect(analyzeAndTransformCode(code, examplePath)).toContain(
+        "<CheckboxKit.Root __qwik_analyzer_has_Description={true}>",
+      );
+    } finally {
+      configureAnalyzer({});
+    }
+  });
+
+  test("a locally declared isComponentPresent is neither rewritten nor treated as a presence check", async () => {
+    const rootPath = path.resolve(__dirname, "../qwik-app/src/examples/local_presence.tsx");
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/local_presence_example.tsx");
+    const rootCode = fs.readFileSync(rootPath, "utf-8");
+    const code = fs.readFileSync(examplePath, "utf-8");
+
+    expect(analyzeAndTransformCode(rootCode, rootPath)).toBe(rootCode);
+    expect(analyzeAndTransformCode(code, examplePath)).toBe(code);
+    expect(analyzeFile(examplePath).presenceChecks).toEqual([]);
+  });
+
+  test("with namedSlots, children are routed through the Slot names the Root renders", async () => {
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/named_slot_example.tsx");
+    const code = fs.readFileSync(examplePath, "utf-8");
+
+    // By default any Title under the Root counts, even one sent to a slot that doesn't exist
+    expect(analyzeAndTransformCode(code, examplePath)).toContain(
+      "<CardKit.Root __qwik_analyzer_has_Description={true} __qwik_analyzer_has_Title={true}>",
+    );
+
+    configureAnalyzer({ namedSlots: true });
+    try {
+      expect(analyzeAndTransformCode(code, examplePath)).toContain(
+        "<CardKit.Root __qwik_analyzer_has_Description={true} __qwik_analyzer_has_Title={false}>",
+      );
+    } finally {
+      configureAnalyzer({});
+    }
+  });
+
+  test("a Root renamed on import from a barrel receives its presence props", async () => {
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/renamed_import_example.tsx");
+    const code = fs.readFileSync(examplePath, "utf-8");
+
+    expect(analyzeAndTransformCode(code, examplePath)).toContain(
+      "<AccordionRoot __qwik_analyzer_has_Item={true}>",
+    );
+
+    const checks = analyzeFile(examplePath).presenceChecks;
+    expect(checks).toEqual([
+      expect.objectContaining({ component: "Item", presence: "present" }),
+    ]);
+    expect(path.basename(checks[0].sourceFile)).toBe("root.tsx");
+  });
+
+  test("flat-named compound components are injected without a namespace", async () => {
+    const examplesDir = path.resolve(__dirname, "../qwik-app/src/examples");
+    const presentPath = path.join(examplesDir, "flat_named_example.tsx");
+    const absentPath = path.join(examplesDir, "flat_named_absent_example.tsx");
+
+    expect(analyzeAndTransformCode(fs.readFileSync(presentPath, "utf-8"), presentPath)).toContain(
+      "<CheckboxRoot __qwik_analyzer_has_CheckboxDescription={true}>",
+    );
+    // The Root's own check for the description doesn't count as rendering it
+    expect(analyzeAndTransformCode(fs.readFileSync(absentPath, "utf-8"), absentPath)).toContain(
+      "<CheckboxRoot __qwik_analyzer_has_CheckboxDescription={false}>",
+    );
+  });
+
+  test("a check for a default import renamed locally is named after the export", async () => {
+    const examplePath = path.resolve(
+      __dirname,
+      "../qwik-app/src/examples/renamed_default_target_example.tsx",
+    );
+    const rootPath = path.resolve(__dirname, "../qwik-app/src/components/legend-kit/root.tsx");
+
+    // root.tsx checks `isComponentPresent(Caption)` with `import Caption from "./legend"`
+    expect(analyzeAndTransformCode(fs.readFileSync(examplePath, "utf-8"), examplePath)).toContain(
+      "<LegendKit.Root __qwik_analyzer_has_Legend={true}>",
+    );
+    expect(analyzeAndTransformCode(fs.readFileSync(rootPath, "utf-8"), rootPath)).toContain(
+      "isComponentPresent(Caption, props.__qwik_analyzer_has_Legend)",
+    );
+  });
+
+  test("a component that is the default export of a barrel is followed to its definition", async () => {
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/default_barrel_example.tsx");
+    const code = fs.readFileSync(examplePath, "utf-8");
+
+    expect(analyzeAndTransformCode(code, examplePath)).toContain(
+      "<Notice __qwik_analyzer_has_Icon={true}>",
+    );
+
+    const checks = analyzeFile(examplePath).presenceChecks;
+    expect(checks.map((check) => path.basename(check.sourceFile))).toEqual(["notice.tsx"]);
+  });
+
+  test("an isComponentPresent imported from an unrelated package is left untouched", async () => {
+    const rootPath = path.resolve(__dirname, "../qwik-app/src/examples/foreign_presence.tsx");
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/foreign_presence_example.tsx");
+    const rootCode = fs.readFileSync(rootPath, "utf-8");
+    const code = fs.readFileSync(examplePath, "utf-8");
+
+    expect(analyzeAndTransformCode(rootCode, rootPath)).toBe(rootCode);
+    expect(analyzeAndTransformCode(code, examplePath)).toBe(code);
+    expect(analyzeFile(examplePath).presenceChecks).toEqual([]);
+
+    configureAnalyzer({ runtimeModules: ["@acme/registry"] });
+    try {
+      expect(analyzeAndTransformCode(code, examplePath)).toContain(
+        "__qwik_analyzer_has_DummyComp_Description={true}",
+      );
+    } finally {
+      configureAnalyzer({});
+    }
+  });
+
+  test("a local helper sharing the isComponentPresent name is left untouched", async () => {
+    const rootPath = path.resolve(__dirname, "../qwik-app/src/examples/helper_presence.tsx");
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/helper_presence_example.tsx");
+    const rootCode = fs.readFileSync(rootPath, "utf-8");
+    const code = fs.readFileSync(examplePath, "utf-8");
+
+    expect(analyzeAndTransformCode(rootCode, rootPath)).toBe(rootCode);
+    expect(analyzeAndTransformCode(code, examplePath)).toBe(code);
+    expect(analyzeFile(examplePath).presenceChecks).toEqual([]);
+  });
+
+  test("a check taking several components reports them as a component and its alternatives", async () => {
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/alternatives_example.tsx");
+    const result = analyzeFile(examplePath);
+
+    expect(result.presenceChecks).toEqual([
+      expect.objectContaining({ component: "Description", alternatives: ["HelperText"], presence: "present" }),
+    ]);
+    expect(result.injectedProps).toEqual([
+      expect.objectContaining({
+        component: "Description",
+        alternatives: ["HelperText"],
+        propName: "__qwik_analyzer_has_Description_or_HelperText",
+      }),
+    ]);
+  });
+
+  test("a wrapper around a Root receives the presence props for its own usage", async () => {
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/wrapped_root_example.tsx");
+    const wrapperPath = path.resolve(__dirname, "../qwik-app/src/examples/wrapped_root.tsx");
+    const code = fs.readFileSync(examplePath, "utf-8");
+    const wrapperCode = fs.readFileSync(wrapperPath, "utf-8");
+
+    expect(analyzeAndTransformCode(code, examplePath)).toContain(
+      "<WrappedRoot __qwik_analyzer_has_Checkbox_Description={false} __qwik_analyzer_has_Description={true} __qwik_analyzer_has_Title={false}>",
+    );
+
+    // Inside the wrapper the children come through <Slot />, so the forwarded props are left alone
+    expect(analyzeAndTransformCode(wrapperCode, wrapperPath)).toBe(wrapperCode);
+    expect(analyzeFile(wrapperPath).presenceChecks.map((check) => check.presence)).toEqual([
+      "unknown",
+      "unknown",
+      "unknown",
+    ]);
+  });
+
+  test("a Root that also renders library Roots gets both kinds of transformation", async () => {
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/noted_root_example.tsx");
+    const rootPath = path.resolve(__dirname, "../qwik-app/src/examples/noted_root.tsx");
+    const code = fs.readFileSync(examplePath, "utf-8");
+    const rootCode = fs.readFileSync(rootPath, "utf-8");
+
+    // Definition side: its own check reads the injected prop. Consumer side: the Root it renders
+    // directly gets its props, while the one wrapped around <Slot /> keeps the forwarded ones.
+    const root = analyzeAndTransformCode(rootCode, rootPath);
+    expect(root).toContain("isComponentPresent(Note, props.__qwik_analyzer_has_Note)");
+    expect(root).toContain("<CheckboxKit.Root __qwik_analyzer_has_Description={false}>");
+    expect(root).toContain("<DummyComp.Root {...props}>");
+    expect(() => collectNamespaces(root, rootPath)).not.toThrow();
+
+    // Its usages receive the props for its own check and those of the Root it wraps
+    const consumer = analyzeAndTransformCode(code, examplePath);
+    expect(consumer).toContain(
+      "<NotedRoot __qwik_analyzer_has_Checkbox_Description={false} __qwik_analyzer_has_Description={false} __qwik_analyzer_has_Note={true} __qwik_analyzer_has_Title={true}>",
+    );
+    expect(() => collectNamespaces(consumer, examplePath)).not.toThrow();
+  });
+
+  test("a child the Root renders through its own composed components counts as present", async () => {
+    // page -> ComposedKit.Root -> Panel -> Description, with nothing passed as children
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/composed_root_example.tsx");
+    const code = fs.readFileSync(examplePath, "utf-8");
+
+    expect(analyzeAndTransformCode(code, examplePath)).toContain(
+      "<ComposedKit.Root __qwik_analyzer_has_Description={true} __qwik_analyzer_has_Title={false}>",
+    );
+
+    const result = analyzeFile(examplePath);
+    expect(result.foundDirectly).toBe(false);
+    expect(result.presenceChecks.map(({ component, presence }) => ({ component, presence }))).toEqual([
+      { component: "Description", presence: "present" },
+      { component: "Title", presence: "absent" },
+    ]);
+  });
+
+  test("a fallback the Root renders when its check fails doesn't count; the slotted children decide", async () => {
+    const providedPath = path.resolve(__dirname, "../qwik-app/src/examples/default_slot_example.tsx");
+    const fallbackPath = path.resolve(__dirname, "../qwik-app/src/examples/default_slot_fallback_example.tsx");
+
+    for (const strictScoping of [false, true]) {
+      configureAnalyzer({ strictScoping });
+      try {
+        expect(analyzeAndTransformCode(fs.readFileSync(providedPath, "utf-8"), providedPath)).toContain(
+          "<Fallback.Root __qwik_analyzer_has_Description={true}>",
+        );
+        expect(analyzeAndTransformCode(fs.readFileSync(fallbackPath, "utf-8"), fallbackPath)).toContain(
+          "<Fallback.Root __qwik_analyzer_has_Description={false}>",
+        );
+      } finally {
+        configureAnalyzer({});
+      }
+    }
+  });
+
+  test("a single-file compound component imported relatively gives each part only its own checks", async () => {
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/single_file_kit_example.tsx");
+    const code = fs.readFileSync(examplePath, "utf-8");
+
+    const result = analyzeAndTransformCode(code, examplePath);
+    expect(result).toContain(
+      "<Toggle.Root __qwik_analyzer_has_Hint={false} __qwik_analyzer_has_Label={true}>",
+    );
+    expect(result).toContain("<Toggle.Label>Dark mode</Toggle.Label>");
+
+    const checks = analyzeFile(examplePath).presenceChecks;
+    expect(checks.map(({ component, presence }) => ({ component, presence }))).toEqual([
+      { component: "Label", presence: "present" },
+      { component: "Hint", presence: "absent" },
+    ]);
+    expect(path.basename(checks[0].sourceFile)).toBe("toggle.tsx");
+  });
+
+  test("each of several Roots on a page gets the presence of its own children", async () => {
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/multiple_roots_example.tsx");
+    const code = fs.readFileSync(examplePath, "utf-8");
+
+    const result = analyzeAndTransformCode(code, examplePath);
+    expect(result).toContain("<CheckboxKit.Root __qwik_analyzer_has_Description={true}>");
+    expect(result).toContain(
+      "<CheckboxKit.Root __qwik_analyzer_has_Description={false}>{/* empty */}</CheckboxKit.Root>",
+    );
+
+    const injected = analyzeFile(examplePath).injectedProps.map((prop) => prop.value);
+    expect(injected).toEqual([true, false]);
+  });
+
+  test("children passed through a spread props object count as rendered inside the Root", async () => {
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/spread_children_example.tsx");
+    const code = fs.readFileSync(examplePath, "utf-8");
+
+    for (const strictScoping of [false, true]) {
+      configureAnalyzer({ strictScoping });
+      try {
+        const result = analyzeAndTransformCode(code, examplePath);
+        expect(result).toContain("<CheckboxKit.Root {...rootProps} __qwik_analyzer_has_Description={true} />");
+        expect(result).toContain(
+          "<CheckboxKit.Root __qwik_analyzer_has_Description={false}>{/* empty */}</CheckboxKit.Root>",
+        );
+
+        const analysis = analyzeFile(examplePath);
+        expect(analysis.diagnostics).toEqual([]);
+        expect(analysis.presenceChecks).toEqual([expect.objectContaining({ presence: "present" })]);
+      } finally {
+        configureAnalyzer({});
+      }
+    }
+  });
+
+  test("a Root holding only whitespace or comments has no children to project", async () => {
+    for (const [example, expected] of [
+      ["root_whitespace_only_example.tsx", false],
+      ["root_comment_only_example.tsx", false],
+      ["root_with_child_example.tsx", true],
+    ] as const) {
+      const examplePath = path.resolve(__dirname, "../qwik-app/src/examples", example);
+      const code = fs.readFileSync(examplePath, "utf-8");
+
+      expect(analyzeAndTransformCode(code, examplePath)).toContain(
+        `<CheckboxKit.Root __qwik_analyzer_has_Description={${expected}}>`,
+      );
+    }
+  });
+
+  test("a bare specifier that tsconfig paths map into the project is analyzed as local", async () => {
+    const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/path_mapped_example.tsx");
+    const code = fs.readFileSync(examplePath, "utf-8");
+
+    expect(analyzeAndTransformCode(code, examplePath)).toContain("<Tip.Root __qwik_analyzer_has_Hint={true}>");
+
+    const result = analyzeFile(examplePath);
+    expect(result.diagnostics).toEqual([]);
+    expect(path.relative(path.resolve(__dirname, "../qwik-app/src"), result.presenceChecks[0].sourceFile)).toBe(
+      path.join("components", "tip-kit", "root.tsx"),
+    );
+  });
+});
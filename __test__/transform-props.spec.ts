@@ -88,7 +88,7 @@ describe("Prop Injection Approach", () => {
 		const componentCode = `
 import { component$, Slot } from "@builder.io/qwik";
 import { Description } from "./description";
-import { isComponentPresent } from "../../utils/qwik-analyzer";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
 
 export const Root = component$((props) => {
   const isDescription = isComponentPresent(Description);
@@ -1,5 +1,16 @@
 import { test, expect, beforeAll, afterAll } from "vitest";
-import { analyzeAndTransformCode } from "../index.cjs";
+import {
+	analyzeAndTransformCode,
+	analyzeAndTransformFiles,
+	beginCacheSession,
+	clearCaches,
+	collectNamespaces,
+	collectTransformations,
+	configureAnalyzer,
+	endCacheSession,
+	invalidateCachedFile,
+	previewTransform,
+} from "../index.cjs";
 import fs from "node:fs";
 import path from "node:path";
 import { fileURLToPath } from "node:url";
@@ -54,7 +65,7 @@ test("analyzeAndTransformCode function", async () => {
 	const code = `
 import { component$, Slot } from "@builder.io/qwik";
 import { Description } from "./components/description";
-import { isComponentPresent } from "./utils/qwik-analyzer";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
 
 export const Root = component$(() => {
   const isDescription = isComponentPresent(Description);
@@ -84,3 +95,1158 @@ export const Root = component$(() => {
 		expect(result).not.toBe(code);
 	}
 });
+
+test("multiple isComponentPresent calls and the props parameter apply without offset drift", async () => {
+	fs.writeFileSync(
+		path.join(tempDir, "components", "title.tsx"),
+		`
+import { component$ } from "@builder.io/qwik";
+
+export const Title = component$(() => {
+  return <div>Title Component</div>;
+});
+  `.trim(),
+	);
+
+	const code = `
+import { component$, Slot } from "@builder.io/qwik";
+import { Description } from "./components/description";
+import { Title } from "./components/title";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+export const Root = component$(() => {
+  const isDescription = isComponentPresent(Description);
+  const isTitle = isComponentPresent(Title);
+  const isDescriptionAgain = isComponentPresent(Description);
+  return <div><Slot /></div>;
+});
+  `.trim();
+
+	const testFilePath = path.join(tempDir, "root-three-calls.tsx");
+	fs.writeFileSync(testFilePath, code);
+	const result = analyzeAndTransformCode(code, testFilePath);
+
+	expect(result).toBe(
+		`
+import { component$, Slot } from "@builder.io/qwik";
+import { Description } from "./components/description";
+import { Title } from "./components/title";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+export const Root = component$((props) => {
+  const isDescription = isComponentPresent(Description, props.__qwik_analyzer_has_Description);
+  const isTitle = isComponentPresent(Title, props.__qwik_analyzer_has_Title);
+  const isDescriptionAgain = isComponentPresent(Description, props.__qwik_analyzer_has_Description);
+  return <div><Slot /></div>;
+});
+  `.trim(),
+	);
+});
+
+test("a Root that destructures its props reads presence props through an added rest element", async () => {
+	const rootPath = path.resolve(
+		__dirname,
+		"../qwik-app/src/components/labeled-kit/root.tsx",
+	);
+	const examplePath = path.resolve(
+		__dirname,
+		"../qwik-app/src/examples/labeled_example.tsx",
+	);
+
+	const root = analyzeAndTransformCode(fs.readFileSync(rootPath, "utf-8"), rootPath);
+	expect(root).toContain(
+		"component$(({ label, disabled, ...props }: LabeledRootProps) =>",
+	);
+	expect(root).toContain(
+		"isComponentPresent(Hint, props.__qwik_analyzer_has_Hint)",
+	);
+
+	expect(
+		analyzeAndTransformCode(fs.readFileSync(examplePath, "utf-8"), examplePath),
+	).toContain('<Labeled.Root label="Email" __qwik_analyzer_has_Hint={true}>');
+});
+
+test("a Root with only a rest parameter reads its props through it instead of gaining a parameter", async () => {
+	const rootPath = path.resolve(
+		__dirname,
+		"../qwik-app/src/components/relay-kit/root.tsx",
+	);
+	const examplePath = path.resolve(
+		__dirname,
+		"../qwik-app/src/examples/relay_example.tsx",
+	);
+
+	const root = analyzeAndTransformCode(fs.readFileSync(rootPath, "utf-8"), rootPath);
+	expect(root).toContain(
+		"component$((...args: [Record<string, unknown>]) =>",
+	);
+	expect(root).toContain(
+		"isComponentPresent(Hint, args[0].__qwik_analyzer_has_Hint)",
+	);
+
+	expect(
+		analyzeAndTransformCode(fs.readFileSync(examplePath, "utf-8"), examplePath),
+	).toContain("<Relay.Root __qwik_analyzer_has_Hint={true}>");
+});
+
+test("usePresence receives the injected prop as its first-render value", async () => {
+	const rootPath = path.resolve(
+		__dirname,
+		"../qwik-app/src/components/disclosure-kit/root.tsx",
+	);
+	const examplePath = path.resolve(
+		__dirname,
+		"../qwik-app/src/examples/disclosure_example.tsx",
+	);
+
+	expect(
+		analyzeAndTransformCode(fs.readFileSync(rootPath, "utf-8"), rootPath),
+	).toContain(
+		"usePresence(Details, props.__qwik_analyzer_has_Details)",
+	);
+
+	expect(
+		analyzeAndTransformCode(fs.readFileSync(examplePath, "utf-8"), examplePath),
+	).toContain("<Disclosure.Root __qwik_analyzer_has_Details={true}>");
+});
+
+test("presence reads use the component's own props binding and skip shadowed ones", async () => {
+	const code = `
+import { component$, useStore } from "@builder.io/qwik";
+import { Description } from "./components/description";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+export const Renamed = component$((p) => {
+  return <div>{isComponentPresent(Description) ? "yes" : "no"}</div>;
+});
+
+export const Rest = component$(({ label, ...rest }) => {
+  return <div>{isComponentPresent(Description) ? label : "no"}</div>;
+});
+
+export const Shadowed = component$(() => {
+  const props = useStore({ open: false });
+  return <div>{isComponentPresent(Description) ? "yes" : "no"}</div>;
+});
+  `.trim();
+
+	const testFilePath = path.join(tempDir, "props-bindings.tsx");
+	fs.writeFileSync(testFilePath, code);
+	const result = analyzeAndTransformCode(code, testFilePath);
+
+	expect(result).toContain(
+		"component$((p) => {\n  return <div>{isComponentPresent(Description, p.__qwik_analyzer_has_Description)",
+	);
+	expect(result).toContain(
+		"component$(({ label, ...rest }) => {\n  return <div>{isComponentPresent(Description, rest.__qwik_analyzer_has_Description)",
+	);
+	// `props` is the store here, so the call keeps its runtime value
+	expect(result).toContain(
+		"component$(() => {\n  const props = useStore({ open: false });\n  return <div>{isComponentPresent(Description) ?",
+	);
+});
+
+test("configured custom extensions are parsed with the mapped syntax", async () => {
+	const code = `
+import { component$, Slot } from "@builder.io/qwik";
+import { Description } from "./components/description";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+interface RootProps {
+  label: string;
+}
+
+export const Root = component$(() => {
+  const isDescription: boolean = isComponentPresent(Description);
+  return <div><Slot /></div>;
+});
+  `.trim();
+
+	const testFilePath = path.join(tempDir, "root.qwik");
+	fs.writeFileSync(testFilePath, code);
+
+	configureAnalyzer({ sourceExtensions: { qwik: "tsx" } });
+	try {
+		const result = analyzeAndTransformCode(code, testFilePath);
+
+		expect(result).toContain("component$((props) =>");
+		expect(result).toContain(
+			"isComponentPresent(Description, props.__qwik_analyzer_has_Description)",
+		);
+	} finally {
+		configureAnalyzer({});
+	}
+});
+
+test("isComponentPresent calls inside useTask$ use the enclosing component's props", async () => {
+	const code = `
+import { component$, Slot, useSignal, useTask$ } from "@builder.io/qwik";
+import { Description } from "./components/description";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+const hasDescriptionOutsideComponent = () => isComponentPresent(Description);
+
+export const Root = component$(() => {
+  const hasDescription = useSignal(false);
+  useTask$(() => {
+    hasDescription.value = isComponentPresent(Description);
+  });
+  return <div><Slot /></div>;
+});
+  `.trim();
+
+	const testFilePath = path.join(tempDir, "root-task.tsx");
+	fs.writeFileSync(testFilePath, code);
+	const result = analyzeAndTransformCode(code, testFilePath);
+
+	expect(result).toBe(
+		`
+import { component$, Slot, useSignal, useTask$ } from "@builder.io/qwik";
+import { Description } from "./components/description";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+const hasDescriptionOutsideComponent = () => isComponentPresent(Description);
+
+export const Root = component$((props) => {
+  const hasDescription = useSignal(false);
+  useTask$(() => {
+    hasDescription.value = isComponentPresent(Description, props.__qwik_analyzer_has_Description);
+  });
+  return <div><Slot /></div>;
+});
+  `.trim(),
+	);
+});
+
+test("named re-exports of a default export are followed through the chain to the Root definition", async () => {
+	const accordionDir = path.join(tempDir, "components", "accordion");
+	fs.mkdirSync(accordionDir, { recursive: true });
+	fs.writeFileSync(
+		path.join(accordionDir, "index.ts"),
+		`
+export { default as Root } from "./root";
+export { Description } from "../description";
+  `.trim(),
+	);
+	fs.writeFileSync(
+		path.join(accordionDir, "root.tsx"),
+		`export { default } from "./root-impl";`,
+	);
+	fs.writeFileSync(
+		path.join(accordionDir, "root-impl.tsx"),
+		`
+import { component$, Slot } from "@builder.io/qwik";
+import { Description } from "../description";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+export default component$(() => {
+  const isDescription = isComponentPresent(Description);
+  return <div><Slot /></div>;
+});
+  `.trim(),
+	);
+
+	const code = `
+import { component$ } from "@builder.io/qwik";
+import * as Accordion from "./components/accordion";
+
+export default component$(() => {
+  return (
+    <Accordion.Root>
+      <Accordion.Description />
+    </Accordion.Root>
+  );
+});
+  `.trim();
+
+	const testFilePath = path.join(tempDir, "default-reexport.tsx");
+	fs.writeFileSync(testFilePath, code);
+	const result = analyzeAndTransformCode(code, testFilePath);
+
+	expect(result).toContain(
+		"<Accordion.Root __qwik_analyzer_has_Description={true}>",
+	);
+});
+
+test("CRLF line endings keep every insertion on the right byte", async () => {
+	const rootCode = `
+import { component$, Slot } from "@builder.io/qwik";
+import { Description } from "./components/description";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+export const Root = component$(() => {
+  const isDescription = isComponentPresent(Description);
+  return <div><Slot /></div>;
+});
+  `
+		.trim()
+		.split("\n")
+		.join("\r\n");
+
+	const rootFilePath = path.join(tempDir, "crlf-root.tsx");
+	fs.writeFileSync(rootFilePath, rootCode);
+
+	expect(analyzeAndTransformCode(rootCode, rootFilePath)).toBe(
+		`
+import { component$, Slot } from "@builder.io/qwik";
+import { Description } from "./components/description";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+export const Root = component$((props) => {
+  const isDescription = isComponentPresent(Description, props.__qwik_analyzer_has_Description);
+  return <div><Slot /></div>;
+});
+  `
+			.trim()
+			.split("\n")
+			.join("\r\n"),
+	);
+
+	const consumerCode = `
+import { component$ } from "@builder.io/qwik";
+import { Root } from "./crlf-root";
+
+export default component$(() => {
+  return (
+    <Root
+      class="root"
+    >
+      <span />
+    </Root>
+  );
+});
+  `
+		.trim()
+		.split("\n")
+		.join("\r\n");
+
+	const consumerFilePath = path.join(tempDir, "crlf-consumer.tsx");
+	fs.writeFileSync(consumerFilePath, consumerCode);
+
+	expect(analyzeAndTransformCode(consumerCode, consumerFilePath)).toMatch(
+		/class="root"\r\n {5}__qwik_analyzer_has_Description=\{(true|false)\}>\r\n {6}<span \/>/,
+	);
+});
+
+test("configured projectRoot is used for ~/ imports inside a nested package", async () => {
+	const monorepoRoot = path.join(tempDir, "monorepo");
+	const kitDir = path.join(monorepoRoot, "src", "components", "kit");
+	const appDir = path.join(monorepoRoot, "packages", "app");
+	fs.mkdirSync(kitDir, { recursive: true });
+	fs.mkdirSync(path.join(appDir, "src"), { recursive: true });
+
+	fs.writeFileSync(
+		path.join(monorepoRoot, "package.json"),
+		JSON.stringify({ name: "monorepo" }),
+	);
+	fs.writeFileSync(
+		path.join(appDir, "package.json"),
+		JSON.stringify({ name: "app" }),
+	);
+	fs.writeFileSync(
+		path.join(kitDir, "index.ts"),
+		`
+import { Description } from "./description";
+import { Root } from "./root";
+
+export const Kit = { Root, Description };
+  `.trim(),
+	);
+	fs.writeFileSync(
+		path.join(kitDir, "description.tsx"),
+		`
+import { component$ } from "@builder.io/qwik";
+
+export const Description = component$(() => <p>Description</p>);
+  `.trim(),
+	);
+	fs.writeFileSync(
+		path.join(kitDir, "root.tsx"),
+		`
+import { component$, Slot } from "@builder.io/qwik";
+import { Description } from "./description";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+export const Root = component$(() => {
+  const hasDescription = isComponentPresent(Description);
+  return <div><Slot /></div>;
+});
+  `.trim(),
+	);
+
+	const code = `
+import { component$ } from "@builder.io/qwik";
+import { Kit } from "~/components/kit";
+
+export default component$(() => {
+  return (
+    <Kit.Root>
+      <Kit.Description />
+    </Kit.Root>
+  );
+});
+  `.trim();
+
+	const testFilePath = path.join(appDir, "src", "page.tsx");
+	fs.writeFileSync(testFilePath, code);
+
+	// The nearest package.json belongs to the inner package, which has no src/components/kit
+	expect(analyzeAndTransformCode(code, testFilePath)).toBe(code);
+
+	configureAnalyzer({ projectRoot: monorepoRoot });
+	try {
+		expect(analyzeAndTransformCode(code, testFilePath)).toContain(
+			"<Kit.Root __qwik_analyzer_has_Description={true}>",
+		);
+	} finally {
+		configureAnalyzer({});
+	}
+});
+
+test("# subpath imports resolve through the nearest package.json imports field", async () => {
+	const appRoot = path.join(tempDir, "subpath-app");
+	const kitDir = path.join(appRoot, "src", "components", "kit");
+	fs.mkdirSync(kitDir, { recursive: true });
+
+	fs.writeFileSync(
+		path.join(appRoot, "package.json"),
+		JSON.stringify({
+			name: "subpath-app",
+			imports: {
+				"#components/*": "./src/components/*",
+				"#kit": { import: "./src/components/kit/index.ts" },
+			},
+		}),
+	);
+	fs.writeFileSync(
+		path.join(kitDir, "index.ts"),
+		`
+import { Description } from "./description";
+import { Root } from "./root";
+
+export const Kit = { Root, Description };
+  `.trim(),
+	);
+	fs.writeFileSync(
+		path.join(kitDir, "description.tsx"),
+		`
+import { component$ } from "@builder.io/qwik";
+
+export const Description = component$(() => <p>Description</p>);
+  `.trim(),
+	);
+	fs.writeFileSync(
+		path.join(kitDir, "root.tsx"),
+		`
+import { component$, Slot } from "@builder.io/qwik";
+import { Description } from "./description";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+export const Root = component$(() => {
+  const hasDescription = isComponentPresent(Description);
+  return <div><Slot /></div>;
+});
+  `.trim(),
+	);
+
+	const testFilePath = path.join(appRoot, "src", "page.tsx");
+
+	for (const specifier of ["#components/kit", "#kit"]) {
+		const code = `
+import { component$ } from "@builder.io/qwik";
+import { Kit } from "${specifier}";
+
+export default component$(() => {
+  return (
+    <Kit.Root>
+      <Kit.Description />
+    </Kit.Root>
+  );
+});
+  `.trim();
+
+		fs.writeFileSync(testFilePath, code);
+		expect(analyzeAndTransformCode(code, testFilePath)).toContain(
+			"<Kit.Root __qwik_analyzer_has_Description={true}>",
+		);
+	}
+});
+
+test("tsconfig paths aliases fall back to later targets when earlier ones don't exist", async () => {
+	const appRoot = path.join(tempDir, "paths-app");
+	const kitDir = path.join(appRoot, "packages", "legacy-ui", "src", "kit");
+	fs.mkdirSync(kitDir, { recursive: true });
+	fs.mkdirSync(path.join(appRoot, "packages", "ui", "src"), { recursive: true });
+	fs.mkdirSync(path.join(appRoot, "src"), { recursive: true });
+
+	fs.writeFileSync(
+		path.join(appRoot, "package.json"),
+		JSON.stringify({ name: "paths-app" }),
+	);
+	fs.writeFileSync(
+		path.join(appRoot, "tsconfig.json"),
+		JSON.stringify({
+			compilerOptions: {
+				baseUrl: ".",
+				paths: { "@ui/*": ["packages/ui/src/*", "packages/legacy-ui/src/*"] },
+			},
+		}),
+	);
+	fs.writeFileSync(
+		path.join(kitDir, "index.ts"),
+		`
+import { Description } from "./description";
+import { Root } from "./root";
+
+export const Kit = { Root, Description };
+  `.trim(),
+	);
+	fs.writeFileSync(
+		path.join(kitDir, "description.tsx"),
+		`
+import { component$ } from "@builder.io/qwik";
+
+export const Description = component$(() => <p>Description</p>);
+  `.trim(),
+	);
+	fs.writeFileSync(
+		path.join(kitDir, "root.tsx"),
+		`
+import { component$, Slot } from "@builder.io/qwik";
+import { Description } from "./description";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+export const Root = component$(() => {
+  const hasDescription = isComponentPresent(Description);
+  return <div><Slot /></div>;
+});
+  `.trim(),
+	);
+
+	const code = `
+import { component$ } from "@builder.io/qwik";
+import { Kit } from "@ui/kit";
+
+export default component$(() => {
+  return (
+    <Kit.Root>
+      <Kit.Description />
+    </Kit.Root>
+  );
+});
+  `.trim();
+
+	// The kit only exists under the second mapped directory
+	const testFilePath = path.join(appRoot, "src", "page.tsx");
+	fs.writeFileSync(testFilePath, code);
+	expect(analyzeAndTransformCode(code, testFilePath)).toContain(
+		"<Kit.Root __qwik_analyzer_has_Description={true}>",
+	);
+});
+
+test("configured presenceCallTemplate shapes the rewritten isComponentPresent call", async () => {
+	const code = `
+import { component$ } from "@builder.io/qwik";
+import { Description } from "./components/description";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+export const Root = component$(() => {
+  const isDescription = isComponentPresent(Description);
+  return <div>{isDescription && "Has description"}</div>;
+});
+  `.trim();
+
+	const testFilePath = path.join(tempDir, "template-root.tsx");
+	fs.writeFileSync(testFilePath, code);
+
+	expect(analyzeAndTransformCode(code, testFilePath)).toContain(
+		"isComponentPresent(Description, props.__qwik_analyzer_has_Description)",
+	);
+
+	configureAnalyzer({
+		propsParameter: "rootProps",
+		presenceCallTemplate: 'readPresence({arg}, () => {props}["{prop}"])',
+	});
+	try {
+		const result = analyzeAndTransformCode(code, testFilePath);
+		expect(result).toContain("component$((rootProps) =>");
+		expect(result).toContain(
+			'const isDescription = readPresence(Description, () => rootProps["__qwik_analyzer_has_Description"]);',
+		);
+	} finally {
+		configureAnalyzer({});
+	}
+
+	expect(() => configureAnalyzer({ presenceCallTemplate: "readPresence({arg})" })).toThrow(
+		/\{prop\}/,
+	);
+	configureAnalyzer({});
+});
+
+test("paths with .. segments through missing directories are normalized before comparing", async () => {
+	const appRoot = path.join(tempDir, "normalize-app");
+	const kitDir = path.join(appRoot, "src", "components", "kit");
+	fs.mkdirSync(kitDir, { recursive: true });
+
+	fs.writeFileSync(
+		path.join(appRoot, "package.json"),
+		JSON.stringify({ name: "normalize-app" }),
+	);
+	fs.writeFileSync(
+		path.join(kitDir, "index.ts"),
+		`
+import { Description } from "./description";
+import { Root } from "./root";
+
+export const Kit = { Root, Description };
+  `.trim(),
+	);
+	fs.writeFileSync(
+		path.join(kitDir, "description.tsx"),
+		`
+import { component$ } from "@builder.io/qwik";
+
+export const Description = component$(() => <p>Description</p>);
+  `.trim(),
+	);
+	fs.writeFileSync(
+		path.join(kitDir, "root.tsx"),
+		`
+import { component$, Slot } from "@builder.io/qwik";
+import { Description } from "./description";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+export const Root = component$(() => {
+  const hasDescription = isComponentPresent(Description);
+  return <div><Slot /></div>;
+});
+  `.trim(),
+	);
+
+	// src/generated doesn't exist, so the path only matches the kit once `..` is resolved lexically
+	const code = `
+import { component$ } from "@builder.io/qwik";
+import { Kit } from "~/generated/../components/kit";
+
+export default component$(() => {
+  return (
+    <Kit.Root>
+      <Kit.Description />
+    </Kit.Root>
+  );
+});
+  `.trim();
+
+	const testFilePath = path.join(appRoot, "src", "page.tsx");
+	fs.writeFileSync(testFilePath, code);
+
+	expect(analyzeAndTransformCode(code, testFilePath)).toContain(
+		"<Kit.Root __qwik_analyzer_has_Description={true}>",
+	);
+});
+
+test("configured injectionPosition places presence props first or last among the attributes", async () => {
+	const examplePath = path.resolve(
+		__dirname,
+		"../qwik-app/src/examples/attributed_example.tsx",
+	);
+	const code = fs.readFileSync(examplePath, "utf-8");
+	const injected =
+		" __qwik_analyzer_has_Checkbox_Description={false} __qwik_analyzer_has_Description={true} __qwik_analyzer_has_Title={false}";
+
+	const last = analyzeAndTransformCode(code, examplePath);
+	expect(last).toContain(`data-testid="terms"\n\t\t${injected}>`);
+	expect(() => collectNamespaces(last, examplePath)).not.toThrow();
+
+	configureAnalyzer({ injectionPosition: "first" });
+	try {
+		const first = analyzeAndTransformCode(code, examplePath);
+		expect(first).toContain(`<DummyComp.Root${injected}\n\t\t\tclass="terms-checkbox"`);
+		expect(() => collectNamespaces(first, examplePath)).not.toThrow();
+	} finally {
+		configureAnalyzer({});
+	}
+
+	expect(() => configureAnalyzer({ injectionPosition: "middle" })).toThrow(
+		/injectionPosition/,
+	);
+	configureAnalyzer({});
+});
+
+test("injectionPosition first places presence props after a generic Root's type arguments", async () => {
+	const examplePath = path.resolve(
+		__dirname,
+		"../qwik-app/src/examples/generic_root_example.tsx",
+	);
+	const code = fs.readFileSync(examplePath, "utf-8");
+	const injected =
+		" __qwik_analyzer_has_Checkbox_Description={false} __qwik_analyzer_has_Description={true} __qwik_analyzer_has_Title={false}";
+
+	configureAnalyzer({ injectionPosition: "first" });
+	try {
+		const first = analyzeAndTransformCode(code, examplePath);
+		expect(first).toContain(`<DummyComp.Root<string>${injected} class="generic-checkbox">`);
+		expect(() => collectNamespaces(first, examplePath)).not.toThrow();
+	} finally {
+		configureAnalyzer({});
+	}
+});
+
+test("a Root with several checks gets its presence props sorted by name, identically on every run", async () => {
+	const examplePath = path.resolve(
+		__dirname,
+		"../qwik-app/src/examples/direct_example.tsx",
+	);
+	const code = fs.readFileSync(examplePath, "utf-8");
+
+	const first = analyzeAndTransformCode(code, examplePath);
+	const second = analyzeAndTransformCode(code, examplePath);
+	expect(second).toBe(first);
+
+	const propNames = [...first.matchAll(/(__qwik_analyzer_has_\w+)=/g)].map(
+		([, propName]) => propName,
+	);
+	expect(propNames).toEqual([
+		"__qwik_analyzer_has_Checkbox_Description",
+		"__qwik_analyzer_has_Description",
+		"__qwik_analyzer_has_Title",
+	]);
+});
+
+test("JSX-like text in comments, template literals and strings is left alone", async () => {
+	const examplePath = path.resolve(
+		__dirname,
+		"../qwik-app/src/examples/jsx_in_string_example.tsx",
+	);
+	const code = fs.readFileSync(examplePath, "utf-8");
+	const injected =
+		" __qwik_analyzer_has_Checkbox_Description={false} __qwik_analyzer_has_Description={false} __qwik_analyzer_has_Title={false}";
+
+	// Only the rendered Root changes, and the components named in strings don't count as present
+	const rootIndex = code.indexOf("<DummyComp.Root>", code.indexOf("return ("));
+	const insertAt = rootIndex + "<DummyComp.Root".length;
+	expect(analyzeAndTransformCode(code, examplePath)).toBe(
+		code.slice(0, insertAt) + injected + code.slice(insertAt),
+	);
+});
+
+test("configured propNaming gives a Root and its consumers the same prop names", async () => {
+	const examplePath = path.resolve(
+		__dirname,
+		"../qwik-app/src/examples/attributed_example.tsx",
+	);
+	const rootPath = path.resolve(
+		__dirname,
+		"../qwik-app/src/components/dummy-comp/root.tsx",
+	);
+	const code = fs.readFileSync(examplePath, "utf-8");
+	const rootCode = fs.readFileSync(rootPath, "utf-8");
+	const names = (result: string, pattern: RegExp) =>
+		[...new Set(result.match(pattern))].sort();
+
+	configureAnalyzer({ propNaming: "hashed" });
+	try {
+		const consumerNames = names(analyzeAndTransformCode(code, examplePath), /__qa_[0-9a-f]{10}/g);
+		const rootNames = names(analyzeAndTransformCode(rootCode, rootPath), /__qa_[0-9a-f]{10}/g);
+		expect(consumerNames).toHaveLength(3);
+		expect(consumerNames).toEqual(rootNames);
+	} finally {
+		configureAnalyzer({});
+	}
+
+	configureAnalyzer({ propNaming: "qa_{name}_{hash}" });
+	try {
+		const consumer = analyzeAndTransformCode(code, examplePath);
+		const consumerNames = names(consumer, /qa_\w+_[0-9a-f]{10}/g);
+		const rootNames = names(analyzeAndTransformCode(rootCode, rootPath), /qa_\w+_[0-9a-f]{10}/g);
+		expect(consumerNames.some((name) => name.startsWith("qa_Checkbox_Description_"))).toBe(true);
+		expect(consumerNames).toEqual(rootNames);
+		expect(() => collectNamespaces(consumer, examplePath)).not.toThrow();
+	} finally {
+		configureAnalyzer({});
+	}
+
+	expect(() => configureAnalyzer({ propNaming: "short" })).toThrow(/propNaming/);
+	configureAnalyzer({});
+});
+
+test("data-attribute propNaming injects kebab-cased attributes the Root reads back", async () => {
+	const examplePath = path.resolve(
+		__dirname,
+		"../qwik-app/src/examples/attributed_example.tsx",
+	);
+	const rootPath = path.resolve(
+		__dirname,
+		"../qwik-app/src/components/dummy-comp/root.tsx",
+	);
+	const code = fs.readFileSync(examplePath, "utf-8");
+	const rootCode = fs.readFileSync(rootPath, "utf-8");
+
+	configureAnalyzer({ propNaming: "data-attribute" });
+	try {
+		const consumer = analyzeAndTransformCode(code, examplePath);
+		const root = analyzeAndTransformCode(rootCode, rootPath);
+		const consumerNames = [...consumer.matchAll(/\s(data-qwik-analyzer-has-[a-z-]+)=/g)]
+			.map((match) => match[1])
+			.sort();
+		const rootNames = [...root.matchAll(/props\["(data-qwik-analyzer-has-[a-z-]+)"\]/g)]
+			.map((match) => match[1])
+			.sort();
+
+		expect(consumerNames).toContain("data-qwik-analyzer-has-checkbox-description");
+		expect(consumerNames).toEqual(rootNames);
+		expect(consumer).not.toContain("__qwik_analyzer_has_");
+		expect(root).not.toContain("__qwik_analyzer_has_");
+	} finally {
+		configureAnalyzer({});
+	}
+});
+
+test("analyzeAndTransformFiles shares one library across pages and matches per-file transforms", async () => {
+	const kitDir = path.join(tempDir, "batch", "kit");
+	const pagesDir = path.join(tempDir, "batch", "pages");
+	fs.mkdirSync(kitDir, { recursive: true });
+	fs.mkdirSync(pagesDir, { recursive: true });
+
+	fs.writeFileSync(
+		path.join(kitDir, "index.ts"),
+		`
+import { Description } from "./description";
+import { Root } from "./root";
+
+export const Kit = { Root, Description };
+  `.trim(),
+	);
+	fs.writeFileSync(
+		path.join(kitDir, "description.tsx"),
+		`
+import { component$ } from "@builder.io/qwik";
+
+export const Description = component$(() => <p>Description</p>);
+  `.trim(),
+	);
+	fs.writeFileSync(
+		path.join(kitDir, "root.tsx"),
+		`
+import { component$, Slot } from "@builder.io/qwik";
+import { Description } from "./description";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+export const Root = component$(() => {
+  const hasDescription = isComponentPresent(Description);
+  return <div><Slot /></div>;
+});
+  `.trim(),
+	);
+
+	const pages = Array.from({ length: 10 }, (_, i) => {
+		const pagePath = path.join(pagesDir, `page-${i}.tsx`);
+		const child = i % 2 === 0 ? "<Kit.Description />" : "<p>No description</p>";
+		fs.writeFileSync(
+			pagePath,
+			`
+import { component$ } from "@builder.io/qwik";
+import { Kit } from "../kit";
+
+export default component$(() => <Kit.Root>${child}</Kit.Root>);
+  `.trim(),
+		);
+		return pagePath;
+	});
+	const missingPath = path.join(pagesDir, "missing.tsx");
+
+	const results = analyzeAndTransformFiles([...pages, missingPath]);
+
+	expect(results.map((result) => result.filePath)).toEqual([...pages, missingPath]);
+	pages.forEach((pagePath, i) => {
+		const expected = analyzeAndTransformCode(fs.readFileSync(pagePath, "utf-8"), pagePath);
+		expect(results[i].code).toBe(expected);
+		expect(results[i].code).toContain(
+			`<Kit.Root __qwik_analyzer_has_Description={${i % 2 === 0}}>`,
+		);
+	});
+	expect(results[10].code).toBeUndefined();
+	expect(results[10].error).toMatch(/Could not read file/);
+});
+
+test("a cache session serves stale library files until they are invalidated", async () => {
+	const kitDir = path.join(tempDir, "session", "kit");
+	fs.mkdirSync(kitDir, { recursive: true });
+	fs.writeFileSync(
+		path.join(kitDir, "index.ts"),
+		`
+import { Description } from "./description";
+import { Root } from "./root";
+import { Title } from "./title";
+
+export const Kit = { Root, Description, Title };
+  `.trim(),
+	);
+	fs.writeFileSync(
+		path.join(kitDir, "description.tsx"),
+		`
+import { component$ } from "@builder.io/qwik";
+
+export const Description = component$(() => <p>Description</p>);
+  `.trim(),
+	);
+	fs.writeFileSync(
+		path.join(kitDir, "title.tsx"),
+		`
+import { component$ } from "@builder.io/qwik";
+
+export const Title = component$(() => <h2>Title</h2>);
+  `.trim(),
+	);
+	const rootPath = path.join(kitDir, "root.tsx");
+	const rootCode = (checked: string) => `
+import { component$, Slot } from "@builder.io/qwik";
+import { ${checked} } from "./${checked.toLowerCase()}";
+import { isComponentPresent } from "@jackshelton/qwik-analyzer";
+
+export const Root = component$(() => {
+  const has${checked} = isComponentPresent(${checked});
+  return <div><Slot /></div>;
+});
+  `.trim();
+	fs.writeFileSync(rootPath, rootCode("Description"));
+
+	const pagePath = path.join(tempDir, "session", "page.tsx");
+	const page = `
+import { component$ } from "@builder.io/qwik";
+import { Kit } from "./kit";
+
+export default component$(() => <Kit.Root><Kit.Title /></Kit.Root>);
+  `.trim();
+	fs.writeFileSync(pagePath, page);
+
+	beginCacheSession();
+	try {
+		expect(analyzeAndTransformCode(page, pagePath)).toContain(
+			"<Kit.Root __qwik_analyzer_has_Description={false}>",
+		);
+
+		// The Root now checks for Title, but its calls are still cached
+		fs.writeFileSync(rootPath, rootCode("Title"));
+		expect(analyzeAndTransformCode(page, pagePath)).toContain(
+			"<Kit.Root __qwik_analyzer_has_Description={false}>",
+		);
+
+		invalidateCachedFile(rootPath);
+		expect(analyzeAndTransformCode(page, pagePath)).toContain(
+			"<Kit.Root __qwik_analyzer_has_Title={true}>",
+		);
+
+		fs.writeFileSync(rootPath, rootCode("Description"));
+		clearCaches();
+		expect(analyzeAndTransformCode(page, pagePath)).toContain(
+			"<Kit.Root __qwik_analyzer_has_Description={false}>",
+		);
+	} finally {
+		expect(endCacheSession()).toBeGreaterThan(0);
+	}
+});
+
+test("invalidating a barrel drops resolutions made through its old re-exports", async () => {
+	const kitDir = path.join(tempDir, "barrel-session", "kit");
+	fs.mkdirSync(kitDir, { recursive: true });
+	const indexPath = path.join(kitDir, "index.ts");
+	const barrel = (rootFile: string) => `
+import { Description } from "./description";
+import { Root } from "./${rootFile}";
+import { Title } from "./title";
+
+export const Kit = { Root, Description, Title };
+  `.trim();
+	fs.writeFileSync(indexPath, barrel("root-a"));
+	for (const name of ["Description", "Title"]) {
+		fs.writeFileSync(
+			path.join(kitDir, `${name.toLowerCase()}.tsx`),
+			`
+import { component$ } from "@builder.io/qwik";
+
+export const ${name} = component$(() => <p>${name}</p>);
+  `.trim(),
+		);
+	}
+	for (const [file, checked] of [
+		["root-a", "Description"],
+		["root-b", "Title"],
+	]) {
+		fs.writeFileSync(
+			path.join(kitDir, `${file}.tsx`),
+			`
+import { component$, Slot } from "@builder.io/qwik";
+import { ${checked} } from "./${checked.toLowerCase()}";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+
+export const Root = component$(() => {
+  const has${checked} = isComponentPresent(${checked});
+  return <div><Slot /></div>;
+});
+  `.trim(),
+		);
+	}
+
+	const pagePath = path.join(tempDir, "barrel-session", "page.tsx");
+	const page = `
+import { component$ } from "@builder.io/qwik";
+import { Kit } from "./kit";
+
+export default component$(() => <Kit.Root><Kit.Title /></Kit.Root>);
+  `.trim();
+	fs.writeFileSync(pagePath, page);
+
+	beginCacheSession();
+	try {
+		expect(analyzeAndTransformCode(page, pagePath)).toContain(
+			"<Kit.Root __qwik_analyzer_has_Description={false}>",
+		);
+
+		fs.writeFileSync(indexPath, barrel("root-b"));
+		invalidateCachedFile(indexPath);
+		expect(analyzeAndTransformCode(page, pagePath)).toContain(
+			"<Kit.Root __qwik_analyzer_has_Title={true}>",
+		);
+	} finally {
+		endCacheSession();
+	}
+});
+
+test("minified components without spacing get their props parameter and presence props", async () => {
+	const examplesDir = path.resolve(__dirname, "../qwik-app/src/examples");
+	const rootPath = path.resolve(__dirname, "../qwik-app/src/components/minified-kit/root.tsx");
+	const examplePath = path.join(examplesDir, "minified_example.tsx");
+	const selfClosingPath = path.join(examplesDir, "minified_self_closing_example.tsx");
+
+	expect(analyzeAndTransformCode(fs.readFileSync(rootPath, "utf-8"), rootPath)).toContain(
+		"component$((props)=>{const h=isComponentPresent(Hint, props.__qwik_analyzer_has_Hint);",
+	);
+	expect(analyzeAndTransformCode(fs.readFileSync(examplePath, "utf-8"), examplePath)).toContain(
+		"<div><Min.Root __qwik_analyzer_has_Hint={true}><Min.Hint>",
+	);
+	// The prop goes before the `/` of a self-closing tag rather than right before its `>`
+	expect(
+		analyzeAndTransformCode(fs.readFileSync(selfClosingPath, "utf-8"), selfClosingPath),
+	).toContain("<Min.Root data-min={1} __qwik_analyzer_has_Hint={false}/>");
+});
+
+test("previewTransform shows the injected prop and rewritten call as a unified diff", async () => {
+	const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/resource_example.tsx");
+	const rootPath = path.resolve(__dirname, "../qwik-app/src/components/checkbox-kit/checkbox-root.tsx");
+
+	const diff = previewTransform(fs.readFileSync(examplePath, "utf-8"), examplePath);
+	expect(diff.startsWith(`--- ${examplePath}\n+++ ${examplePath}\n@@ -`)).toBe(true);
+	expect(diff).toContain("\n-\t\t<CheckboxKit.Root>\n");
+	expect(diff).toContain("\n+\t\t<CheckboxKit.Root __qwik_analyzer_has_Description={true}>\n");
+	expect(diff).toContain("\n \t\t\t<input type=\"checkbox\" />\n");
+
+	const rootDiff = previewTransform(fs.readFileSync(rootPath, "utf-8"), rootPath);
+	expect(rootDiff).toContain("\n+export const Root = component$((props) => {\n");
+	expect(rootDiff).toContain("isComponentPresent(Description, props.__qwik_analyzer_has_Description)");
+
+	const untouched = `export const answer = 42;\n`;
+	expect(previewTransform(untouched, path.join(tempDir, "plain.ts"))).toBe("");
+});
+
+test("collectTransformations returns the edits for the host to apply with JS string offsets", async () => {
+	const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/multiple_roots_example.tsx");
+	const rootPath = path.resolve(__dirname, "../qwik-app/src/components/checkbox-kit/checkbox-root.tsx");
+	const applyEdits = (code: string, edits: { start: number; end: number; replacement: string }[]) =>
+		edits.reduceRight(
+			(transformed, edit) =>
+				transformed.slice(0, edit.start) + edit.replacement + transformed.slice(edit.end),
+			code,
+		);
+
+	// Multi-byte characters before the Roots shift byte offsets but not string indices
+	const code = `// héllo 😀\n${fs.readFileSync(examplePath, "utf-8")}`;
+	const edits = collectTransformations(code, examplePath);
+	expect(edits.map((edit) => edit.replacement)).toEqual([
+		" __qwik_analyzer_has_Description={true}",
+		" __qwik_analyzer_has_Description={false}",
+	]);
+	for (const edit of edits) {
+		expect(edit.start).toBe(edit.end);
+		expect(code.slice(0, edit.start).endsWith("<CheckboxKit.Root")).toBe(true);
+	}
+	expect(applyEdits(code, edits)).toBe(analyzeAndTransformCode(code, examplePath));
+
+	const rootCode = fs.readFileSync(rootPath, "utf-8");
+	const rootEdits = collectTransformations(rootCode, rootPath);
+	expect(rootEdits.some((edit) => edit.end > edit.start)).toBe(true);
+	expect(applyEdits(rootCode, rootEdits)).toBe(analyzeAndTransformCode(rootCode, rootPath));
+
+	expect(collectTransformations(`export const answer = 42;\n`, path.join(tempDir, "plain.ts"))).toEqual(
+		[],
+	);
+});
+
+test("configured annotateTransformations explains each change with a comment", async () => {
+	const examplePath = path.resolve(__dirname, "../qwik-app/src/examples/absent_example.tsx");
+	const rootPath = path.resolve(__dirname, "../qwik-app/src/components/dummy-comp/root.tsx");
+	const code = fs.readFileSync(examplePath, "utf-8");
+	const rootCode = fs.readFileSync(rootPath, "utf-8");
+
+	expect(analyzeAndTransformCode(code, examplePath)).not.toContain("qwik-analyzer:");
+
+	configureAnalyzer({ annotateTransformations: true });
+	try {
+		const consumer = analyzeAndTransformCode(code, examplePath);
+		expect(consumer).toContain(
+			"__qwik_analyzer_has_Description={false /* qwik-analyzer: Description absent */}",
+		);
+		expect(consumer).toContain("__qwik_analyzer_has_Title={true /* qwik-analyzer: Title present */}");
+
+		const root = analyzeAndTransformCode(rootCode, rootPath);
+		expect(root).toContain(
+			"component$((props /* qwik-analyzer: parameter added to read injected presence props */) =>",
+		);
+		expect(root).toContain(
+			"isComponentPresent(Title, props.__qwik_analyzer_has_Title) /* qwik-analyzer: Title presence comes from the __qwik_analyzer_has_Title prop */;",
+		);
+
+		// The annotated output is still valid source
+		expect(() => collectNamespaces(consumer, examplePath)).not.toThrow();
+		expect(() => collectNamespaces(root, rootPath)).not.toThrow();
+	} finally {
+		configureAnalyzer({});
+	}
+});
+
+test("configured transformSide only emits its side's transformations", async () => {
+	// The consent Root both checks for Note and renders CheckboxKit.Root
+	const rootPath = path.resolve(
+		__dirname,
+		"../qwik-app/src/components/consent-kit/root.tsx",
+	);
+	const code = fs.readFileSync(rootPath, "utf-8");
+
+	configureAnalyzer({ transformSide: "definition" });
+	try {
+		const definition = analyzeAndTransformCode(code, rootPath);
+		expect(definition).toContain("component$((props) =>");
+		expect(definition).toContain(
+			"isComponentPresent(Note, props.__qwik_analyzer_has_Note)",
+		);
+		expect(definition).toContain("<CheckboxKit.Root>");
+	} finally {
+		configureAnalyzer({});
+	}
+
+	configureAnalyzer({ transformSide: "consumer" });
+	try {
+		const consumer = analyzeAndTransformCode(code, rootPath);
+		expect(consumer).toContain("component$(() =>");
+		expect(consumer).toContain("isComponentPresent(Note);");
+		expect(consumer).toContain(
+			"<CheckboxKit.Root __qwik_analyzer_has_Description={true}>",
+		);
+	} finally {
+		configureAnalyzer({});
+	}
+
+	expect(() => configureAnalyzer({ transformSide: "library" })).toThrow(
+		/Unknown transformSide 'library'/,
+	);
+});
@@ -310,8 +310,27 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { analyzeFile, analyzeFileChanged, analyzeAndTransformCode } = nativeBinding
+const { configureAnalyzer, analyzeFile, analyzeFileChanged, analyzeAndTransformCode, transformWithDiagnostics, previewTransform, collectTransformations, analyzeAndTransformFiles, beginCacheSession, endCacheSession, invalidateCachedFile, clearCaches, presenceCoverage, presenceCoverageJson, collectNamespaces, declaredOptionalChildren, classifyFile, collectDependencies, dependentsOf, checkImports, explainPresence, adjustTransformationsForEdit } = nativeBinding
 
+module.exports.configureAnalyzer = configureAnalyzer
 module.exports.analyzeFile = analyzeFile
 module.exports.analyzeFileChanged = analyzeFileChanged
 module.exports.analyzeAndTransformCode = analyzeAndTransformCode
+module.exports.transformWithDiagnostics = transformWithDiagnostics
+module.exports.previewTransform = previewTransform
+module.exports.collectTransformations = collectTransformations
+module.exports.analyzeAndTransformFiles = analyzeAndTransformFiles
+module.exports.beginCacheSession = beginCacheSession
+module.exports.endCacheSession = endCacheSession
+module.exports.invalidateCachedFile = invalidateCachedFile
+module.exports.clearCaches = clearCaches
+module.exports.presenceCoverage = presenceCoverage
+module.exports.presenceCoverageJson = presenceCoverageJson
+module.exports.collectNamespaces = collectNamespaces
+module.exports.declaredOptionalChildren = declaredOptionalChildren
+module.exports.classifyFile = classifyFile
+module.exports.collectDependencies = collectDependencies
+module.exports.dependentsOf = dependentsOf
+module.exports.checkImports = checkImports
+module.exports.explainPresence = explainPresence
+module.exports.adjustTransformationsForEdit = adjustTransformationsForEdit
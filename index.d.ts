@@ -8,12 +8,287 @@ export interface Transformation {
   end: number
   replacement: string
 }
+export interface Diagnostic {
+  /**
+   * Stable identifier of the diagnostic class, e.g. `QA004` for an invalid `isComponentPresent`
+   * argument
+   */
+  code: string
+  message: string
+  filePath: string
+  start: number
+  end: number
+}
+/** The outcome of one `isComponentPresent` check made by a rendered Root */
+export interface PresenceCheck {
+  /** The checked component, the first one listed when the check takes several */
+  component: string
+  /**
+   * The other components satisfying the check, e.g. `["HelperText"]` for
+   * `isComponentPresent([Description, HelperText])`
+   */
+  alternatives: Array<string>
+  /** The file containing the `isComponentPresent` call */
+  sourceFile: string
+  /**
+   * `"present"`, `"absent"` or `"unknown"`. Unknown checks get no injected prop, so the call
+   * falls back to its runtime value.
+   */
+  presence: string
+}
+/** A presence prop injected into a rendered Root, for explaining a transformation */
+export interface InjectedProp {
+  /** Span of the Root's opening tag that received the prop */
+  start: number
+  end: number
+  /** The Root element's name as written, e.g. `Checkbox.Root` */
+  element: string
+  /** The checked component, the first one listed when the check takes several */
+  component: string
+  /** The other components satisfying the check */
+  alternatives: Array<string>
+  propName: string
+  value: boolean
+}
 export interface AnalysisResult {
   hasComponent: boolean
+  /**
+   * True when at least one checked component appears in this file's own JSX rather than only
+   * through recursion into imported components
+   */
+  foundDirectly: boolean
   filePath: string
   dependencies: Array<string>
   transformations: Array<Transformation>
+  diagnostics: Array<Diagnostic>
+  presenceChecks: Array<PresenceCheck>
+  /**
+   * One entry per injected presence prop. They come first in `transformations` and in the same
+   * order, so `injected_props[i]` explains `transformations[i]`
+   */
+  injectedProps: Array<InjectedProp>
+}
+/** How an import of a rendered component resolved */
+export interface ImportResolution {
+  /** The import specifier, e.g. `../components/checkbox` */
+  source: string
+  /** Local names bound by the import that are rendered in the file */
+  localNames: Array<string>
+  /** `"local"` for a file in the project, `"package"` for one in node_modules, or `"unresolved"` */
+  status: string
+  resolvedPath?: string
+}
+/** How often one child checked by a Root came out present, absent or unknown */
+export interface ChildCoverage {
+  /** The checked component, the first one listed when the check takes several */
+  component: string
+  /** The other components satisfying the check */
+  alternatives: Array<string>
+  present: number
+  absent: number
+  unknown: number
+}
+export interface ComponentCoverage {
+  /** The file defining the Root, where its `isComponentPresent` calls live */
+  rootFile: string
+  /** Number of analyzed files rendering the Root */
+  usages: number
+  children: Array<ChildCoverage>
+}
+/** Presence results aggregated over a set of files, per Root */
+export interface CoverageReport {
+  filesAnalyzed: number
+  components: Array<ComponentCoverage>
+}
+/**
+ * How one `isComponentPresent` check made by a rendered Root was settled, for debugging a
+ * result that looks wrong
+ */
+export interface PresenceExplanation {
+  /** The Root element as written, e.g. `Checkbox.Root` */
+  root: string
+  /** The file defining the Root, when its import resolves */
+  rootFile?: string
+  target: string
+  /** `"present"`, `"absent"` or `"unknown"`, or `None` when the Root doesn't check for `target` */
+  presence?: string
+  /** The JSX element that made `target` present, e.g. `Checkbox.Description` */
+  matchedElement?: string
+  /** How many levels of imported components were searched below the file */
+  depth: number
+  /** The files searched and the decisions made, in order */
+  steps: Array<string>
+}
+/** An editor change against the code a previous analysis ran on, in byte offsets */
+export interface SourceEdit {
+  offset: number
+  removedLength: number
+  insertedText: string
+}
+export interface IncrementalUpdate {
+  /** The previous transformations shifted to apply to the edited code */
+  transformations: Array<Transformation>
+  /**
+   * True when the edit touched an import, JSX, an `isComponentPresent` call or a transformed
+   * span, so the shifted transformations may be stale and the file should be analyzed again
+   */
+  requiresReanalysis: boolean
+}
+/** The transformed code of one file with the diagnostics its analysis produced */
+export interface TransformOutput {
+  code: string
+  diagnostics: Array<Diagnostic>
+}
+/** One file's outcome in `analyze_and_transform_files` */
+export interface BatchTransformResult {
+  filePath: string
+  /** The transformed code, or `None` when the file couldn't be read or analyzed */
+  code?: string
+  error?: string
+}
+export interface AnalyzerOptions {
+  propsParameter?: string
+  /**
+   * Annotation of an added props parameter in TypeScript files: `"none"` (default),
+   * `"record"` for `Record<string, unknown>`, `"declared"` for the component's own props type,
+   * or a type such as `"PropsOf<typeof Root>"`
+   */
+  propsType?: string
+  /** Maps extra file extensions to the syntax they are parsed as, e.g. `{ qwik: "tsx" }` */
+  sourceExtensions?: Record<string, string>
+  /**
+   * `"respect"` (default) keeps presence props the user already wrote on a Root, `"override"`
+   * replaces their value
+   */
+  existingProps?: string
+  /**
+   * `"last"` (default) places injected props after a Root's attributes, `"first"` right after
+   * its name
+   */
+  injectionPosition?: string
+  /**
+   * Files larger than this many bytes are skipped with a diagnostic (default 2 MiB, `0` disables
+   * the limit)
+   */
+  maxSourceBytes?: number
+  /**
+   * Overrides the project root used for `~/` imports and `qwik-analyzer.json`, which otherwise is
+   * the nearest directory with a package.json
+   */
+  projectRoot?: string
+  /** Skip injecting props into a Root when none of its presence checks are true (default `false`) */
+  injectOnlyWhenPresent?: boolean
+  /**
+   * Replacement for each `isComponentPresent` call, with `{arg}`, `{prop}` and `{props}`
+   * placeholders (default `isComponentPresent({arg}, {props}.{prop})`)
+   */
+  presenceCallTemplate?: string
+  /** Report and leave unresolved presence checks only a heuristic could settle (default `false`) */
+  strictScoping?: boolean
+  /** Count children sent to a `q:slot` the Root doesn't render as absent (default `false`) */
+  namedSlots?: boolean
+  /**
+   * `"readable"` (default), `"hashed"`, `"data-attribute"`, or a template for injected prop names with `{name}`
+   * and/or `{hash}` placeholders, e.g. `"qa_{hash}"`
+   */
+  propNaming?: string
+  /** Refuse to read files that imports resolve to outside this directory (default: no limit) */
+  sandboxRoot?: string
+  /** Children always counted as present, as named in the Root's `isComponentPresent` call */
+  assumePresent?: Array<string>
+  /** Only analyze these components or namespaces, e.g. `["Checkbox"]` (default: all) */
+  onlyComponents?: Array<string>
+  /** Explain each transformation with a `/* qwik-analyzer: ... *\/` comment (default `false`) */
+  annotateTransformations?: boolean
+  /**
+   * `"both"` (default), or `"definition"`/`"consumer"` to only rewrite Root definitions or only
+   * inject props into the Roots a file renders
+   */
+  transformSide?: string
+  /** How many levels of nested components are searched for a child (default 8) */
+  maxComponentDepth?: number
+  /**
+   * Cross-check manifest-declared Roots against their definitions, reporting `QA005` for
+   * injected props they never read (default `false`)
+   */
+  verifyManifest?: boolean
+  /**
+   * Other packages `isComponentPresent` and `usePresence` may be imported from, besides
+   * `@jackshelton/qwik-analyzer` and files re-exporting them from either
+   */
+  runtimeModules?: Array<string>
 }
+export declare function configureAnalyzer(options: AnalyzerOptions): void
 export declare function analyzeFile(filePath: string): AnalysisResult
 export declare function analyzeFileChanged(filePath: string, event: string): void
 export declare function analyzeAndTransformCode(code: string, filePath: string): string
+/**
+ * Same as `analyze_and_transform_code`, also returning the analysis diagnostics for the host to
+ * report, e.g. through Vite's `this.warn`
+ */
+export declare function transformWithDiagnostics(code: string, filePath: string): TransformOutput
+/**
+ * A unified diff of what `analyze_and_transform_code` would change in `code`, for review tooling.
+ * Empty when the file is left as is.
+ */
+export declare function previewTransform(code: string, filePath: string): string
+/**
+ * The edits `analyze_and_transform_code` would make to `code`, for hosts that apply them
+ * themselves, e.g. with magic-string to keep a single source map. They come sorted by position,
+ * with offsets in UTF-16 code units like JS string indices. Insertions sharing a position are
+ * listed in the order they apply.
+ */
+export declare function collectTransformations(code: string, filePath: string): Array<Transformation>
+/**
+ * Transforms each file in `file_paths` like `analyze_and_transform_code`, sharing import
+ * resolution and parsed library components across the batch. A failing file is reported in its
+ * result without stopping the rest.
+ */
+export declare function analyzeAndTransformFiles(filePaths: Array<string>): Array<BatchTransformResult>
+/**
+ * Keeps import resolution and parsed library components cached across analyses until
+ * `end_cache_session`, for long-running hosts such as editors and watchers. Files that change
+ * meanwhile must be passed to `invalidate_cached_file`.
+ */
+export declare function beginCacheSession(): void
+/** Closes the session opened by `begin_cache_session`, returning how many lookups it answered */
+export declare function endCacheSession(): number
+/** Drops everything cached from `file_path` after it changed, was added or was deleted */
+export declare function invalidateCachedFile(filePath: string): void
+/** Empties the caches without closing a session */
+export declare function clearCaches(): void
+/** Aggregates presence results over files and directories into a per-Root coverage report */
+export declare function presenceCoverage(paths: Array<string>): CoverageReport
+/** Same as `presence_coverage`, serialized as JSON for CI dashboards */
+export declare function presenceCoverageJson(paths: Array<string>): string
+/** Lists the distinct compound-component namespaces rendered in `code`, e.g. `["Checkbox", "Form"]` */
+export declare function collectNamespaces(code: string, filePath: string): Array<string>
+/** Lists the children a Root definition file checks for with `isComponentPresent`, sorted */
+export declare function declaredOptionalChildren(rootFile: string): Array<string>
+/**
+ * Classifies a file as `"definition"` (calls `isComponentPresent`), `"consumer"` (renders a Root
+ * that does), `"both"` or `"neither"`
+ */
+export declare function classifyFile(filePath: string): string
+/**
+ * Lists the library files a file's transformation depends on, for watching them without analyzing
+ * the file. They are recorded for `dependents_of`.
+ */
+export declare function collectDependencies(filePath: string): Array<string>
+/**
+ * Lists the files whose recorded dependencies, from earlier `collect_dependencies` calls, include
+ * `path`, i.e. the pages to transform again after a component file changed
+ */
+export declare function dependentsOf(path: string): Array<string>
+/**
+ * Lists how the imports of components rendered in a file resolve, to debug presence analysis
+ * that never fires
+ */
+export declare function checkImports(filePath: string): Array<ImportResolution>
+/**
+ * Explains why `target`, as passed to `isComponentPresent`, came out present or absent for the
+ * `root_name` element rendered in a file
+ */
+export declare function explainPresence(filePath: string, rootName: string, target: string): PresenceExplanation
+/** Adjusts a previous analysis of `code` for a single edit without analyzing the file again */
+export declare function adjustTransformationsForEdit(code: string, filePath: string, previous: AnalysisResult, edit: SourceEdit): IncrementalUpdate
@@ -0,0 +1,2 @@
+export { default as Root } from "./root";
+export { Item } from "./item";
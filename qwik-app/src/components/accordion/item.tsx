@@ -0,0 +1,5 @@
+import { component$ } from "@builder.io/qwik";
+
+export const Item = component$(() => {
+	return <div>Accordion item</div>;
+});
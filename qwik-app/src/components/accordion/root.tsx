@@ -0,0 +1,14 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { Item } from "./item";
+
+export default component$(() => {
+	const hasItem = isComponentPresent(Item);
+
+	return (
+		<div>
+			<Slot />
+			{!hasItem && <p>No items yet</p>}
+		</div>
+	);
+});
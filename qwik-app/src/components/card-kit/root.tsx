@@ -0,0 +1,20 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { Description } from "./description";
+import { Title } from "./title";
+
+export const Root = component$(() => {
+	const hasDescription = isComponentPresent(Description);
+	const hasTitle = isComponentPresent(Title);
+
+	return (
+		<article aria-labelledby={hasTitle ? "card-title" : undefined}>
+			<Slot />
+			{hasDescription && (
+				<footer>
+					<Slot name="description" />
+				</footer>
+			)}
+		</article>
+	);
+});
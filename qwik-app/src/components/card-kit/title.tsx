@@ -0,0 +1,9 @@
+import { component$, Slot } from "@builder.io/qwik";
+
+export const Title = component$(() => {
+	return (
+		<h2 id="card-title">
+			<Slot />
+		</h2>
+	);
+});
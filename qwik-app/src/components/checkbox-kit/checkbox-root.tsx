@@ -0,0 +1,17 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { Description } from "./description";
+import { rootLayout } from "./routes";
+
+export const Root = component$(() => {
+	const hasDescription = isComponentPresent(Description);
+
+	return (
+		<div
+			style={rootLayout}
+			aria-describedby={hasDescription ? "checkbox-description" : undefined}
+		>
+			<Slot />
+		</div>
+	);
+});
@@ -0,0 +1,9 @@
+import { Root } from "./checkbox-root";
+import { Description } from "./description";
+import { Title } from "./title";
+
+export const CheckboxKit = {
+	Root,
+	Description,
+	Title,
+};
@@ -0,0 +1,9 @@
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { Title } from "./title";
+
+// A helper whose name mentions "root" but that doesn't define the Root
+export const rootLayout = { display: "flex", gap: "8px" };
+
+export function hasRouteTitle() {
+	return isComponentPresent(Title);
+}
@@ -0,0 +1,9 @@
+import { Description } from "./description";
+import { Root } from "./root";
+import { Title } from "./title";
+
+export const ComposedKit = {
+	Root,
+	Description,
+	Title,
+};
@@ -0,0 +1,10 @@
+import { component$ } from "@builder.io/qwik";
+import { Description } from "./description";
+
+export const Panel = component$(() => {
+	return (
+		<section>
+			<Description />
+		</section>
+	);
+});
@@ -0,0 +1,17 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { Description } from "./description";
+import { Panel } from "./panel";
+import { Title } from "./title";
+
+export const Root = component$(() => {
+	const isDescription = isComponentPresent(Description);
+	const isTitle = isComponentPresent(Title);
+
+	return (
+		<div data-described={isDescription} data-titled={isTitle}>
+			<Slot />
+			<Panel />
+		</div>
+	);
+});
@@ -0,0 +1,9 @@
+import { component$, Slot } from "@builder.io/qwik";
+
+export const Title = component$(() => {
+	return (
+		<h3>
+			<Slot />
+		</h3>
+	);
+});
@@ -0,0 +1,9 @@
+import { component$, Slot } from "@builder.io/qwik";
+
+export const Note = component$(() => {
+	return (
+		<small id="consent-note">
+			<Slot />
+		</small>
+	);
+});
@@ -0,0 +1,17 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { CheckboxKit } from "../checkbox-kit";
+import { Note } from "./note";
+
+export const Root = component$(() => {
+	const hasNote = isComponentPresent(Note);
+
+	return (
+		<CheckboxKit.Root>
+			<input type="checkbox" aria-describedby={hasNote ? "consent-note" : undefined} />
+			<CheckboxKit.Description>
+				<Slot />
+			</CheckboxKit.Description>
+		</CheckboxKit.Root>
+	);
+});
@@ -0,0 +1,2 @@
+export * from "./parts/item";
+export * from "./parts/root";
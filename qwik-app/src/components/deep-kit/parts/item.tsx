@@ -0,0 +1,9 @@
+import { component$, Slot } from "@builder.io/qwik";
+
+export const Item = component$(() => {
+	return (
+		<li>
+			<Slot />
+		</li>
+	);
+});
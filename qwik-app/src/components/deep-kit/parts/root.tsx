@@ -0,0 +1,14 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../../src/vite/plugin";
+import { Item } from "./item";
+
+export const Root = component$(() => {
+	const hasItems = isComponentPresent(Item);
+
+	return (
+		<ul>
+			<Slot />
+			{!hasItems && <li>Nothing here yet</li>}
+		</ul>
+	);
+});
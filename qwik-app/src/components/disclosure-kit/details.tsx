@@ -0,0 +1,9 @@
+import { component$, Slot } from "@builder.io/qwik";
+
+export const Details = component$(() => {
+	return (
+		<div class="disclosure-details">
+			<Slot />
+		</div>
+	);
+});
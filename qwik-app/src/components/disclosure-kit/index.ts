@@ -0,0 +1,4 @@
+import { Details } from "./details";
+import { Root } from "./root";
+
+export const Disclosure = { Root, Details };
@@ -0,0 +1,14 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { usePresence } from "../../../../src/vite/plugin";
+import { Details } from "./details";
+
+export const Root = component$(() => {
+	const hasDetails = usePresence(Details);
+
+	return (
+		<div class="disclosure-root">
+			<Slot />
+			{hasDetails.value ? null : <span class="disclosure-empty">Nothing more to show</span>}
+		</div>
+	);
+});
@@ -0,0 +1,17 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { Description } from "./description";
+
+export interface LabelledRootProps {
+	label?: string;
+}
+
+export const LabelledRoot = component$(() => {
+	const isDescription = isComponentPresent(Description);
+
+	return (
+		<fieldset aria-describedby={isDescription ? "labelled-description" : undefined}>
+			<Slot />
+		</fieldset>
+	);
+});
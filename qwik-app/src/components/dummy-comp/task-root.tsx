@@ -0,0 +1,18 @@
+import { component$, Slot, useSignal, useTask$ } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { Description } from "./description";
+
+export const TaskRoot = component$(() => {
+	const hasDescription = useSignal(false);
+
+	useTask$(() => {
+		hasDescription.value = isComponentPresent(Description);
+	});
+
+	return (
+		<div>
+			<Slot />
+			<p>Description present: {hasDescription.value ? "true" : "false"}</p>
+		</div>
+	);
+});
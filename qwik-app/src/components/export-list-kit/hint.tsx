@@ -0,0 +1,9 @@
+import { component$, Slot } from "@builder.io/qwik";
+
+export default component$(() => {
+	return (
+		<p id="list-hint">
+			<Slot />
+		</p>
+	);
+});
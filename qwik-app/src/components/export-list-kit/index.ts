@@ -0,0 +1,4 @@
+import Hint from "./hint";
+import { ListRoot } from "./root";
+
+export { ListRoot as Root, Hint };
@@ -0,0 +1,13 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { Hint } from "./hint";
+
+export const ListRoot = component$(() => {
+	const hasHint = isComponentPresent(Hint);
+
+	return (
+		<ul aria-describedby={hasHint ? "list-hint" : undefined}>
+			<Slot />
+		</ul>
+	);
+});
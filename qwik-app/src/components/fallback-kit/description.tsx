@@ -0,0 +1,9 @@
+import { component$, Slot } from "@builder.io/qwik";
+
+export const Description = component$(() => {
+	return (
+		<p class="fallback-description">
+			<Slot />
+		</p>
+	);
+});
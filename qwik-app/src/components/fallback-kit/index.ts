@@ -0,0 +1,4 @@
+import { Description } from "./description";
+import { Root } from "./root";
+
+export const Fallback = { Root, Description };
@@ -0,0 +1,14 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { Description } from "./description";
+
+export const Root = component$(() => {
+	const hasDescription = isComponentPresent(Description);
+
+	return (
+		<div class="fallback-root">
+			<Slot />
+			{!hasDescription && <Description>No description provided</Description>}
+		</div>
+	);
+});
@@ -0,0 +1,9 @@
+import { component$, Slot } from "@builder.io/qwik";
+
+export const HelperText = component$(() => {
+	return (
+		<small class="field-helper-text">
+			<Slot />
+		</small>
+	);
+});
@@ -0,0 +1,9 @@
+import { Description } from "./description";
+import { HelperText } from "./helper-text";
+import { Root } from "./root";
+
+export const Field = {
+	Root,
+	Description,
+	HelperText,
+};
@@ -0,0 +1,13 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { CheckboxDescription } from "./checkbox-description";
+
+export const CheckboxRoot = component$(() => {
+	const hasDescription = isComponentPresent(CheckboxDescription);
+
+	return (
+		<div aria-describedby={hasDescription ? "checkbox-description" : undefined}>
+			<Slot />
+		</div>
+	);
+});
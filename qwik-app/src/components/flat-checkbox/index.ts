@@ -0,0 +1,2 @@
+export { CheckboxRoot } from "./checkbox-root";
+export { CheckboxDescription } from "./checkbox-description";
@@ -0,0 +1,9 @@
+import { component$, Slot } from "@builder.io/qwik";
+
+export const Hint = component$(() => {
+	return (
+		<p class="labeled-hint">
+			<Slot />
+		</p>
+	);
+});
@@ -0,0 +1,4 @@
+import { Hint } from "./hint";
+import { Root } from "./root";
+
+export const Labeled = { Root, Hint };
@@ -0,0 +1,20 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { Hint } from "./hint";
+
+type LabeledRootProps = {
+	label: string;
+	disabled?: boolean;
+};
+
+export const Root = component$(({ label, disabled }: LabeledRootProps) => {
+	const hasHint = isComponentPresent(Hint);
+
+	return (
+		<label class={{ "labeled-root": true, "with-hint": hasHint }}>
+			<span>{label}</span>
+			<input type="text" disabled={disabled} />
+			<Slot />
+		</label>
+	);
+});
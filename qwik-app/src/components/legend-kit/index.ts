@@ -0,0 +1,4 @@
+import Legend from "./legend";
+import { Root } from "./root";
+
+export const LegendKit = { Root, Legend };
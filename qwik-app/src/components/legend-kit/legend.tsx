@@ -0,0 +1,11 @@
+import { component$, Slot } from "@builder.io/qwik";
+
+const Legend = component$(() => {
+	return (
+		<legend>
+			<Slot />
+		</legend>
+	);
+});
+
+export default Legend;
@@ -0,0 +1,13 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import Caption from "./legend";
+
+export const Root = component$(() => {
+	const hasCaption = isComponentPresent(Caption);
+
+	return (
+		<fieldset data-captioned={hasCaption}>
+			<Slot />
+		</fieldset>
+	);
+});
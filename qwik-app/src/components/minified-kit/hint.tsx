@@ -0,0 +1 @@
+import{component$,Slot}from"@builder.io/qwik";export const Hint=component$(()=><small><Slot/></small>);
@@ -0,0 +1 @@
+import{Hint}from"./hint";import{Root}from"./root";export const Min={Root,Hint};
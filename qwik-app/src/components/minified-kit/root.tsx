@@ -0,0 +1 @@
+import{component$,Slot}from"@builder.io/qwik";import{isComponentPresent}from"../../../../src/vite/plugin";import{Hint}from"./hint";export const Root=component$(()=>{const h=isComponentPresent(Hint);return<div data-hint={h}><Slot/></div>});
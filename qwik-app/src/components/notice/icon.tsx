@@ -0,0 +1,5 @@
+import { component$ } from "@builder.io/qwik";
+
+export const Icon = component$(() => {
+	return <span aria-hidden="true">!</span>;
+});
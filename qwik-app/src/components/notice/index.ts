@@ -0,0 +1,4 @@
+import Notice from "./notice";
+
+export { Icon } from "./icon";
+export default Notice;
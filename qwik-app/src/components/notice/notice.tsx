@@ -0,0 +1,13 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { Icon } from "./icon";
+
+export default component$(() => {
+	const hasIcon = isComponentPresent(Icon);
+
+	return (
+		<aside role="note" class={hasIcon ? "notice notice--with-icon" : "notice"}>
+			<Slot />
+		</aside>
+	);
+});
@@ -0,0 +1,7 @@
+import { Legend } from "./legend";
+import { Root } from "./root";
+
+export const Radio = {
+	Root,
+	Legend,
+} as const;
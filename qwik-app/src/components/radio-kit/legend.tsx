@@ -0,0 +1,9 @@
+import { component$, Slot } from "@builder.io/qwik";
+
+export const Legend = component$(() => {
+	return (
+		<legend id="radio-legend">
+			<Slot />
+		</legend>
+	);
+});
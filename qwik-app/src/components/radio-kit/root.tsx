@@ -0,0 +1,13 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { Legend } from "./legend";
+
+export const Root = component$(() => {
+	const hasLegend = isComponentPresent(Legend);
+
+	return (
+		<fieldset role="radiogroup" aria-labelledby={hasLegend ? "radio-legend" : undefined}>
+			<Slot />
+		</fieldset>
+	);
+});
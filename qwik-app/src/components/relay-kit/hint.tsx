@@ -0,0 +1,9 @@
+import { component$, Slot } from "@builder.io/qwik";
+
+export const Hint = component$(() => {
+	return (
+		<small class="relay-hint">
+			<Slot />
+		</small>
+	);
+});
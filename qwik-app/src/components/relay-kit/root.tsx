@@ -0,0 +1,17 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { Hint } from "./hint";
+
+// Every argument is forwarded to the tracking helper untouched
+const track = (...args: unknown[]) => console.debug("relay", ...args);
+
+export const Root = component$((...args: [Record<string, unknown>]) => {
+	const hasHint = isComponentPresent(Hint);
+	track(...args);
+
+	return (
+		<div class="relay-root" data-has-hint={hasHint}>
+			<Slot />
+		</div>
+	);
+});
@@ -0,0 +1,7 @@
+import { Description } from "./description";
+import { Root } from "./root";
+
+export default {
+	Root,
+	Description,
+};
@@ -0,0 +1,16 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { Description } from "./description";
+
+export const Root = component$(() => {
+	const hasDescription = isComponentPresent(Description);
+
+	return (
+		<div
+			role="switch"
+			aria-describedby={hasDescription ? "switch-description" : undefined}
+		>
+			<Slot />
+		</div>
+	);
+});
@@ -0,0 +1,13 @@
+import { Panel } from "./panel";
+import { Root } from "./root";
+
+export interface TabsProps {
+	label?: string;
+}
+
+export const Tabs = {
+	Root,
+	Panel,
+};
+
+export { Panel };
@@ -0,0 +1,9 @@
+import { component$, Slot } from "@builder.io/qwik";
+
+export const Panel = component$(() => {
+	return (
+		<div role="tabpanel">
+			<Slot />
+		</div>
+	);
+});
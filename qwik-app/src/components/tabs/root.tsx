@@ -0,0 +1,14 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { Tabs } from ".";
+
+export const Root = component$(() => {
+	const hasPanel = isComponentPresent(Tabs.Panel);
+
+	return (
+		<div>
+			<Slot />
+			{!hasPanel && <p>No panels yet</p>}
+		</div>
+	);
+});
@@ -0,0 +1,13 @@
+import { component$, Slot } from "@builder.io/qwik";
+
+export type TipHintProps = {
+	tone?: "info" | "warning";
+};
+
+export const Hint = component$<TipHintProps>(({ tone = "info" }) => {
+	return (
+		<span class={`tip-hint tip-hint-${tone}`}>
+			<Slot />
+		</span>
+	);
+});
@@ -0,0 +1,7 @@
+import { Hint } from "./hint";
+import { Root } from "./root";
+
+export type { Hint, Root } from "./types";
+export { type TipRootProps } from "./root";
+export type { TipHintProps } from "./hint";
+export const Tip = { Root, Hint };
@@ -0,0 +1,17 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { Hint } from "./hint";
+
+export type TipRootProps = {
+	open?: boolean;
+};
+
+export const Root = component$(() => {
+	const hasHint = isComponentPresent(Hint);
+
+	return (
+		<div class="tip-root" data-has-hint={hasHint}>
+			<Slot />
+		</div>
+	);
+});
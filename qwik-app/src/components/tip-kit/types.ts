@@ -0,0 +1,6 @@
+import type { TipHintProps } from "./hint";
+import type { TipRootProps } from "./root";
+
+/** Props of `Tip.Root` and `Tip.Hint`, for wrappers that forward them */
+export type Root = TipRootProps;
+export type Hint = TipHintProps;
@@ -0,0 +1,9 @@
+import { component$, Slot } from "@builder.io/qwik";
+
+export const Field = component$(() => {
+	return (
+		<div class="field">
+			<Slot />
+		</div>
+	);
+});
@@ -0,0 +1,11 @@
+import { Description } from "./description";
+import { Root as ToggleRoot } from "./root";
+
+// The named `Root` is the plain form field; the toggle's own Root is the default export's
+export { Field as Root } from "./field";
+export { Description };
+
+export default {
+	Root: ToggleRoot,
+	Description,
+};
@@ -0,0 +1,14 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../../src/vite/plugin";
+import { Description } from "./description";
+
+export const Root = component$(() => {
+	const hasDescription = isComponentPresent(Description);
+
+	return (
+		<label aria-describedby={hasDescription ? "toggle-description" : undefined}>
+			<input type="checkbox" role="switch" />
+			<Slot />
+		</label>
+	);
+});
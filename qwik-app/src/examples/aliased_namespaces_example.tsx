@@ -0,0 +1,20 @@
+import { component$ } from "@builder.io/qwik";
+import { CheckboxKit } from "../components/checkbox-kit";
+import { CheckboxKit as CB } from "../components/checkbox-kit";
+
+export default component$(() => {
+	return (
+		<>
+			<CheckboxKit.Root>
+				<input type="checkbox" name="terms" />
+			</CheckboxKit.Root>
+			<CB.Root>
+				<input type="checkbox" name="updates" />
+				<CB.Description>Receive weekly updates</CB.Description>
+			</CB.Root>
+			<CB.Root>
+				<input type="checkbox" name="offers" />
+			</CB.Root>
+		</>
+	);
+});
@@ -0,0 +1,11 @@
+import { component$ } from "@builder.io/qwik";
+import { Field } from "../components/field";
+
+export default component$(() => {
+	return (
+		<Field.Root>
+			<input type="text" />
+			<Field.HelperText>At least 8 characters</Field.HelperText>
+		</Field.Root>
+	);
+});
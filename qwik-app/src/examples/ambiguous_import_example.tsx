@@ -0,0 +1,11 @@
+import { component$ } from "@builder.io/qwik";
+import type { Field as DummyComp } from "../components/field";
+import { DummyComp } from "../components/dummy-comp";
+
+export default component$(() => {
+	return (
+		<DummyComp.Root>
+			<DummyComp.Description />
+		</DummyComp.Root>
+	);
+});
@@ -0,0 +1,15 @@
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+
+export default component$(() => {
+	return (
+		<DummyComp.Root
+			class="terms-checkbox"
+			aria-label="Terms and Conditions"
+			data-testid="terms"
+		>
+			<button type="button">Accept</button>
+			<DummyComp.Description />
+		</DummyComp.Root>
+	);
+});
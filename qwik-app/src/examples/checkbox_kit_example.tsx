@@ -0,0 +1,11 @@
+import { component$ } from "@builder.io/qwik";
+import { CheckboxKit } from "../components/checkbox-kit";
+
+export default component$(() => {
+	return (
+		<CheckboxKit.Root>
+			<input type="checkbox" />
+			<CheckboxKit.Description>Receive weekly updates</CheckboxKit.Description>
+		</CheckboxKit.Root>
+	);
+});
@@ -0,0 +1,10 @@
+import { component$ } from "@builder.io/qwik";
+import { ComposedKit } from "../components/composed-kit";
+
+export default component$(() => {
+	return (
+		<ComposedKit.Root>
+			<button type="button">Some trigger</button>
+		</ComposedKit.Root>
+	);
+});
@@ -0,0 +1,12 @@
+import { component$ } from "@builder.io/qwik";
+import { Root as ConsentRoot } from "../components/consent-kit/root";
+import { Note } from "../components/consent-kit/note";
+
+export default component$(() => {
+	return (
+		<form>
+			<ConsentRoot>I agree to the terms</ConsentRoot>
+			<Note>You can withdraw at any time</Note>
+		</form>
+	);
+});
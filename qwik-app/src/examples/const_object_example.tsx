@@ -0,0 +1,13 @@
+import { component$ } from "@builder.io/qwik";
+import { Radio } from "../components/radio-kit";
+
+export default component$(() => {
+	return (
+		<Radio.Root>
+			<Radio.Legend>Delivery speed</Radio.Legend>
+			<label>
+				<input type="radio" name="speed" value="standard" /> Standard
+			</label>
+		</Radio.Root>
+	);
+});
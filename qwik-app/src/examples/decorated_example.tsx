@@ -0,0 +1,24 @@
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+
+function tracked(target: unknown, context: ClassMethodDecoratorContext) {
+	return target;
+}
+
+class Store {
+	@tracked
+	label() {
+		return "Some trigger";
+	}
+}
+
+export default component$(() => {
+	const store = new Store();
+
+	return (
+		<DummyComp.Root>
+			<button type="button">{store.label()}</button>
+			<DummyComp.Description />
+		</DummyComp.Root>
+	);
+});
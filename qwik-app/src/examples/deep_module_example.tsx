@@ -0,0 +1,10 @@
+import { component$ } from "@builder.io/qwik";
+import * as DeepKit from "../components/deep-kit";
+
+export default component$(() => {
+	return (
+		<DeepKit.Root>
+			<DeepKit.Item>First</DeepKit.Item>
+		</DeepKit.Root>
+	);
+});
@@ -0,0 +1,11 @@
+import { component$ } from "@builder.io/qwik";
+import Toggle, { Description } from "../components/toggle-kit";
+
+export default component$(() => {
+	return (
+		<Toggle.Root>
+			Dark mode
+			<Description>Follows the system setting</Description>
+		</Toggle.Root>
+	);
+});
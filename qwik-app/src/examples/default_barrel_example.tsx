@@ -0,0 +1,11 @@
+import { component$ } from "@builder.io/qwik";
+import Notice, { Icon } from "../components/notice";
+
+export default component$(() => {
+	return (
+		<Notice>
+			<Icon />
+			Your trial ends tomorrow.
+		</Notice>
+	);
+});
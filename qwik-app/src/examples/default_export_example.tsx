@@ -0,0 +1,10 @@
+import { component$ } from "@builder.io/qwik";
+import * as Accordion from "../components/accordion";
+
+export default component$(() => {
+	return (
+		<Accordion.Root>
+			<Accordion.Item />
+		</Accordion.Root>
+	);
+});
@@ -0,0 +1,11 @@
+import { component$ } from "@builder.io/qwik";
+import Switch from "../components/switch-kit";
+
+export default component$(() => {
+	return (
+		<Switch.Root>
+			<button type="button">Airplane mode</button>
+			<Switch.Description>Turns off all radios</Switch.Description>
+		</Switch.Root>
+	);
+});
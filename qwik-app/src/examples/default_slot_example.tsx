@@ -0,0 +1,11 @@
+import { component$ } from "@builder.io/qwik";
+import { Fallback } from "../components/fallback-kit";
+
+export default component$(() => {
+	return (
+		<Fallback.Root>
+			<h2>Provided</h2>
+			<Fallback.Description>Written by the page</Fallback.Description>
+		</Fallback.Root>
+	);
+});
@@ -0,0 +1,10 @@
+import { component$ } from "@builder.io/qwik";
+import { Fallback } from "../components/fallback-kit";
+
+export default component$(() => {
+	return (
+		<Fallback.Root>
+			<h2>Left to the Root</h2>
+		</Fallback.Root>
+	);
+});
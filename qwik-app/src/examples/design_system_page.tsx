@@ -0,0 +1,17 @@
+import { component$ } from "@builder.io/qwik";
+import { CheckboxKit } from "../components/checkbox-kit";
+import { Radio } from "../components/radio-kit";
+
+export default component$(() => {
+	return (
+		<form>
+			<CheckboxKit.Root>
+				<input type="checkbox" />
+				<CheckboxKit.Description>Receive weekly updates</CheckboxKit.Description>
+			</CheckboxKit.Root>
+			<Radio.Root>
+				<Radio.Legend>Delivery speed</Radio.Legend>
+			</Radio.Root>
+		</form>
+	);
+});
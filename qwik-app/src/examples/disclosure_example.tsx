@@ -0,0 +1,11 @@
+import { component$ } from "@builder.io/qwik";
+import { Disclosure } from "../components/disclosure-kit";
+
+export default component$(() => {
+	return (
+		<Disclosure.Root>
+			<h3>Shipping</h3>
+			<Disclosure.Details>Orders ship within two business days.</Disclosure.Details>
+		</Disclosure.Root>
+	);
+});
@@ -0,0 +1,15 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../src/vite/plugin";
+import { Description } from "../components/dummy-comp/description";
+
+export const EmptyPresenceCall = component$(() => {
+	// @ts-expect-error - the component to check for is missing
+	const isAnything = isComponentPresent();
+	const isDescription = isComponentPresent(Description);
+
+	return (
+		<div data-anything={isAnything} data-described={isDescription}>
+			<Slot />
+		</div>
+	);
+});
@@ -0,0 +1,11 @@
+import { component$ } from "@builder.io/qwik";
+import { Hint, Root } from "../components/export-list-kit";
+
+export default component$(() => {
+	return (
+		<Root>
+			<li>First item</li>
+			<Hint>Items are sorted by date</Hint>
+		</Root>
+	);
+});
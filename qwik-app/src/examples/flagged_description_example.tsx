@@ -0,0 +1,17 @@
+import { component$ } from "@builder.io/qwik";
+import { CheckboxKit } from "../components/checkbox-kit";
+import { Description as RealDescription } from "../components/checkbox-kit/description";
+import { Title } from "../components/checkbox-kit/title";
+
+const NEW_DESCRIPTION = import.meta.env.PUBLIC_NEW_DESCRIPTION === "true";
+
+const Description = NEW_DESCRIPTION ? RealDescription : Title;
+
+export default component$(() => {
+	return (
+		<CheckboxKit.Root>
+			<input type="checkbox" />
+			<Description>Receive weekly updates</Description>
+		</CheckboxKit.Root>
+	);
+});
@@ -0,0 +1,10 @@
+import { component$ } from "@builder.io/qwik";
+import { CheckboxRoot } from "../components/flat-checkbox";
+
+export default component$(() => {
+	return (
+		<CheckboxRoot>
+			<input type="checkbox" />
+		</CheckboxRoot>
+	);
+});
@@ -0,0 +1,11 @@
+import { component$ } from "@builder.io/qwik";
+import { CheckboxDescription, CheckboxRoot } from "../components/flat-checkbox";
+
+export default component$(() => {
+	return (
+		<CheckboxRoot>
+			<input type="checkbox" />
+			<CheckboxDescription>Sent once a week</CheckboxDescription>
+		</CheckboxRoot>
+	);
+});
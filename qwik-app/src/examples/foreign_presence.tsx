@@ -0,0 +1,14 @@
+import { component$, Slot } from "@builder.io/qwik";
+// An unrelated package's helper that happens to share the analyzer's function name
+import { isComponentPresent } from "@acme/registry";
+import { DummyComp } from "../components/dummy-comp";
+
+export const ForeignPresence = component$(() => {
+	const hasDescription = isComponentPresent(DummyComp.Description);
+
+	return (
+		<div data-described={hasDescription}>
+			<Slot />
+		</div>
+	);
+});
@@ -0,0 +1,11 @@
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+import { ForeignPresence } from "./foreign_presence";
+
+export default component$(() => {
+	return (
+		<ForeignPresence>
+			<DummyComp.Description />
+		</ForeignPresence>
+	);
+});
@@ -0,0 +1,14 @@
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+
+export default component$(() => {
+	return (
+		<DummyComp.Root>
+			{(state: { checked: boolean }) => (
+				<DummyComp.Description>
+					{state.checked ? "Checked" : "Unchecked"}
+				</DummyComp.Description>
+			)}
+		</DummyComp.Root>
+	);
+});
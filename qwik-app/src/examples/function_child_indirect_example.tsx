@@ -0,0 +1,11 @@
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+import { FunctionChildPanel } from "./function_child_panel";
+
+export default component$(() => {
+	return (
+		<DummyComp.Root>
+			<FunctionChildPanel />
+		</DummyComp.Root>
+	);
+});
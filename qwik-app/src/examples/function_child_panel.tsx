@@ -0,0 +1,12 @@
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+
+export const FunctionChildPanel = component$(() => {
+	return (
+		<section>
+			{function renderDescription() {
+				return <DummyComp.Description />;
+			}}
+		</section>
+	);
+});
@@ -0,0 +1,13 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+import { isComponentPresent } from "./utils";
+
+export const HelperPresence = component$(() => {
+	const hasDescription = isComponentPresent(DummyComp.Description);
+
+	return (
+		<div data-described={hasDescription}>
+			<Slot />
+		</div>
+	);
+});
@@ -0,0 +1,11 @@
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+import { HelperPresence } from "./helper_presence";
+
+export default component$(() => {
+	return (
+		<HelperPresence>
+			<DummyComp.Description />
+		</HelperPresence>
+	);
+});
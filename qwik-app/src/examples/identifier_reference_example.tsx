@@ -0,0 +1,12 @@
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+import { Description } from "../components/dummy-comp/description";
+
+export default component$(() => {
+	return (
+		<DummyComp.Root>
+			<button type="button">Some trigger</button>
+			<Description />
+		</DummyComp.Root>
+	);
+});
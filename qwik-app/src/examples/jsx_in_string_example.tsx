@@ -0,0 +1,16 @@
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+
+// Usage docs: <DummyComp.Root><DummyComp.Description /></DummyComp.Root>
+const snippet = `<DummyComp.Root>
+	<DummyComp.Title>Terms</DummyComp.Title>
+</DummyComp.Root>`;
+
+export default component$(() => {
+	return (
+		<DummyComp.Root>
+			<pre>{snippet}</pre>
+			<code>{"<DummyComp.Description />"}</code>
+		</DummyComp.Root>
+	);
+});
@@ -0,0 +1,10 @@
+import { component$ } from "@builder.io/qwik";
+import { Labeled } from "../components/labeled-kit";
+
+export default component$(() => {
+	return (
+		<Labeled.Root label="Email">
+			<Labeled.Hint>We never share it</Labeled.Hint>
+		</Labeled.Root>
+	);
+});
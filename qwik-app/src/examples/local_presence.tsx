@@ -0,0 +1,15 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+
+// Shares its name with the analyzer's function but only checks that a component was passed
+const isComponentPresent = (component: unknown) => component !== undefined;
+
+export const LocalPresence = component$(() => {
+	const hasDescription = isComponentPresent(DummyComp.Description);
+
+	return (
+		<div data-described={hasDescription}>
+			<Slot />
+		</div>
+	);
+});
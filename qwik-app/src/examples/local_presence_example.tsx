@@ -0,0 +1,11 @@
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+import { LocalPresence } from "./local_presence";
+
+export default component$(() => {
+	return (
+		<LocalPresence>
+			<DummyComp.Description />
+		</LocalPresence>
+	);
+});
@@ -0,0 +1,14 @@
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+
+const sections = [DummyComp.Title, DummyComp.Description];
+
+export default component$(() => {
+	return (
+		<DummyComp.Root>
+			{sections.map((Section, index) => (
+				<Section key={index}>Section {index + 1}</Section>
+			))}
+		</DummyComp.Root>
+	);
+});
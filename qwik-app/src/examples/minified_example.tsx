@@ -0,0 +1 @@
+import{component$}from"@builder.io/qwik";import{Min}from"../components/minified-kit";export default component$(()=><div><Min.Root><Min.Hint>Tip</Min.Hint></Min.Root></div>);
@@ -0,0 +1 @@
+import{component$}from"@builder.io/qwik";import{Min}from"../components/minified-kit";export default component$(()=><Min.Root data-min={1}/>);
@@ -0,0 +1,12 @@
+import { component$ } from "@builder.io/qwik";
+import { Tabs, Panel, type TabsProps } from "../components/tabs";
+
+export const tabsProps: TabsProps = { label: "Mixed tabs" };
+
+export default component$(() => {
+	return (
+		<Tabs.Root>
+			<Panel>First panel</Panel>
+		</Tabs.Root>
+	);
+});
@@ -0,0 +1,14 @@
+import { component$ } from "@builder.io/qwik";
+import { CheckboxKit } from "../components/checkbox-kit";
+
+export default component$(() => {
+	return (
+		<div>
+			<CheckboxKit.Root>
+				<input type="checkbox" />
+				<CheckboxKit.Description>Receive weekly updates</CheckboxKit.Description>
+			</CheckboxKit.Root>
+			<CheckboxKit.Root>{/* empty */}</CheckboxKit.Root>
+		</div>
+	);
+});
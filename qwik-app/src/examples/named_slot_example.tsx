@@ -0,0 +1,17 @@
+import { component$ } from "@builder.io/qwik";
+import { CardKit } from "../components/card-kit";
+
+export default component$(() => {
+	return (
+		<CardKit.Root>
+			<p>Terms apply to every plan.</p>
+			<div q:slot="description">
+				<CardKit.Description>Billed yearly</CardKit.Description>
+			</div>
+			{/* The Root renders no "heading" slot, so this Title is never projected */}
+			<header q:slot="heading">
+				<CardKit.Title>Pro plan</CardKit.Title>
+			</header>
+		</CardKit.Root>
+	);
+});
@@ -0,0 +1,12 @@
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+import { Panel } from "./panel";
+
+export default component$(() => {
+	return (
+		<DummyComp.Root>
+			<button type="button">Some trigger</button>
+			<Panel />
+		</DummyComp.Root>
+	);
+});
@@ -0,0 +1,21 @@
+import { component$, Slot, type PropsOf } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../src/vite/plugin";
+import { CheckboxKit } from "../components/checkbox-kit";
+import { DummyComp } from "../components/dummy-comp";
+import { Note } from "../components/consent-kit/note";
+
+// Defines a Root of its own, checking for Note, while rendering two library Roots
+export const NotedRoot = component$((props: PropsOf<typeof DummyComp.Root>) => {
+	const hasNote = isComponentPresent(Note);
+
+	return (
+		<div class={hasNote ? "noted" : undefined}>
+			<DummyComp.Root {...props}>
+				<Slot />
+			</DummyComp.Root>
+			<CheckboxKit.Root>
+				<input type="checkbox" />
+			</CheckboxKit.Root>
+		</div>
+	);
+});
@@ -0,0 +1,13 @@
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+import { Note } from "../components/consent-kit/note";
+import { NotedRoot } from "./noted_root";
+
+export default component$(() => {
+	return (
+		<NotedRoot>
+			<DummyComp.Title>Preferences</DummyComp.Title>
+			<Note>Saved to this device only</Note>
+		</NotedRoot>
+	);
+});
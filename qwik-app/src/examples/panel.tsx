@@ -0,0 +1,10 @@
+import { component$ } from "@builder.io/qwik";
+import { Heyo } from "./heyo";
+
+export const Panel = component$(() => {
+	return (
+		<section>
+			<Heyo />
+		</section>
+	);
+});
@@ -0,0 +1,11 @@
+import { component$ } from "@builder.io/qwik";
+import { Tip } from "ui/tip-kit";
+
+export default component$(() => {
+	return (
+		<Tip.Root>
+			<button type="button">Shortcuts</button>
+			<Tip.Hint>Press ? to list them</Tip.Hint>
+		</Tip.Root>
+	);
+});
@@ -0,0 +1,10 @@
+import { component$ } from "@builder.io/qwik";
+import { Relay } from "../components/relay-kit";
+
+export default component$(() => {
+	return (
+		<Relay.Root>
+			<Relay.Hint>Forwarded to analytics</Relay.Hint>
+		</Relay.Root>
+	);
+});
@@ -0,0 +1,11 @@
+import { component$ } from "@builder.io/qwik";
+import { LegendKit } from "../components/legend-kit";
+
+export default component$(() => {
+	return (
+		<LegendKit.Root>
+			<LegendKit.Legend>Shipping address</LegendKit.Legend>
+			<input name="street" />
+		</LegendKit.Root>
+	);
+});
@@ -0,0 +1,10 @@
+import { component$ } from "@builder.io/qwik";
+import { Item as AccordionItem, Root as AccordionRoot } from "../components/accordion";
+
+export default component$(() => {
+	return (
+		<AccordionRoot>
+			<AccordionItem />
+		</AccordionRoot>
+	);
+});
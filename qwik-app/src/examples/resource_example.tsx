@@ -0,0 +1,17 @@
+import { component$, Resource, useResource$ } from "@builder.io/qwik";
+import { CheckboxKit } from "../components/checkbox-kit";
+
+export default component$(() => {
+	const terms = useResource$(async () => "Accept the terms");
+
+	return (
+		<CheckboxKit.Root>
+			<input type="checkbox" />
+			<Resource
+				value={terms}
+				onPending={() => <span>Loading…</span>}
+				onResolved$={(text) => <CheckboxKit.Description>{text}</CheckboxKit.Description>}
+			/>
+		</CheckboxKit.Root>
+	);
+});
@@ -0,0 +1,11 @@
+import { component$ } from "@builder.io/qwik";
+import { Tabs } from "../components/tabs";
+import { Panel } from "../components/tabs/panel";
+
+export default component$(() => {
+	return (
+		<Tabs.Root>
+			<Panel>First panel</Panel>
+		</Tabs.Root>
+	);
+});
@@ -0,0 +1,13 @@
+import { component$ } from "@builder.io/qwik";
+import { CheckboxKit } from "../components/checkbox-kit";
+
+export default component$(() => {
+	return (
+		<div>
+			<CheckboxKit.Root>
+				{/* the description lives outside */}
+			</CheckboxKit.Root>
+			<CheckboxKit.Description>Shown outside the checkbox</CheckboxKit.Description>
+		</div>
+	);
+});
@@ -0,0 +1,13 @@
+import { component$ } from "@builder.io/qwik";
+import { CheckboxKit } from "../components/checkbox-kit";
+
+export default component$(() => {
+	return (
+		<div>
+			<CheckboxKit.Root>
+				{/* the description follows */}
+				<CheckboxKit.Description>Receive weekly updates</CheckboxKit.Description>
+			</CheckboxKit.Root>
+		</div>
+	);
+});
@@ -0,0 +1,13 @@
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+
+export default component$(() => {
+	return (
+		<>
+			<DummyComp.Root>
+				<button type="button">Toggle</button>
+			</DummyComp.Root>
+			<DummyComp.Description />
+		</>
+	);
+});
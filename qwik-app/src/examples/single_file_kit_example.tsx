@@ -0,0 +1,10 @@
+import { component$ } from "@builder.io/qwik";
+import { Toggle } from "./toggle";
+
+export default component$(() => {
+	return (
+		<Toggle.Root>
+			<Toggle.Label>Dark mode</Toggle.Label>
+		</Toggle.Root>
+	);
+});
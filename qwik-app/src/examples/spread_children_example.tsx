@@ -0,0 +1,16 @@
+import { component$ } from "@builder.io/qwik";
+import { CheckboxKit } from "../components/checkbox-kit";
+
+export default component$(() => {
+	const rootProps = {
+		name: "newsletter",
+		children: <CheckboxKit.Description>Receive weekly updates</CheckboxKit.Description>,
+	};
+
+	return (
+		<div>
+			<CheckboxKit.Root {...rootProps} />
+			<CheckboxKit.Root>{/* empty */}</CheckboxKit.Root>
+		</div>
+	);
+});
@@ -0,0 +1,11 @@
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+import { SpreadPanel } from "./spread_panel";
+
+export default component$(() => {
+	return (
+		<DummyComp.Root>
+			<SpreadPanel />
+		</DummyComp.Root>
+	);
+});
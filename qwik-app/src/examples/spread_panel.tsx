@@ -0,0 +1,6 @@
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+
+export const SpreadPanel = component$(() => {
+	return <section>{...[<DummyComp.Description />]}</section>;
+});
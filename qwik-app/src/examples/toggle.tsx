@@ -0,0 +1,31 @@
+import { component$, Slot } from "@builder.io/qwik";
+import { isComponentPresent } from "../../../src/vite/plugin";
+
+const Label = component$(() => {
+	return (
+		<span>
+			<Slot />
+		</span>
+	);
+});
+
+const Hint = component$(() => {
+	return (
+		<small>
+			<Slot />
+		</small>
+	);
+});
+
+const Root = component$(() => {
+	const hasLabel = isComponentPresent(Label);
+	const hasHint = isComponentPresent(Hint);
+
+	return (
+		<button type="button" aria-labelledby={hasLabel ? "label" : undefined} data-hinted={hasHint}>
+			<Slot />
+		</button>
+	);
+});
+
+export const Toggle = { Root, Label, Hint };
@@ -0,0 +1,11 @@
+import { component$ } from "@builder.io/qwik";
+import { Tip } from "../components/tip-kit";
+
+export default component$(() => {
+	return (
+		<Tip.Root>
+			<button type="button">Shortcuts</button>
+			<Tip.Hint tone="warning">Press ? to list them</Tip.Hint>
+		</Tip.Root>
+	);
+});
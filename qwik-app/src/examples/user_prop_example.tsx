@@ -0,0 +1,10 @@
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+
+export default component$(() => {
+	return (
+		<DummyComp.Root __qwik_analyzer_has_Description={false}>
+			<DummyComp.Description />
+		</DummyComp.Root>
+	);
+});
@@ -0,0 +1,4 @@
+// A project helper that happens to share the analyzer's function name
+export function isComponentPresent(component: unknown): boolean {
+	return component !== undefined;
+}
@@ -0,0 +1,10 @@
+import { component$, Slot, type PropsOf } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+
+export const WrappedRoot = component$((props: PropsOf<typeof DummyComp.Root>) => {
+	return (
+		<DummyComp.Root {...props}>
+			<Slot />
+		</DummyComp.Root>
+	);
+});
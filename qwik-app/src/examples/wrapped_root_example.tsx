@@ -0,0 +1,12 @@
+import { component$ } from "@builder.io/qwik";
+import { DummyComp } from "../components/dummy-comp";
+import { WrappedRoot } from "./wrapped_root";
+
+export default component$(() => {
+	return (
+		<WrappedRoot>
+			<button type="button">Some trigger</button>
+			<DummyComp.Description />
+		</WrappedRoot>
+	);
+});
@@ -1,20 +1,38 @@
-use oxc_semantic::Semantic;
+use oxc_semantic::{NodeId, Semantic};
+use std::collections::HashMap;
 use std::path::Path;
 use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+  Expression, JSXAttributeItem, JSXAttributeName, JSXAttributeValue, JSXElement,
+  JSXOpeningElement, LogicalOperator, ObjectPropertyKind,
+};
 use oxc_ast::AstKind;
 use oxc_parser;
-use oxc_span::SourceType;
+use oxc_span::{GetSpan, Span};
 
+use crate::component_analyzer::config::current_config;
+use crate::component_analyzer::declarations::{declared_members, module_renders_jsx, package_types_file};
+use crate::component_analyzer::explanation;
 use crate::component_analyzer::import_resolver::{
-  file_has_component, find_calls_in_file, resolve_component_from_index,
-  find_import_source_for_component, resolve_import_path,
+  file_has_component, find_calls_in_component, find_calls_in_file, find_import_source_for_component,
+  is_local_specifier, is_type_only_export, local_part_binding, module_index_file,
+  presence_target_name, resolve_import_path, resolve_imported_component_file,
+  resolve_namespace_member, ImportBinding, ImportKind,
 };
-use crate::component_analyzer::jsx_analysis::extract_imported_jsx_components;
+use crate::component_analyzer::jsx_analysis::{extract_imported_jsx_components, extract_jsx_element_name};
+use crate::component_analyzer::transformations::jsx_element_resolves_to_source_file;
 use crate::component_analyzer::utils::{
-  component_exists_in_jsx_with_path, debug, ComponentPresenceCall,
+  component_alternatives, component_exists_in_jsx_with_path, debug, extract_function_name,
+  is_presence_call,
+  jsx_element_matches_component, read_dependency, same_file, source_extensions,
+  source_type_for_path, within_sandbox, ComponentPresenceCall, Presence,
 };
 use crate::Result;
 
+
+/// Maximum directory depth scanned below a module root when its index can't name the component
+const MAX_MODULE_SCAN_DEPTH: usize = 8;
+
 fn is_external_import(import_source: &str, current_file: &Path) -> bool {
   // Use oxc_resolver to get the actual resolved path
   match resolve_import_path(import_source, current_file) {
@@ -29,10 +47,35 @@ fn is_external_import(import_source: &str, current_file: &Path) -> bool {
   }
 }
 
+/// The children a Root definition file checks for with `isComponentPresent`, sorted and without
+/// repeats. Each alternative of `isComponentPresent([Description, HelperText])` is listed.
+pub fn declared_optional_children(root_file: &Path) -> Result<Vec<String>> {
+  let calls = find_calls_in_file(&root_file.to_string_lossy())?;
+
+  let mut children: Vec<String> = calls
+    .iter()
+    .flat_map(|call| component_alternatives(&call.component_name))
+    .map(str::to_string)
+    .collect();
+  children.sort();
+  children.dedup();
+
+  Ok(children)
+}
+
 pub fn find_presence_calls(
   semantic: &Semantic,
   jsx_component: &str,
   current_file: &Path,
+) -> Result<Vec<ComponentPresenceCall>> {
+  find_presence_calls_at_depth(semantic, jsx_component, current_file, 0)
+}
+
+fn find_presence_calls_at_depth(
+  semantic: &Semantic,
+  jsx_component: &str,
+  current_file: &Path,
+  depth: usize,
 ) -> Result<Vec<ComponentPresenceCall>> {
   debug(&format!(
     "🔍 Analyzing imported component: {}",
@@ -48,11 +91,11 @@ pub fn find_presence_calls(
     let module_name = parts[0];
     let component_name = parts[1];
 
-    let Some(import_source) = find_import_source_for_component(semantic, module_name) else {
+    let Some(import_binding) = find_import_source_for_component(semantic, module_name) else {
       return Ok(Vec::new());
     };
 
-    let Ok(module_dir) = resolve_import_path(&import_source, current_file) else {
+    let Ok(module_dir) = resolve_import_path(&import_binding.source, current_file) else {
       return Ok(Vec::new());
     };
 
@@ -63,26 +106,16 @@ pub fn find_presence_calls(
 
     debug(&format!("📂 About to scan module {} for component {}", module_dir, component_name));
     
-    // Try to find index file in the module directory
-    let module_path = Path::new(&module_dir);
-    let index_file = if module_path.is_file() {
-      module_dir.clone()
-    } else {
-      // Look for index.ts or index.tsx in the directory
-      let index_ts = module_path.join("index.ts");
-      let index_tsx = module_path.join("index.tsx");
-      if index_ts.exists() {
-        index_ts.to_string_lossy().to_string()
-      } else if index_tsx.exists() {
-        index_tsx.to_string_lossy().to_string()
-      } else {
-        module_dir.clone() // Fallback to original behavior
-      }
-    };
-    
-    debug(&format!("🔍 Trying resolve_component_from_index for {} in index file {}", component_name, index_file));
-    if let Ok(component_file) = resolve_component_from_index(&index_file, component_name) {
+    let index_file = module_index_file(&module_dir);
+
+    debug(&format!("🔍 Trying resolve_namespace_member for {} in index file {}", component_name, index_file));
+    if let Ok(component_file) = resolve_namespace_member(&import_binding, &index_file, component_name) {
       debug(&format!("📂 Found component file: {}", component_file));
+      // A single-file compound component defines every part in one file, so only the calls
+      // made by this part belong to it
+      if let Some(binding) = local_part_binding(&component_file, component_name)? {
+        return find_calls_in_component(&component_file, &binding);
+      }
       return find_calls_in_file(&component_file);
     } else {
       debug(&format!("📂 No direct component file found, scanning entire module: {}", module_dir));
@@ -90,26 +123,691 @@ pub fn find_presence_calls(
     }
   }
 
-  let Some(import_source) = find_import_source_for_component(semantic, jsx_component) else {
+  let Some(import_source) = find_import_source_for_component(semantic, jsx_component)
+    .map(|import_binding| import_binding.source)
+  else {
     return Ok(Vec::new());
   };
 
   let Ok(resolved_path) = resolve_import_path(&import_source, current_file) else {
     return Ok(Vec::new());
   };
+  // `import { Root as CheckboxRoot } from "./checkbox"` names the barrel, not the Root's file
+  let resolved_path = resolve_imported_component_file(semantic, jsx_component, resolved_path);
 
   debug(&format!(
     "📂 Resolved component {} to: {}",
     jsx_component, resolved_path
   ));
-  find_calls_in_file(&resolved_path)
+
+  // A component can both make presence checks of its own and wrap another library's Root, whose
+  // checks it forwards alongside them
+  let mut calls = find_calls_in_file(&resolved_path)?;
+  if depth < current_config().max_component_depth {
+    calls.extend(find_wrapped_root_calls(&resolved_path, depth + 1)?);
+  }
+  Ok(calls)
 }
 
-pub fn has_component(
+/// Presence calls of the Roots a wrapper component renders with forwarded props around a
+/// `<Slot />`, e.g. `<Checkbox.Root {...props}><Slot /></Checkbox.Root>`. The calls are attributed
+/// to the wrapper's file, so usages of the wrapper receive the props and forward them to the Root.
+fn find_wrapped_root_calls(
+  wrapper_file: &str,
+  depth: usize,
+) -> Result<Vec<ComponentPresenceCall>> {
+  let source_text = read_dependency(wrapper_file)?;
+  let allocator = Allocator::default();
+  let source_type = source_type_for_path(Path::new(wrapper_file));
+
+  let oxc_parser::ParserReturn { program, errors, .. } =
+    oxc_parser::Parser::new(&allocator, &source_text, source_type).parse();
+
+  if !errors.is_empty() {
+    return Ok(Vec::new());
+  }
+
+  let semantic_ret = oxc_semantic::SemanticBuilder::new().build(&program);
+  let semantic = &semantic_ret.semantic;
+
+  let mut calls = Vec::new();
+  for jsx_opening in elements_projecting_slot(semantic) {
+    let forwards_props = jsx_opening
+      .attributes
+      .iter()
+      .any(|attribute| matches!(attribute, JSXAttributeItem::SpreadAttribute(_)));
+    if !forwards_props {
+      continue;
+    }
+
+    let Some(element_name) = extract_jsx_element_name(jsx_opening) else {
+      continue;
+    };
+
+    let wrapped_calls =
+      find_presence_calls_at_depth(semantic, &element_name, Path::new(wrapper_file), depth)?;
+    for mut call in wrapped_calls {
+      debug(&format!(
+        "🎁 {} wraps {}, attributing its isComponentPresent({}) to the wrapper",
+        wrapper_file, element_name, call.component_name
+      ));
+      call.source_file = wrapper_file.to_string();
+      calls.push(call);
+    }
+  }
+
+  Ok(calls)
+}
+
+/// Opening elements with a Qwik `<Slot />` among their descendants, whose children therefore partly
+/// come from the caller of the component rendering them
+pub fn elements_projecting_slot<'a>(semantic: &Semantic<'a>) -> Vec<&'a JSXOpeningElement<'a>> {
+  let nodes = semantic.nodes();
+  let mut elements: Vec<&JSXOpeningElement> = Vec::new();
+
+  if find_import_source_for_component(semantic, "Slot")
+    .is_none_or(|import_binding| import_binding.source != "@builder.io/qwik")
+  {
+    return elements;
+  }
+
+  for node in nodes.iter() {
+    let AstKind::JSXOpeningElement(slot_opening) = node.kind() else {
+      continue;
+    };
+
+    if extract_jsx_element_name(slot_opening).as_deref() != Some("Slot") {
+      continue;
+    }
+
+    for ancestor_id in nodes.ancestor_ids(node.id()) {
+      let AstKind::JSXElement(jsx_element) = nodes.kind(ancestor_id) else {
+        continue;
+      };
+
+      let opening = &jsx_element.opening_element;
+      if opening.span != slot_opening.span
+        && !elements.iter().any(|element| element.span == opening.span)
+      {
+        elements.push(opening);
+      }
+    }
+  }
+
+  elements
+}
+
+/// Whether an element rendering `component_name` sits inside an element rendering the Root defined
+/// in `source_file`, rather than elsewhere in the file
+pub fn component_within_root(
   semantic: &Semantic,
   component_name: &str,
+  source_file: &str,
   current_file: &Path,
 ) -> Result<bool> {
+  let nodes = semantic.nodes();
+
+  for node in nodes.iter() {
+    let AstKind::JSXOpeningElement(jsx_opening) = node.kind() else {
+      continue;
+    };
+
+    let is_target = extract_jsx_element_name(jsx_opening).is_some_and(|element_name| {
+      jsx_element_matches_component(semantic, &element_name, component_name, current_file)
+    });
+    if !is_target {
+      continue;
+    }
+
+    for jsx_element in enclosing_jsx_elements(semantic, node.id()) {
+      let opening = &jsx_element.opening_element;
+      if opening.span == jsx_opening.span {
+        continue;
+      }
+
+      let Some(ancestor_name) = extract_jsx_element_name(opening) else {
+        continue;
+      };
+
+      if jsx_element_resolves_to_source_file(semantic, &ancestor_name, source_file, current_file)? {
+        return Ok(true);
+      }
+    }
+  }
+
+  Ok(false)
+}
+
+/// The JSX elements enclosing `node_id`, innermost first. JSX in the `children` of a locally bound
+/// object literal is enclosed by the elements the object is spread into, e.g. by
+/// `<Checkbox.Root {...rootProps} />` for `const rootProps = { children: <Description /> }`.
+fn enclosing_jsx_elements<'a>(semantic: &Semantic<'a>, node_id: NodeId) -> Vec<&'a JSXElement<'a>> {
+  let nodes = semantic.nodes();
+  let mut elements = Vec::new();
+  let mut pending = vec![node_id];
+  let mut followed_bindings = Vec::new();
+
+  while let Some(start_id) = pending.pop() {
+    let start_span = nodes.kind(start_id).span();
+    for ancestor_id in nodes.ancestor_ids(start_id) {
+      let declarator = match nodes.kind(ancestor_id) {
+        AstKind::JSXElement(jsx_element) => {
+          elements.push(jsx_element);
+          continue;
+        }
+        AstKind::VariableDeclarator(declarator) => declarator,
+        _ => continue,
+      };
+
+      let Some(Expression::ObjectExpression(object)) = &declarator.init else {
+        break;
+      };
+      let in_children = object.properties.iter().any(|property| match property {
+        ObjectPropertyKind::ObjectProperty(property) => {
+          property.key.static_name().is_some_and(|key| key == "children")
+            && property.value.span().contains_inclusive(start_span)
+        }
+        ObjectPropertyKind::SpreadProperty(_) => false,
+      });
+      let Some(symbol_id) = declarator.id.get_binding_identifier().and_then(|id| id.symbol_id.get())
+      else {
+        break;
+      };
+      if !in_children || followed_bindings.contains(&symbol_id) {
+        break;
+      }
+      followed_bindings.push(symbol_id);
+
+      // `{...rootProps}` on an element passes the children to it
+      pending.extend(semantic.symbol_references(symbol_id).filter_map(|reference| {
+        let parent_id = nodes.parent_id(reference.node_id())?;
+        matches!(nodes.kind(parent_id), AstKind::JSXSpreadAttribute(_)).then_some(parent_id)
+      }));
+      break;
+    }
+  }
+
+  elements
+}
+
+/// A rendered component bound at module scope to a conditional, logical or call expression, such
+/// as `const Description = FLAG ? RealDescription : Fallback`, so it may stand for any of its
+/// branches. An element type taken from an array by `.map`, as in
+/// `sections.map((Section) => <Section />)`, stands for any of the array's elements the same way.
+#[derive(Debug, Clone)]
+pub struct ConditionalComponent {
+  /// The element name as rendered, e.g. `Description`
+  pub element_name: String,
+  /// The references the initializer picks from, as written, e.g. `RealDescription`
+  pub branches: Vec<String>,
+  /// Whether `branches` lists everything the element may be. An array that can't be read
+  /// statically may hold any component.
+  pub exhaustive: bool,
+  /// The array the element type is mapped from, as written, e.g. `sections`
+  pub mapped_from: Option<String>,
+  /// Names the element may render as: its own, then the element through each branch, including
+  /// the name an aliased import was exported as
+  pub candidates: Vec<String>,
+  /// Span of the first rendered element's tag name
+  pub span: Span,
+}
+
+impl ConditionalComponent {
+  /// Whether the element may render `component_name`, depending on which branch is taken
+  pub fn may_render(&self, semantic: &Semantic, component_name: &str, current_file: &Path) -> bool {
+    !self.exhaustive
+      || self.candidates.iter().any(|candidate| {
+        jsx_element_matches_component(semantic, candidate, component_name, current_file)
+      })
+  }
+}
+
+/// Rendered components whose binding is chosen at runtime, see [`ConditionalComponent`]
+pub fn conditionally_bound_components(semantic: &Semantic) -> Vec<ConditionalComponent> {
+  let scoping = semantic.scoping();
+  let mut components: Vec<ConditionalComponent> = Vec::new();
+
+  for node in semantic.nodes().iter() {
+    let AstKind::JSXOpeningElement(jsx_opening) = node.kind() else {
+      continue;
+    };
+    let Some(element_name) = extract_jsx_element_name(jsx_opening) else {
+      continue;
+    };
+    if components.iter().any(|component| component.element_name == element_name) {
+      continue;
+    }
+
+    let (binding, member) = match element_name.split_once('.') {
+      Some((namespace, member)) => (namespace, Some(member)),
+      None => (element_name.as_str(), None),
+    };
+    let Some(symbol_id) = scoping.find_binding(node.scope_id(), binding) else {
+      continue;
+    };
+
+    let (branches, exhaustive, mapped_from) = match semantic.symbol_declaration(symbol_id).kind() {
+      AstKind::VariableDeclarator(declarator) if scoping.get_root_binding(binding) == Some(symbol_id) => {
+        let Some(init) = declarator.init.as_ref().map(|init| init.get_inner_expression()) else {
+          continue;
+        };
+        let is_conditional = match init {
+          Expression::ConditionalExpression(_) | Expression::LogicalExpression(_) => true,
+          // `component$(...)` defines the component rather than picking one
+          Expression::CallExpression(call_expr) => {
+            extract_function_name(call_expr).as_deref() != Some("component$")
+          }
+          _ => false,
+        };
+        if !is_conditional {
+          continue;
+        }
+        let mut branches = Vec::new();
+        collect_branch_names(init, &mut branches);
+        (branches, true, None)
+      }
+      AstKind::FormalParameter(parameter) => {
+        let declaration_id = semantic.symbol_declaration(symbol_id).id();
+        let Some(mapped) = mapped_element_type(semantic, declaration_id, parameter) else {
+          continue;
+        };
+        mapped
+      }
+      _ => continue,
+    };
+    // `import { Description as RealDescription }` also stands for the name it was exported as
+    let imported_names = branches
+      .iter()
+      .filter_map(|branch| match find_import_source_for_component(semantic, branch) {
+        Some(ImportBinding {
+          imported_name: Some(imported_name),
+          kind: ImportKind::Named,
+          ..
+        }) if imported_name != *branch => Some(imported_name),
+        _ => None,
+      });
+    let mut candidates = vec![element_name.clone()];
+    candidates.extend(
+      branches
+        .iter()
+        .cloned()
+        .chain(imported_names)
+        .map(|branch| match member {
+          Some(member) => format!("{}.{}", branch, member),
+          None => branch,
+        }),
+    );
+
+    debug(&format!(
+      "🔀 {} is bound to a conditional expression, it may be any of {:?}{}",
+      element_name,
+      candidates,
+      if exhaustive { "" } else { " or anything else" }
+    ));
+    components.push(ConditionalComponent {
+      element_name,
+      branches,
+      exhaustive,
+      mapped_from,
+      candidates,
+      span: jsx_opening.name.span(),
+    });
+  }
+
+  components
+}
+
+/// The components a `.map` callback parameter ranges over, when `parameter` is the element of
+/// `sections.map((Section) => ...)` or `[A, B].map(...)`. Returns the array's element references,
+/// whether they are all known, and the array as written.
+fn mapped_element_type(
+  semantic: &Semantic,
+  declaration_id: NodeId,
+  parameter: &oxc_ast::ast::FormalParameter,
+) -> Option<(Vec<String>, bool, Option<String>)> {
+  let nodes = semantic.nodes();
+  let mut ancestors = nodes
+    .ancestors(declaration_id)
+    .filter(|ancestor| ancestor.id() != declaration_id)
+    .skip_while(|ancestor| matches!(ancestor.kind(), AstKind::FormalParameters(_)));
+  let callback = ancestors.next()?;
+  if !matches!(
+    callback.kind(),
+    AstKind::ArrowFunctionExpression(_) | AstKind::Function(_)
+  ) {
+    return None;
+  }
+  let call_expr = ancestors
+    .find(|ancestor| !matches!(ancestor.kind(), AstKind::Argument(_)))?
+    .kind()
+    .as_call_expression()?;
+  let Expression::StaticMemberExpression(callee) = &call_expr.callee else {
+    return None;
+  };
+  let is_element_parameter = call_expr
+    .arguments
+    .first()
+    .is_some_and(|argument| argument.span() == callback.kind().span());
+  if callee.property.name != "map" || !is_element_parameter {
+    return None;
+  }
+
+  let array_text = callee.object.span().source_text(semantic.source_text()).to_string();
+  let array = match callee.object.get_inner_expression() {
+    Expression::ArrayExpression(array) => Some(array),
+    Expression::Identifier(identifier) => semantic
+      .scoping()
+      .find_binding(callback.scope_id(), &identifier.name)
+      .and_then(|symbol_id| match semantic.symbol_declaration(symbol_id).kind() {
+        AstKind::VariableDeclarator(declarator) => {
+          match declarator.init.as_ref().map(|init| init.get_inner_expression()) {
+            Some(Expression::ArrayExpression(array)) => Some(array),
+            _ => None,
+          }
+        }
+        _ => None,
+      }),
+    _ => None,
+  };
+
+  // `({ Component }) => <Component />` picks a property of each element rather than the element
+  let mut exhaustive = array.is_some() && parameter.pattern.get_binding_identifier().is_some();
+  let mut branches = Vec::new();
+  for element in array.iter().flat_map(|array| array.elements.iter()) {
+    let branch_count = branches.len();
+    if let Some(expression) = element.as_expression() {
+      collect_branch_names(expression, &mut branches);
+    }
+    exhaustive &= branches.len() > branch_count;
+  }
+
+  debug(&format!(
+    "🗺️ {} ranges over the elements of {}",
+    parameter.span.source_text(semantic.source_text()),
+    array_text
+  ));
+  Some((branches, exhaustive, Some(array_text)))
+}
+
+/// Component references an initializer may evaluate to, e.g. `A` and `B` for `FLAG ? A : B`,
+/// `Kit.Description` for `FLAG && Kit.Description`, or the arguments of `pick(A, B)`
+fn collect_branch_names(expression: &Expression, names: &mut Vec<String>) {
+  match expression.get_inner_expression() {
+    Expression::Identifier(identifier) => names.push(identifier.name.to_string()),
+    Expression::StaticMemberExpression(member) => {
+      if let Expression::Identifier(object) = &member.object {
+        names.push(format!("{}.{}", object.name, member.property.name));
+      }
+    }
+    Expression::ConditionalExpression(conditional) => {
+      collect_branch_names(&conditional.consequent, names);
+      collect_branch_names(&conditional.alternate, names);
+    }
+    // `FLAG && Description` is either the component or something that renders nothing
+    Expression::LogicalExpression(logical) if logical.operator == LogicalOperator::And => {
+      collect_branch_names(&logical.right, names);
+    }
+    Expression::LogicalExpression(logical) => {
+      collect_branch_names(&logical.left, names);
+      collect_branch_names(&logical.right, names);
+    }
+    Expression::CallExpression(call_expr) => {
+      for argument in &call_expr.arguments {
+        if let Some(argument) = argument.as_expression() {
+          collect_branch_names(argument, names);
+        }
+      }
+    }
+    _ => {}
+  }
+}
+
+/// String value of a JSX attribute such as `name="description"` or `q:slot="description"`, where
+/// `namespace` is the part before the colon
+fn jsx_string_attribute<'a>(
+  jsx_opening: &'a JSXOpeningElement<'a>,
+  namespace: Option<&str>,
+  name: &str,
+) -> Option<&'a str> {
+  jsx_opening.attributes.iter().find_map(|item| {
+    let JSXAttributeItem::Attribute(attr) = item else {
+      return None;
+    };
+    let matches_name = match (&attr.name, namespace) {
+      (JSXAttributeName::Identifier(ident), None) => ident.name == name,
+      (JSXAttributeName::NamespacedName(namespaced), Some(namespace)) => {
+        namespaced.namespace.name == namespace && namespaced.name.name == name
+      }
+      _ => false,
+    };
+    match &attr.value {
+      Some(JSXAttributeValue::StringLiteral(value)) if matches_name => Some(value.value.as_str()),
+      _ => None,
+    }
+  })
+}
+
+/// Names of the Qwik slots `source_file` renders, with `""` for the default `<Slot />`
+fn slot_names_in_file(source_file: &str) -> Result<Vec<String>> {
+  let source_text = read_dependency(source_file)?;
+  let allocator = Allocator::default();
+  let source_type = source_type_for_path(Path::new(source_file));
+
+  let oxc_parser::ParserReturn {
+    program, errors, ..
+  } = oxc_parser::Parser::new(&allocator, &source_text, source_type).parse();
+
+  if !errors.is_empty() {
+    return Err(format!("Failed to parse {}", source_file).into());
+  }
+
+  let semantic_ret = oxc_semantic::SemanticBuilder::new().build(&program);
+  let semantic = &semantic_ret.semantic;
+
+  let mut names = Vec::new();
+  if find_import_source_for_component(semantic, "Slot")
+    .is_none_or(|import_binding| import_binding.source != "@builder.io/qwik")
+  {
+    return Ok(names);
+  }
+
+  for node in semantic.nodes().iter() {
+    let AstKind::JSXOpeningElement(slot_opening) = node.kind() else {
+      continue;
+    };
+
+    if extract_jsx_element_name(slot_opening).as_deref() != Some("Slot") {
+      continue;
+    }
+
+    let name = jsx_string_attribute(slot_opening, None, "name").unwrap_or_default();
+    if !names.iter().any(|existing| existing == name) {
+      names.push(name.to_string());
+    }
+  }
+
+  Ok(names)
+}
+
+/// Whether every element rendering `component_name` inside the Root from `source_file` is marked
+/// `q:slot="..."` for a slot that Root never renders, so Qwik never projects it
+pub fn component_slotted_out(
+  semantic: &Semantic,
+  component_name: &str,
+  source_file: &str,
+  current_file: &Path,
+) -> Result<bool> {
+  let nodes = semantic.nodes();
+  let mut slot_names: Option<Vec<String>> = None;
+  let mut slotted_out = false;
+
+  for node in nodes.iter() {
+    let AstKind::JSXOpeningElement(jsx_opening) = node.kind() else {
+      continue;
+    };
+
+    let is_target = extract_jsx_element_name(jsx_opening).is_some_and(|element_name| {
+      jsx_element_matches_component(semantic, &element_name, component_name, current_file)
+    });
+    if !is_target {
+      continue;
+    }
+
+    // The q:slot closest to the Root decides where the subtree is projected
+    let mut slot_name = jsx_string_attribute(jsx_opening, Some("q"), "slot");
+    let mut inside_root = false;
+    for ancestor_id in nodes.ancestor_ids(node.id()) {
+      let AstKind::JSXElement(jsx_element) = nodes.kind(ancestor_id) else {
+        continue;
+      };
+
+      let opening = &jsx_element.opening_element;
+      if opening.span == jsx_opening.span {
+        continue;
+      }
+
+      let Some(ancestor_name) = extract_jsx_element_name(opening) else {
+        continue;
+      };
+
+      if jsx_element_resolves_to_source_file(semantic, &ancestor_name, source_file, current_file)? {
+        inside_root = true;
+        break;
+      }
+
+      if let Some(name) = jsx_string_attribute(opening, Some("q"), "slot") {
+        slot_name = Some(name);
+      }
+    }
+
+    let Some(slot_name) = slot_name.filter(|_| inside_root) else {
+      return Ok(false);
+    };
+
+    if slot_names.is_none() {
+      slot_names = Some(slot_names_in_file(source_file)?);
+    }
+    if slot_names
+      .as_ref()
+      .is_some_and(|names| names.iter().any(|name| name == slot_name))
+    {
+      return Ok(false);
+    }
+
+    debug(&format!(
+      "🕳️ {} is sent to q:slot=\"{}\", which {} doesn't render",
+      component_name, slot_name, source_file
+    ));
+    slotted_out = true;
+  }
+
+  Ok(slotted_out)
+}
+
+/// Whether an element rendered as the Root defined in `source_file` projects a `<Slot />`, so the
+/// children it checks for may be supplied by the caller of the current file's component
+pub fn root_projects_slot(
+  semantic: &Semantic,
+  source_file: &str,
+  current_file: &Path,
+) -> Result<bool> {
+  for jsx_opening in elements_projecting_slot(semantic) {
+    let Some(element_name) = extract_jsx_element_name(jsx_opening) else {
+      continue;
+    };
+
+    if jsx_element_resolves_to_source_file(semantic, &element_name, source_file, current_file)? {
+      return Ok(true);
+    }
+  }
+
+  Ok(false)
+}
+
+/// Whether the Root making `call` comes from a package that ships compiled JS without JSX and has
+/// another member of its compound component rendered inside it, e.g. `<Checkbox.Panel />`. That
+/// member can't be searched, so it may render the child. Only members the package's type
+/// declarations list count, e.g. `export declare const Checkbox: { Root: ...; Panel: ... }`.
+pub fn declared_member_may_render(
+  semantic: &Semantic,
+  call: &ComponentPresenceCall,
+  current_file: &Path,
+) -> Result<bool> {
+  let module_file = Path::new(&call.source_file);
+  if !call.source_file.contains("node_modules") || module_renders_jsx(module_file) {
+    return Ok(false);
+  }
+  let Some(types_file) = package_types_file(module_file) else {
+    return Ok(false);
+  };
+
+  let nodes = semantic.nodes();
+  for node in nodes.iter() {
+    let AstKind::JSXOpeningElement(jsx_opening) = node.kind() else {
+      continue;
+    };
+    let Some(element_name) = extract_jsx_element_name(jsx_opening) else {
+      continue;
+    };
+    let Some((local_name, member)) = element_name.split_once('.') else {
+      continue;
+    };
+    let Some(ImportBinding {
+      source: import_source,
+      imported_name: Some(imported_name),
+      ..
+    }) = find_import_source_for_component(semantic, local_name)
+    else {
+      continue;
+    };
+    let resolves_to_module = resolve_import_path(&import_source, current_file)
+      .is_ok_and(|resolved_path| same_file(&resolved_path, &call.source_file));
+    if !resolves_to_module {
+      continue;
+    }
+
+    let mut within_root = false;
+    for jsx_element in enclosing_jsx_elements(semantic, node.id()) {
+      if jsx_element.opening_element.span == jsx_opening.span {
+        continue;
+      }
+      let Some(ancestor_name) = extract_jsx_element_name(&jsx_element.opening_element) else {
+        continue;
+      };
+      if jsx_element_resolves_to_source_file(
+        semantic,
+        &ancestor_name,
+        &call.source_file,
+        current_file,
+      )? {
+        within_root = true;
+        break;
+      }
+    }
+
+    if within_root
+      && declared_members(&types_file, &imported_name)
+        .iter()
+        .any(|declared| declared == member)
+    {
+      debug(&format!(
+        "❔ {} is declared in {} but its compiled source can't be searched for {}",
+        element_name,
+        types_file.display(),
+        call.component_name
+      ));
+      return Ok(true);
+    }
+  }
+
+  Ok(false)
+}
+
+pub fn has_component(
+  semantic: &Semantic,
+  component_name: &str,
+  current_file: &Path,
+) -> Result<Presence> {
   debug(&format!(
     "🔍 Checking if {} is present in JSX subtree",
     component_name
@@ -120,7 +818,7 @@ pub fn has_component(
       "✅ Found direct usage of {} in JSX",
       component_name
     ));
-    return Ok(true);
+    return Ok(Presence::Present);
   }
 
   debug(&format!(
@@ -137,52 +835,97 @@ pub fn has_component(
     // e.g., MyTest.Child resolves to MyTestChild
     if jsx_component.contains('.') && !component_name.contains('.') {
       if let Ok(component_file) = resolve_component_from_jsx_to_file(&jsx_component, current_file) {
-        // Check if the component file defines the component we're looking for
-        if component_file_defines_component(&component_file, component_name)? {
+        // Check if the component file defines the component we're looking for. A single-file
+        // compound component defines all of its parts, so there only the rendered part counts.
+        let part = jsx_component.split('.').nth(1).unwrap_or_default();
+        let defines_component = match local_part_binding(&component_file, part)? {
+          Some(binding) => binding == component_name,
+          None => component_file_defines_component(&component_file, component_name)?,
+        };
+        if defines_component {
           debug(&format!(
             "✅ Found {} via JSX component {} which resolves to the same file",
             component_name, jsx_component
           ));
-          return Ok(true);
+          explanation::note_match(&jsx_component, || {
+            format!(
+              "<{}> resolves to {}, which defines {}",
+              jsx_component, component_file, component_name
+            )
+          });
+          return Ok(Presence::Present);
         }
       }
     }
     
+    // e.g., a directly imported Description satisfies Checkbox.Description
+    if !jsx_component.contains('.')
+      && component_name.contains('.')
+      && resolve_simple_name_to_member_expression(&jsx_component, component_name, semantic, current_file)?
+    {
+      debug(&format!(
+        "✅ Found {} via directly imported {}",
+        component_name, jsx_component
+      ));
+      explanation::note_match(&jsx_component, || {
+        format!("<{}> is imported from the same file as {}", jsx_component, component_name)
+      });
+      return Ok(Presence::Present);
+    }
+
     // For member expressions, only match if they're exactly the same
     if jsx_component.contains('.') && component_name.contains('.') {
       if jsx_component == component_name {
         // Check if this is from an external package before considering it a match
         let module_name = jsx_component.split('.').next().unwrap_or("");
-        if let Some(import_source) = find_import_source_for_component(semantic, module_name) {
-          if is_external_import(&import_source, current_file) {
-            debug(&format!("❌ Skipping external component: {} from {}", jsx_component, import_source));
+        if let Some(import_binding) = find_import_source_for_component(semantic, module_name) {
+          if is_external_import(&import_binding.source, current_file) {
+            debug(&format!(
+              "❌ Skipping external component: {} from {}",
+              jsx_component, import_binding.source
+            ));
+            explanation::note(|| {
+              format!("<{}> comes from the package {}", jsx_component, import_binding.source)
+            });
             continue;
           }
         }
         debug(&format!("✅ Found exact match: {} == {}", jsx_component, component_name));
-        return Ok(true);
+        explanation::note_match(&jsx_component, || format!("<{}> is rendered", jsx_component));
+        return Ok(Presence::Present);
       }
       continue; // Skip if both have dots but don't match exactly
     }
 
-    let Some(import_source) = find_import_source_for_component(semantic, &jsx_component) else {
+    let Some(import_source) = find_import_source_for_component(semantic, &jsx_component)
+      .map(|import_binding| import_binding.source)
+    else {
       continue;
     };
 
     // Skip external components early
     if is_external_import(&import_source, current_file) {
       debug(&format!("❌ Skipping external import: {} from {}", jsx_component, import_source));
+      explanation::note(|| {
+        format!(
+          "<{}> comes from the package {}, which isn't searched",
+          jsx_component, import_source
+        )
+      });
       continue;
     }
 
     let Ok(resolved_path) = resolve_import_path(&import_source, current_file) else {
       continue;
     };
+    let resolved_path = resolve_imported_component_file(semantic, &jsx_component, resolved_path);
 
     debug(&format!(
       "📂 Analyzing {} (from {}) for {}",
       jsx_component, resolved_path, component_name
     ));
+    explanation::note_depth(1);
+    explanation::note(|| format!("searching {} for <{}>", resolved_path, jsx_component));
 
     let presence_calls = match find_calls_in_file(&resolved_path) {
       Ok(calls) => calls,
@@ -192,24 +935,16 @@ pub fn has_component(
       }
     };
     debug(&format!("📋 Found {} presence calls in {}", presence_calls.len(), resolved_path));
-    for call in &presence_calls {
-      if call.component_name == component_name {
-        debug(&format!(
-          "✅ Found {} via imported component {}",
-          component_name, jsx_component
-        ));
-        return Ok(true);
-      }
-    }
 
     // NEW: Always check JSX content recursively using oxc semantic APIs
     debug(&format!("🔄 About to analyze JSX content in {} for {}", resolved_path, component_name));
-    if analyze_jsx_content_in_component_file(&resolved_path, component_name)? {
+    let mut visited = HashMap::new();
+    if analyze_jsx_content_in_component_file(&resolved_path, component_name, 0, &mut visited)? {
       debug(&format!(
         "✅ Found {} via JSX content in imported component {}",
         component_name, jsx_component
       ));
-      return Ok(true);
+      return Ok(Presence::Present);
     }
 
     if presence_calls.is_empty() {
@@ -218,23 +953,74 @@ pub fn has_component(
           "✅ Found {} in imported component {}",
           component_name, jsx_component
         ));
-        return Ok(true);
+        explanation::note_match(&jsx_component, || {
+          format!("{} rendered as <{}> uses {}", resolved_path, jsx_component, component_name)
+        });
+        return Ok(Presence::Present);
       }
     }
   }
 
+  // Components that couldn't be searched may still render the target
+  let unanalyzable_component = extract_imported_jsx_components(semantic)
+    .into_iter()
+    .find(|jsx_component| is_unanalyzable_component(semantic, jsx_component, current_file));
+
+  if let Some(jsx_component) = unanalyzable_component {
+    debug(&format!(
+      "❔ {} not found, but {} couldn't be analyzed",
+      component_name, jsx_component
+    ));
+    explanation::note(|| {
+      format!("<{}> couldn't be analyzed, so it may render {}", jsx_component, component_name)
+    });
+    return Ok(Presence::Unknown);
+  }
+
   debug(&format!(
     "❌ Component {} not found in JSX subtree",
     component_name
   ));
-  Ok(false)
+  explanation::note(|| {
+    format!("no element rendered in {} resolves to {}", current_file.display(), component_name)
+  });
+  Ok(Presence::Absent)
+}
+
+/// Whether `jsx_component` comes from a project-local import that doesn't resolve, or resolves to
+/// a file that doesn't parse, so its definition can't be searched. A bare specifier mapped into the
+/// project by tsconfig `paths`, e.g. `ui/checkbox`, is local once it resolves outside node_modules.
+fn is_unanalyzable_component(
+  semantic: &Semantic,
+  jsx_component: &str,
+  current_file: &Path,
+) -> bool {
+  let local_name = jsx_component.split('.').next().unwrap_or(jsx_component);
+  let Some(import_source) =
+    find_import_source_for_component(semantic, local_name).map(|import_binding| import_binding.source)
+  else {
+    return false;
+  };
+
+  let resolved_path = match resolve_import_path(&import_source, current_file) {
+    Ok(resolved_path) if !resolved_path.contains("node_modules") => resolved_path,
+    Ok(_) => return false,
+    Err(_) => return is_local_specifier(&import_source),
+  };
+
+  let Ok(source_text) = std::fs::read_to_string(&resolved_path) else {
+    return false;
+  };
+
+  let allocator = Allocator::default();
+  let source_type = source_type_for_path(Path::new(&resolved_path));
+  let parser_ret = oxc_parser::Parser::new(&allocator, &source_text, source_type).parse();
+  !parser_ret.errors.is_empty()
 }
 
 fn find_calls_in_module(module_path: &str) -> Result<Vec<ComponentPresenceCall>> {
-  use std::fs;
-  use oxc_span::VALID_EXTENSIONS;
-  
   let mut all_calls = Vec::new();
+  let extensions = source_extensions();
   
   let module_dir = if module_path.ends_with(".ts") || module_path.ends_with(".tsx") || 
                      module_path.ends_with(".js") || module_path.ends_with(".jsx") {
@@ -243,26 +1029,70 @@ fn find_calls_in_module(module_path: &str) -> Result<Vec<ComponentPresenceCall>>
     Path::new(module_path)
   };
   
-  debug(&format!("🔍 Scanning directory: {}", module_dir.display()));
+  scan_directory_for_calls(module_dir, &extensions, 0, &mut all_calls);
+  for call in &mut all_calls {
+    call.resolved_by_module_scan = true;
+  }
   
-  if let Ok(entries) = fs::read_dir(module_dir) {
-    for entry in entries.flatten() {
-      let path = entry.path();
-      if path.is_file() {
-        if let Some(extension) = path.extension() {
-          if VALID_EXTENSIONS.iter().any(|&ext| ext == extension.to_str().unwrap_or("")) {
-            let file_path = path.to_string_lossy().to_string();
-            debug(&format!("📄 Checking file: {}", file_path));
-            if let Ok(calls) = find_calls_in_file(&file_path) {
-              all_calls.extend(calls);
-            }
-          }
-        }
+  Ok(all_calls)
+}
+
+/// Collects presence calls from every source file under `dir`, descending into subdirectories
+/// other than `node_modules` and hidden ones up to `MAX_MODULE_SCAN_DEPTH` levels deep
+fn scan_directory_for_calls(
+  dir: &Path,
+  extensions: &[String],
+  depth: usize,
+  all_calls: &mut Vec<ComponentPresenceCall>,
+) {
+  debug(&format!("🔍 Scanning directory: {}", dir.display()));
+
+  let Ok(entries) = std::fs::read_dir(dir) else {
+    return;
+  };
+
+  let mut paths: Vec<_> = entries.flatten().map(|entry| entry.path()).collect();
+  paths.sort();
+
+  for path in paths {
+    // Symlinks are never followed, so the scan stays inside the module directory, and anything
+    // outside the sandbox root is left unread
+    let Ok(metadata) = std::fs::symlink_metadata(&path) else {
+      continue;
+    };
+    if metadata.file_type().is_symlink() && path.is_dir() {
+      debug(&format!("⏭️ Not following symlinked directory {}", path.display()));
+      continue;
+    }
+    if !within_sandbox(&path) {
+      debug(&format!("🚫 {} is outside the sandbox root, not scanning it", path.display()));
+      continue;
+    }
+
+    if metadata.is_dir() {
+      let is_skipped = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name == "node_modules" || name.starts_with('.'));
+
+      if !is_skipped && depth + 1 < MAX_MODULE_SCAN_DEPTH {
+        scan_directory_for_calls(&path, extensions, depth + 1, all_calls);
+      }
+      continue;
+    }
+
+    let Some(extension) = path.extension().and_then(|extension| extension.to_str()) else {
+      continue;
+    };
+
+    if extensions.iter().any(|ext| ext == extension) {
+      let file_path = path.to_string_lossy().to_string();
+      debug(&format!("📄 Checking file: {}", file_path));
+      if let Ok(calls) = find_calls_in_file(&file_path) {
+        all_calls.extend(calls);
       }
     }
   }
-  
-  Ok(all_calls)
 }
 
 fn resolve_component_from_jsx_to_file(jsx_component: &str, current_file: &Path) -> Result<String> {
@@ -284,7 +1114,7 @@ fn resolve_component_from_jsx_to_file(jsx_component: &str, current_file: &Path)
   // Find the import source for the module
   let allocator = Allocator::default();
   let source_text = std::fs::read_to_string(current_file)?;
-  let source_type = SourceType::from_path(current_file).unwrap_or_default();
+  let source_type = source_type_for_path(current_file);
   
   let oxc_parser::ParserReturn { program, errors, .. } = 
     oxc_parser::Parser::new(&allocator, &source_text, source_type).parse();
@@ -296,37 +1126,23 @@ fn resolve_component_from_jsx_to_file(jsx_component: &str, current_file: &Path)
   let semantic_ret = oxc_semantic::SemanticBuilder::new().build(&program);
   let semantic = &semantic_ret.semantic;
   
-  let Some(import_source) = find_import_source_for_component(semantic, module_name) else {
+  let Some(import_binding) = find_import_source_for_component(semantic, module_name) else {
     return Err(format!("Could not find import for module {}", module_name).into());
   };
   
-  let module_path = resolve_import_path(&import_source, current_file)?;
+  let module_path = resolve_import_path(&import_binding.source, current_file)?;
   
   // Try to resolve the component through the index file
-  let module_dir = std::path::Path::new(&module_path);
-  let index_file = if module_dir.is_file() {
-    module_path.clone()
-  } else {
-    let index_ts = module_dir.join("index.ts");
-    let index_tsx = module_dir.join("index.tsx");
-    if index_ts.exists() {
-      index_ts.to_string_lossy().to_string()
-    } else if index_tsx.exists() {
-      index_tsx.to_string_lossy().to_string()
-    } else {
-      return Err("Could not find index file".into());
-    }
-  };
-  
-  resolve_component_from_index(&index_file, component_name)
+  let index_file = module_index_file(&module_path);
+  resolve_namespace_member(&import_binding, &index_file, component_name)
 }
 
 fn component_file_defines_component(component_file: &str, component_name: &str) -> Result<bool> {
   debug(&format!("🔍 Checking if {} defines component {}", component_file, component_name));
   
-  let source_text = std::fs::read_to_string(component_file)?;
+  let source_text = read_dependency(component_file)?;
   let allocator = Allocator::default();
-  let source_type = SourceType::from_path(std::path::Path::new(component_file)).unwrap_or_default();
+  let source_type = source_type_for_path(std::path::Path::new(component_file));
   
   let oxc_parser::ParserReturn { program, errors, .. } = 
     oxc_parser::Parser::new(&allocator, &source_text, source_type).parse();
@@ -345,7 +1161,7 @@ fn component_file_defines_component(component_file: &str, component_name: &str)
         // Handle export { MyTestChild }
         for specifier in &export_decl.specifiers {
           let exported_name = &specifier.exported.name();
-          if exported_name == component_name {
+          if exported_name == component_name && !is_type_only_export(export_decl, specifier) {
             debug(&format!("✅ Found export specifier for {}", component_name));
             return Ok(true);
           }
@@ -368,21 +1184,48 @@ fn component_file_defines_component(component_file: &str, component_name: &str)
   Ok(false)
 }
 
+/// Whether the Root defined in `source_file` renders `component_name` in its own JSX, directly or
+/// through the local components it composes, e.g. Root renders Panel renders Description
+pub fn root_renders_component(source_file: &str, component_name: &str) -> Result<bool> {
+  debug(&format!(
+    "🔍 Checking whether {} renders {} itself",
+    source_file, component_name
+  ));
+  explanation::note(|| format!("searching the Root in {} for {}", source_file, component_name));
+  analyze_jsx_content_in_component_file(source_file, component_name, 0, &mut HashMap::new())
+}
+
 /// Analyzes JSX content in a component file to find if it contains the target component
-/// Uses oxc semantic analysis to properly resolve JSX member expressions
+/// Uses oxc semantic analysis to properly resolve JSX member expressions, and descends into
+/// locally imported child components up to the configured `max_component_depth` levels deep.
+/// `visited` maps each searched file to the shallowest depth it was searched at. A file is only
+/// searched again when reached at a shallower depth, where more of its children fit under the
+/// limit, so cycles end and the result doesn't depend on which path reached a file first.
 fn analyze_jsx_content_in_component_file(
   component_file: &str,
   target_component: &str,
+  depth: usize,
+  visited: &mut HashMap<String, usize>,
 ) -> Result<bool> {
+  if visited.get(component_file).is_some_and(|&searched| searched <= depth) {
+    debug(&format!(
+      "⏭️ {} was already searched for {}",
+      component_file, target_component
+    ));
+    return Ok(false);
+  }
+  visited.insert(component_file.to_string(), depth);
+
   debug(&format!(
-    "🔍 Analyzing JSX content in {} for target component {}",
-    component_file, target_component
+    "🔍 Analyzing JSX content in {} for target component {} (depth {})",
+    component_file, target_component, depth
   ));
+  explanation::note_depth(depth + 1);
 
   // Parse the component file using oxc
-  let source_text = std::fs::read_to_string(component_file)?;
+  let source_text = read_dependency(component_file)?;
   let allocator = Allocator::default();
-  let source_type = SourceType::from_path(Path::new(component_file)).unwrap_or_default();
+  let source_type = source_type_for_path(Path::new(component_file));
 
   let oxc_parser::ParserReturn { program, errors, .. } = 
     oxc_parser::Parser::new(&allocator, &source_text, source_type).parse();
@@ -394,56 +1237,184 @@ fn analyze_jsx_content_in_component_file(
 
   let semantic_ret = oxc_semantic::SemanticBuilder::new().build(&program);
   let semantic = &semantic_ret.semantic;
+  let file_path = Path::new(component_file);
+
+  let mut child_components = Vec::new();
+  let guards = presence_guards(semantic, target_component, file_path);
 
   // Analyze JSX elements in this file
   for node in semantic.nodes().iter() {
     if let AstKind::JSXOpeningElement(jsx_opening) = node.kind() {
-      if let Some(jsx_element_name) = extract_jsx_element_name_enhanced(jsx_opening) {
+      if let Some(jsx_element_name) = extract_jsx_element_name(jsx_opening) {
         debug(&format!("🔍 Found JSX element: {} in {}", jsx_element_name, component_file));
-        
+
+        // A fallback such as `{!hasDescription && <Description />}` only renders when the
+        // consumer's slotted children decide the check, so it can't settle it
+        if rendered_behind_guard(semantic, node.id(), &guards) {
+          debug(&format!(
+            "⏭️ {} in {} depends on the check for {}, not counting it",
+            jsx_element_name, component_file, target_component
+          ));
+          explanation::note(|| {
+            format!(
+              "<{}> in {} only renders when {} is absent, so it doesn't count",
+              jsx_element_name, component_file, target_component
+            )
+          });
+          continue;
+        }
+
         // Check if this JSX element resolves to our target component
-        if jsx_element_resolves_to_target(&jsx_element_name, target_component, semantic, Path::new(component_file))? {
+        if jsx_element_resolves_to_target(&jsx_element_name, target_component, semantic, file_path)? {
           debug(&format!(
             "✅ JSX element {} resolves to target component {}",
             jsx_element_name, target_component
           ));
+          explanation::note_match(&jsx_element_name, || {
+            format!("<{}> in {} renders {}", jsx_element_name, component_file, target_component)
+          });
           return Ok(true);
         }
+
+        if !child_components.contains(&jsx_element_name) {
+          child_components.push(jsx_element_name);
+        }
       }
     }
   }
 
+  let max_component_depth = current_config().max_component_depth;
+  if depth + 1 >= max_component_depth {
+    debug(&format!(
+      "⚠️ Reached max component depth {} in {}, not descending further",
+      max_component_depth, component_file
+    ));
+    explanation::note(|| {
+      format!(
+        "stopped at {}, the maximum component depth of {}",
+        component_file, max_component_depth
+      )
+    });
+    return Ok(false);
+  }
+
+  // Descend into child component definitions, e.g. Root renders Panel renders Description
+  for child_component in &child_components {
+    let Some(child_file) = resolve_child_component_file(semantic, child_component, file_path) else {
+      continue;
+    };
+
+    if visited.get(&child_file).is_some_and(|&searched| searched <= depth + 1) {
+      continue;
+    }
+
+    debug(&format!(
+      "🔄 Descending into {} ({}) looking for {}",
+      child_component, child_file, target_component
+    ));
+    explanation::note(|| format!("searching {} for <{}>", child_file, child_component));
+
+    if analyze_jsx_content_in_component_file(&child_file, target_component, depth + 1, visited)? {
+      debug(&format!(
+        "✅ Found {} nested inside child component {}",
+        target_component, child_component
+      ));
+      return Ok(true);
+    }
+  }
+
   debug(&format!("❌ No JSX content in {} resolves to {}", component_file, target_component));
   Ok(false)
 }
 
-/// Enhanced JSX element name extraction with better member expression handling
-fn extract_jsx_element_name_enhanced(jsx_opening: &oxc_ast::ast::JSXOpeningElement) -> Option<String> {
-  use oxc_ast::ast::JSXElementName;
-  
-  match &jsx_opening.name {
-    JSXElementName::Identifier(ident) => Some(ident.name.to_string()),
-    JSXElementName::IdentifierReference(ident) => Some(ident.name.to_string()),
-    JSXElementName::MemberExpression(member_expr) => {
-      let object_name = extract_jsx_member_object_name_enhanced(&member_expr.object)?;
-      let property_name = &member_expr.property.name;
-      Some(format!("{}.{}", object_name, property_name))
+/// Spans of the expressions holding the result of an `isComponentPresent` check for
+/// `target_component`: the calls themselves and every read of a variable they initialize
+fn presence_guards(semantic: &Semantic, target_component: &str, file_path: &Path) -> Vec<Span> {
+  let mut guards = Vec::new();
+
+  for node in semantic.nodes().iter() {
+    let AstKind::CallExpression(call_expr) = node.kind() else {
+      continue;
+    };
+    if !is_presence_call(semantic, call_expr, file_path) {
+      continue;
     }
-    _ => None,
+    let checks_target = call_expr
+      .arguments
+      .first()
+      .and_then(|argument| presence_target_name(semantic, argument, file_path))
+      .is_some_and(|component_name| {
+        component_alternatives(&component_name).any(|alternative| alternative == target_component)
+      });
+    if !checks_target {
+      continue;
+    }
+
+    guards.push(call_expr.span);
+    // `const hasDescription = isComponentPresent(Description)`
+    let Some(AstKind::VariableDeclarator(declarator)) = semantic.nodes().parent_kind(node.id()) else {
+      continue;
+    };
+    let Some(symbol_id) = declarator.id.get_binding_identifier().and_then(|id| id.symbol_id.get())
+    else {
+      continue;
+    };
+    guards.extend(
+      semantic
+        .symbol_references(symbol_id)
+        .map(|reference| semantic.nodes().kind(reference.node_id()).span()),
+    );
   }
+
+  guards
 }
 
-fn extract_jsx_member_object_name_enhanced(
-  object: &oxc_ast::ast::JSXMemberExpressionObject,
+/// Whether the JSX at `node_id` is only rendered depending on one of the `guards`, as a branch of
+/// a conditional, `&&`/`||` or `if` whose condition reads it
+fn rendered_behind_guard(semantic: &Semantic, node_id: NodeId, guards: &[Span]) -> bool {
+  if guards.is_empty() {
+    return false;
+  }
+
+  let reads_guard =
+    |condition: Span| guards.iter().any(|guard| condition.contains_inclusive(*guard));
+  semantic
+    .nodes()
+    .ancestor_kinds(node_id)
+    .any(|ancestor| match ancestor {
+      AstKind::ConditionalExpression(conditional) => reads_guard(conditional.test.span()),
+      AstKind::LogicalExpression(logical) => reads_guard(logical.left.span()),
+      AstKind::IfStatement(if_statement) => reads_guard(if_statement.test.span()),
+      _ => false,
+    })
+}
+
+/// Resolve a JSX element used inside a component file to the local file that defines it
+fn resolve_child_component_file(
+  semantic: &Semantic,
+  jsx_element_name: &str,
+  current_file: &Path,
 ) -> Option<String> {
-  use oxc_ast::ast::JSXMemberExpressionObject;
-  
-  match object {
-    JSXMemberExpressionObject::IdentifierReference(ident) => Some(ident.name.to_string()),
-    JSXMemberExpressionObject::MemberExpression(member_expr) => {
-      let object_name = extract_jsx_member_object_name_enhanced(&member_expr.object)?;
-      let property_name = &member_expr.property.name;
-      Some(format!("{}.{}", object_name, property_name))
+  let import_name = jsx_element_name.split('.').next()?;
+  if !import_name.starts_with(|c: char| c.is_ascii_uppercase()) {
+    return None;
+  }
+
+  let import_binding = find_import_source_for_component(semantic, import_name)?;
+  if is_external_import(&import_binding.source, current_file) {
+    return None;
+  }
+
+  let resolved_path = resolve_import_path(&import_binding.source, current_file).ok()?;
+
+  let parts: Vec<&str> = jsx_element_name.split('.').collect();
+  match parts.len() {
+    // `import { Tabs, Panel } from "./tabs"` resolves Panel to the barrel, so follow the exact
+    // specifier to the file that defines it, the same file `Tabs.Panel` resolves to
+    1 => Some(resolve_imported_component_file(semantic, import_name, resolved_path)),
+    2 => {
+      let index_file = module_index_file(&resolved_path);
+      resolve_namespace_member(&import_binding, &index_file, parts[1]).ok()
     }
     _ => None,
   }
@@ -474,7 +1445,12 @@ fn jsx_element_resolves_to_target(
 
   // Case 3: Reverse resolution (e.g., "MyTestChild" used as "MyTest.Child")
   if !jsx_element_name.contains('.') && target_component.contains('.') {
-    return resolve_simple_name_to_member_expression(jsx_element_name, target_component);
+    return resolve_simple_name_to_member_expression(
+      jsx_element_name,
+      target_component,
+      semantic,
+      current_file,
+    );
   }
 
   Ok(false)
@@ -500,40 +1476,26 @@ fn resolve_member_expression_to_component(
     namespace, component_name, target_component
   ));
 
-  let Some(import_source) = find_import_source_for_component(semantic, namespace) else {
+  let Some(import_binding) = find_import_source_for_component(semantic, namespace) else {
     debug(&format!("❌ No import source found for namespace {}", namespace));
     return Ok(false);
   };
 
-  if is_external_import(&import_source, current_file) {
-    debug(&format!("❌ Skipping external import: {} from {}", namespace, import_source));
+  if is_external_import(&import_binding.source, current_file) {
+    debug(&format!("❌ Skipping external import: {} from {}", namespace, import_binding.source));
     return Ok(false);
   }
 
-  let Ok(module_path) = resolve_import_path(&import_source, current_file) else {
-    debug(&format!("❌ Failed to resolve import path for {}", import_source));
+  let Ok(module_path) = resolve_import_path(&import_binding.source, current_file) else {
+    debug(&format!("❌ Failed to resolve import path for {}", import_binding.source));
     return Ok(false);
   };
 
   debug(&format!("📂 Resolved {} to module path: {}", namespace, module_path));
 
-  let module_dir = Path::new(&module_path);
-  let index_file = if module_dir.is_file() {
-    module_path.clone()
-  } else {
-    let index_ts = module_dir.join("index.ts");
-    let index_tsx = module_dir.join("index.tsx");
-    if index_ts.exists() {
-      index_ts.to_string_lossy().to_string()
-    } else if index_tsx.exists() {
-      index_tsx.to_string_lossy().to_string()
-    } else {
-      debug(&format!("❌ No index file found in {}", module_dir.display()));
-      return Ok(false);
-    }
-  };
+  let index_file = module_index_file(&module_path);
 
-  if let Ok(component_file) = resolve_component_from_index(&index_file, component_name) {
+  if let Ok(component_file) = resolve_namespace_member(&import_binding, &index_file, component_name) {
     debug(&format!("📂 Resolved {}.{} to component file: {}", namespace, component_name, component_file));
     
     return component_file_defines_component(&component_file, target_component);
@@ -543,13 +1505,35 @@ fn resolve_member_expression_to_component(
   Ok(false)
 }
 
+/// Resolve a directly imported component like "Description" against a member expression target
+/// like "Checkbox.Description" by comparing the files both resolve to
 fn resolve_simple_name_to_member_expression(
   jsx_element_name: &str,
   target_component: &str,
+  semantic: &Semantic,
+  current_file: &Path,
 ) -> Result<bool> {
   debug(&format!(
-    "🔍 Reverse resolution not yet implemented: {} -> {}",
+    "🔍 Reverse resolving {} against {}",
     jsx_element_name, target_component
   ));
-  Ok(false)
+
+  let Some(element_file) = resolve_child_component_file(semantic, jsx_element_name, current_file)
+  else {
+    debug(&format!("❌ Could not resolve {} to a local file", jsx_element_name));
+    return Ok(false);
+  };
+
+  let Some(target_file) = resolve_child_component_file(semantic, target_component, current_file)
+  else {
+    debug(&format!("❌ Could not resolve {} to a local file", target_component));
+    return Ok(false);
+  };
+
+  let matches = same_file(&element_file, &target_file);
+  debug(&format!(
+    "🔍 Reverse resolution: {} ({}) vs {} ({}) -> {}",
+    jsx_element_name, element_file, target_component, target_file, matches
+  ));
+  Ok(matches)
 }
@@ -0,0 +1,316 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{BindingPatternKind, Expression, Statement};
+use oxc_parser::{Parser, ParserReturn};
+use oxc_span::SourceType;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock, RwLock};
+
+use crate::component_analyzer::utils::is_identifier;
+
+/// Sources larger than this are skipped unless configured otherwise, 2 MiB
+pub const DEFAULT_MAX_SOURCE_BYTES: usize = 2 * 1024 * 1024;
+
+/// Levels of nested components searched for a child unless configured otherwise
+pub const DEFAULT_MAX_COMPONENT_DEPTH: usize = 8;
+
+/// The package the presence functions are imported from, always accepted as their source
+pub const RUNTIME_MODULE: &str = "@jackshelton/qwik-analyzer";
+
+/// Rewrite applied to `isComponentPresent` calls unless configured otherwise
+pub const DEFAULT_PRESENCE_CALL_TEMPLATE: &str = "isComponentPresent({arg}, {props}.{prop})";
+
+/// What to do when a Root element already carries a presence prop written by the user
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExistingPropStrategy {
+  /// Keep the user's attribute and don't inject one
+  #[default]
+  Respect,
+  /// Replace the user's attribute value with the analyzed one
+  Override,
+}
+
+/// Where injected presence props go among a Root's attributes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InjectionPosition {
+  /// Right after the element name, before the user's attributes
+  First,
+  /// After the user's attributes
+  #[default]
+  Last,
+}
+
+/// Which side of a compound component a pass transforms, for splitting the rewrite between a
+/// library build and an app build
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransformSide {
+  /// Only Root definitions: presence calls and the props parameter they read
+  Definition,
+  /// Only consumers: presence props injected into rendered Roots
+  Consumer,
+  #[default]
+  Both,
+}
+
+impl TransformSide {
+  pub fn transforms_definitions(self) -> bool {
+    self != TransformSide::Consumer
+  }
+
+  pub fn transforms_consumers(self) -> bool {
+    self != TransformSide::Definition
+  }
+}
+
+/// Type annotation of a props parameter added to a component in a TypeScript file
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum PropsType {
+  /// No annotation, leaving the type to `component$`'s inference
+  #[default]
+  None,
+  /// `Record<string, unknown>`
+  Record,
+  /// The component's own props type: the type argument of `component$<RootProps>`, else a type
+  /// named after the component such as `RootProps`, falling back to `Record<string, unknown>`
+  Declared,
+  /// The given type, e.g. `PropsOf<typeof Root>`
+  Custom(String),
+}
+
+/// The parameter added to a `component$` arrow that has none, e.g. `props: PropsOf<typeof Root>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropsParameter {
+  /// The parameter as inserted
+  pub text: String,
+  /// The name it binds, e.g. `props`, read by rewritten `isComponentPresent` calls
+  pub binding: String,
+  /// Whether it carries a type annotation of its own
+  pub annotated: bool,
+}
+
+impl Default for PropsParameter {
+  fn default() -> Self {
+    Self {
+      text: "props".to_string(),
+      binding: "props".to_string(),
+      annotated: false,
+    }
+  }
+}
+
+impl PropsParameter {
+  /// Parses `text` as the only parameter of an arrow function. It must bind a plain name, with or
+  /// without a type annotation: a destructuring pattern, default value or anything spilling out
+  /// of the parameter list would break the code it's inserted into.
+  pub fn parse(text: &str) -> std::result::Result<Self, String> {
+    let text = text.trim();
+    let source = format!("({}) => {{}}", text);
+    let allocator = Allocator::default();
+    let ParserReturn {
+      program, errors, ..
+    } = Parser::new(&allocator, &source, SourceType::ts()).parse();
+    if !errors.is_empty() {
+      return Err(format!("isn't a valid parameter: {}", errors[0]));
+    }
+
+    let [Statement::ExpressionStatement(statement)] = program.body.as_slice() else {
+      return Err("must be a single parameter".to_string());
+    };
+    let Expression::ArrowFunctionExpression(arrow) = &statement.expression else {
+      return Err("must be a single parameter".to_string());
+    };
+    let ([parameter], None) = (arrow.params.items.as_slice(), &arrow.params.rest) else {
+      return Err("must be a single parameter".to_string());
+    };
+    let BindingPatternKind::BindingIdentifier(identifier) = &parameter.pattern.kind else {
+      return Err("must bind a name, e.g. `props` or `props: Props`".to_string());
+    };
+
+    Ok(Self {
+      text: text.to_string(),
+      binding: identifier.name.to_string(),
+      annotated: parameter.pattern.type_annotation.is_some(),
+    })
+  }
+}
+
+/// How the props injected into a Root for its presence checks are named
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum PropNaming {
+  /// `__qwik_analyzer_has_Checkbox_Description`
+  #[default]
+  Readable,
+  /// A short token derived from the Root's file and the checked component, e.g. `__qa_1f3a9c0b2e`
+  Hashed,
+  /// A template with `{name}` (the readable suffix, e.g. `Checkbox_Description`) and `{hash}`
+  /// placeholders. The result must be a valid identifier.
+  Custom(String),
+  /// A `data-*` attribute for runtimes that read those instead of props, e.g.
+  /// `data-qwik-analyzer-has-checkbox-description`. The Root reads it as `props["data-..."]`.
+  DataAttribute,
+}
+
+/// Settings that shape how the analyzer transforms source files
+#[derive(Debug, Clone)]
+pub struct AnalyzerConfig {
+  /// Parameter inserted into a `component$` arrow that has none, e.g. `props` or
+  /// `props: PropsOf<typeof Root>`
+  pub props_parameter: PropsParameter,
+  /// Annotation given to `props_parameter` in TypeScript files when it has none of its own
+  pub props_type: PropsType,
+  /// Extra file extensions (without the leading dot) and how to parse them, e.g. `qwik` -> TSX
+  pub source_extensions: HashMap<String, SourceType>,
+  /// How to treat presence props that already exist on a Root element
+  pub existing_props: ExistingPropStrategy,
+  /// Where injected presence props are placed among a Root's attributes
+  pub injection_position: InjectionPosition,
+  /// Sources larger than this many bytes are skipped with a diagnostic instead of analyzed;
+  /// `None` analyzes files of any size
+  pub max_source_bytes: Option<usize>,
+  /// Project root used as the base of `~/` imports and for finding `qwik-analyzer.json`, instead of
+  /// the nearest directory with a package.json. Useful in monorepos with nested packages.
+  pub project_root: Option<PathBuf>,
+  /// Skip injecting props into a Root when none of its presence checks are true, since a missing
+  /// prop already reads as absent at runtime
+  pub inject_only_when_present: bool,
+  /// Replacement for each `isComponentPresent` call inside a `component$`. `{arg}` is the original
+  /// argument, `{prop}` the injected prop name and `{props}` the component's props binding, the
+  /// one from `props_parameter` when it is added.
+  pub presence_call_template: String,
+  /// Leave presence checks unresolved, with a diagnostic, when they'd only be settled by a
+  /// heuristic: a child rendered outside its Root, or calls found by scanning a module directory
+  pub strict_scoping: bool,
+  /// Route children marked `q:slot="name"` through the Root's `<Slot name="name" />`, counting
+  /// them absent when the Root renders no such slot
+  pub named_slots: bool,
+  /// Naming of injected presence props, shared by the Root's rewritten calls and its consumers
+  pub prop_naming: PropNaming,
+  /// When set, imports resolving outside this directory are refused with a diagnostic and never
+  /// read, for analyzing untrusted code
+  pub sandbox_root: Option<PathBuf>,
+  /// Children counted as present whatever the JSX says, e.g. `Checkbox.Description` when it's
+  /// provided at runtime through a context. Matched against the name the Root checks for.
+  pub assume_present: Vec<String>,
+  /// When set, only JSX elements rendering these components or namespaces are analyzed, e.g.
+  /// `Checkbox` for every `Checkbox.*` part. Children rendered through other components go unseen.
+  pub only_components: Option<Vec<String>>,
+  /// Follow each inserted or rewritten piece of code with a `/* qwik-analyzer: ... */` comment
+  /// explaining it, to make transformed output readable while debugging
+  pub annotate_transformations: bool,
+  /// Whether Root definitions, their consumers or both are transformed
+  pub transform_side: TransformSide,
+  /// How many levels of components are followed when looking for a child rendered inside other
+  /// components, e.g. Root renders Panel renders Description, and through wrapped Roots
+  pub max_component_depth: usize,
+  /// Parse the definition of each Root declared in `qwik-analyzer.json` and report injected props
+  /// it never reads. Off by default, since skipping that parse is what the manifest is for.
+  pub verify_manifest: bool,
+  /// Packages besides [`RUNTIME_MODULE`] the presence functions may be imported from, e.g. a design
+  /// system re-exporting `isComponentPresent`. Subpaths of each are accepted too, and so are files
+  /// re-exporting the functions from any of them.
+  pub runtime_modules: Vec<String>,
+}
+
+impl Default for AnalyzerConfig {
+  fn default() -> Self {
+    Self {
+      props_parameter: PropsParameter::default(),
+      props_type: PropsType::default(),
+      source_extensions: HashMap::new(),
+      existing_props: ExistingPropStrategy::default(),
+      injection_position: InjectionPosition::default(),
+      max_source_bytes: Some(DEFAULT_MAX_SOURCE_BYTES),
+      project_root: None,
+      inject_only_when_present: false,
+      presence_call_template: DEFAULT_PRESENCE_CALL_TEMPLATE.to_string(),
+      strict_scoping: false,
+      named_slots: false,
+      prop_naming: PropNaming::default(),
+      sandbox_root: None,
+      assume_present: Vec::new(),
+      only_components: None,
+      annotate_transformations: false,
+      transform_side: TransformSide::default(),
+      max_component_depth: DEFAULT_MAX_COMPONENT_DEPTH,
+      verify_manifest: false,
+      runtime_modules: Vec::new(),
+    }
+  }
+}
+
+impl AnalyzerConfig {
+  /// Whether `module` names [`RUNTIME_MODULE`], a configured runtime module or a subpath of one,
+  /// either as an import specifier or as a package.json `name`
+  pub fn is_runtime_module(&self, module: &str) -> bool {
+    std::iter::once(RUNTIME_MODULE)
+      .chain(self.runtime_modules.iter().map(String::as_str))
+      .any(|runtime_module| {
+        module
+          .strip_prefix(runtime_module)
+          .is_some_and(|subpath| subpath.is_empty() || subpath.starts_with('/'))
+      })
+  }
+
+  /// Whether JSX elements rendering `component`, e.g. `Checkbox.Root`, are analyzed under
+  /// `only_components`
+  pub fn analyzes_component(&self, component: &str) -> bool {
+    let Some(only_components) = &self.only_components else {
+      return true;
+    };
+    let namespace = component.split('.').next().unwrap_or(component);
+    only_components
+      .iter()
+      .any(|listed| listed == component || listed == namespace)
+  }
+
+  /// Name bound by `props_parameter`, used when rewriting `isComponentPresent` calls
+  pub fn props_binding(&self) -> &str {
+    &self.props_parameter.binding
+  }
+
+  /// Fills `presence_call_template` for a call checking `arg`, which receives `prop_name` on the
+  /// `props` binding
+  pub fn presence_call(&self, props: &str, arg: &str, prop_name: &str) -> String {
+    let template = if is_identifier(prop_name) {
+      self.presence_call_template.clone()
+    } else {
+      self
+        .presence_call_template
+        .replace("{props}.{prop}", "{props}[\"{prop}\"]")
+    };
+    template
+      .replace("{props}", props)
+      .replace("{prop}", prop_name)
+      .replace("{arg}", arg)
+  }
+
+  /// `code` followed by a comment explaining it when `annotate_transformations` is on
+  pub fn annotate(&self, code: String, explanation: &str) -> String {
+    if self.annotate_transformations {
+      format!("{} /* qwik-analyzer: {} */", code, explanation)
+    } else {
+      code
+    }
+  }
+}
+
+fn config_lock() -> &'static RwLock<Arc<AnalyzerConfig>> {
+  static CONFIG: OnceLock<RwLock<Arc<AnalyzerConfig>>> = OnceLock::new();
+  CONFIG.get_or_init(|| RwLock::new(Arc::new(AnalyzerConfig::default())))
+}
+
+/// Returns a snapshot of the active analyzer configuration, shared rather than copied since it's
+/// read on hot paths such as every JSX element and resolved file
+pub fn current_config() -> Arc<AnalyzerConfig> {
+  config_lock()
+    .read()
+    .map(|config| Arc::clone(&config))
+    .unwrap_or_default()
+}
+
+/// Replaces the active analyzer configuration
+pub fn set_config(config: AnalyzerConfig) {
+  if let Ok(mut current) = config_lock().write() {
+    *current = Arc::new(config);
+  }
+}
@@ -0,0 +1,167 @@
+use serde_json::json;
+use std::path::{Path, PathBuf};
+
+use crate::component_analyzer::analyze_file_with_semantics;
+use crate::component_analyzer::utils::{debug, source_extensions};
+use crate::{ChildCoverage, ComponentCoverage, CoverageReport, Result};
+
+/// Analyzes every source file in `paths`, descending into directories other than `node_modules`
+/// and hidden ones, and tallies how often each Root's checks came out present, absent or unknown.
+/// Each analyzed file rendering a Root counts as one usage.
+pub fn presence_coverage(paths: &[PathBuf]) -> Result<CoverageReport> {
+  let extensions = source_extensions();
+  let mut files = Vec::new();
+  for path in paths {
+    if path.is_dir() {
+      collect_source_files(path, &extensions, &mut files);
+    } else {
+      files.push(path.clone());
+    }
+  }
+
+  let mut report = CoverageReport {
+    files_analyzed: 0,
+    components: Vec::new(),
+  };
+
+  for file in &files {
+    let result = analyze_file_with_semantics(file)?;
+    report.files_analyzed += 1;
+
+    let mut counted_roots: Vec<&str> = Vec::new();
+    for check in &result.presence_checks {
+      let index = match report
+        .components
+        .iter()
+        .position(|component| component.root_file == check.source_file)
+      {
+        Some(index) => index,
+        None => {
+          report.components.push(ComponentCoverage {
+            root_file: check.source_file.clone(),
+            usages: 0,
+            children: Vec::new(),
+          });
+          report.components.len() - 1
+        }
+      };
+      let component = &mut report.components[index];
+
+      if !counted_roots.contains(&check.source_file.as_str()) {
+        counted_roots.push(&check.source_file);
+        component.usages += 1;
+      }
+
+      let child_index = match component
+        .children
+        .iter()
+        .position(|child| {
+          child.component == check.component && child.alternatives == check.alternatives
+        })
+      {
+        Some(index) => index,
+        None => {
+          component.children.push(ChildCoverage {
+            component: check.component.clone(),
+            alternatives: check.alternatives.clone(),
+            present: 0,
+            absent: 0,
+            unknown: 0,
+          });
+          component.children.len() - 1
+        }
+      };
+      let child = &mut component.children[child_index];
+
+      match check.presence.as_str() {
+        "present" => child.present += 1,
+        "absent" => child.absent += 1,
+        _ => child.unknown += 1,
+      }
+    }
+  }
+
+  report
+    .components
+    .sort_by(|a, b| a.root_file.cmp(&b.root_file));
+  for component in &mut report.components {
+    component
+      .children
+      .sort_by(|a, b| (&a.component, &a.alternatives).cmp(&(&b.component, &b.alternatives)));
+  }
+
+  debug(&format!(
+    "📊 Coverage over {} files found {} Roots",
+    report.files_analyzed,
+    report.components.len()
+  ));
+
+  Ok(report)
+}
+
+/// Serializes `report` as pretty-printed JSON with camelCase keys, matching the JS API
+pub fn coverage_report_json(report: &CoverageReport) -> String {
+  let components: Vec<serde_json::Value> = report
+    .components
+    .iter()
+    .map(|component| {
+      let children: Vec<serde_json::Value> = component
+        .children
+        .iter()
+        .map(|child| {
+          json!({
+            "component": child.component,
+            "alternatives": child.alternatives,
+            "present": child.present,
+            "absent": child.absent,
+            "unknown": child.unknown,
+          })
+        })
+        .collect();
+
+      json!({
+        "rootFile": component.root_file,
+        "usages": component.usages,
+        "children": children,
+      })
+    })
+    .collect();
+
+  let value = json!({
+    "filesAnalyzed": report.files_analyzed,
+    "components": components,
+  });
+
+  serde_json::to_string_pretty(&value).unwrap_or_default()
+}
+
+fn collect_source_files(dir: &Path, extensions: &[String], files: &mut Vec<PathBuf>) {
+  let Ok(entries) = std::fs::read_dir(dir) else {
+    return;
+  };
+
+  let mut paths: Vec<_> = entries.flatten().map(|entry| entry.path()).collect();
+  paths.sort();
+
+  for path in paths {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+      continue;
+    };
+
+    if path.is_dir() {
+      if name != "node_modules" && !name.starts_with('.') {
+        collect_source_files(&path, extensions, files);
+      }
+      continue;
+    }
+
+    let is_source = path
+      .extension()
+      .and_then(|extension| extension.to_str())
+      .is_some_and(|extension| extensions.iter().any(|ext| ext == extension));
+
+    if is_source {
+      files.push(path);
+    }
+  }
+}
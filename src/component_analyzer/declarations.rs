@@ -0,0 +1,135 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+  BindingPatternKind, Declaration, Statement, TSModuleDeclarationBody, TSModuleDeclarationName,
+  TSSignature, TSType,
+};
+use oxc_ast::AstKind;
+use oxc_semantic::SemanticBuilder;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::component_analyzer::utils::{debug, source_type_for_path, within_sandbox};
+
+/// The type declarations a package ships for `module_file`, one of its compiled JS files: the
+/// `types` or `typings` entry of its package.json, else the `.d.ts` next to the file
+pub fn package_types_file(module_file: &Path) -> Option<PathBuf> {
+  let package_dir = module_file
+    .ancestors()
+    .skip(1)
+    .find(|dir| dir.join("package.json").is_file())?;
+  let package_json: serde_json::Value =
+    serde_json::from_str(&fs::read_to_string(package_dir.join("package.json")).ok()?).ok()?;
+
+  let types_file = ["types", "typings"]
+    .iter()
+    .find_map(|field| package_json.get(field)?.as_str())
+    .map(|types| package_dir.join(types))
+    .unwrap_or_else(|| module_file.with_extension("d.ts"));
+  (types_file.is_file() && within_sandbox(&types_file)).then_some(types_file)
+}
+
+/// Members `types_file` declares for the compound component `namespace`, e.g. `Root` and
+/// `Description` for `export declare const Checkbox: { Root: Component; Description: Component }`
+/// or the same members declared in `export declare namespace Checkbox { ... }`
+pub fn declared_members(types_file: &Path, namespace: &str) -> Vec<String> {
+  let Ok(source_text) = fs::read_to_string(types_file) else {
+    return Vec::new();
+  };
+  let allocator = Allocator::default();
+  let parser_ret =
+    oxc_parser::Parser::new(&allocator, &source_text, source_type_for_path(types_file)).parse();
+
+  let mut members = Vec::new();
+  for statement in &parser_ret.program.body {
+    let declaration = match statement {
+      Statement::ExportNamedDeclaration(export) => export.declaration.as_ref(),
+      statement => statement.as_declaration(),
+    };
+    match declaration {
+      Some(Declaration::VariableDeclaration(variable)) => {
+        for declarator in &variable.declarations {
+          let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind else {
+            continue;
+          };
+          let Some(type_annotation) = &declarator.id.type_annotation else {
+            continue;
+          };
+          let TSType::TSTypeLiteral(literal) = &type_annotation.type_annotation else {
+            continue;
+          };
+          if id.name != namespace {
+            continue;
+          }
+          members.extend(literal.members.iter().filter_map(|member| match member {
+            TSSignature::TSPropertySignature(property) => property.key.static_name(),
+            TSSignature::TSMethodSignature(method) => method.key.static_name(),
+            _ => None,
+          }));
+        }
+      }
+      Some(Declaration::TSModuleDeclaration(module)) => {
+        let TSModuleDeclarationName::Identifier(id) = &module.id else {
+          continue;
+        };
+        let Some(TSModuleDeclarationBody::TSModuleBlock(block)) = &module.body else {
+          continue;
+        };
+        if id.name != namespace {
+          continue;
+        }
+        for statement in &block.body {
+          if let Statement::ExportNamedDeclaration(export) = statement {
+            members.extend(export.declaration.iter().flat_map(declared_names));
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+
+  debug(&format!(
+    "📘 {} declares {} with members [{}]",
+    types_file.display(),
+    namespace,
+    members.join(", ")
+  ));
+  members.into_iter().map(|member| member.to_string()).collect()
+}
+
+fn declared_names<'a>(declaration: &'a Declaration<'a>) -> Vec<std::borrow::Cow<'a, str>> {
+  match declaration {
+    Declaration::VariableDeclaration(variable) => variable
+      .declarations
+      .iter()
+      .filter_map(|declarator| declarator.id.get_identifier_name())
+      .map(|name| name.as_str().into())
+      .collect(),
+    Declaration::FunctionDeclaration(function) => function
+      .id
+      .iter()
+      .map(|id| id.name.as_str().into())
+      .collect(),
+    Declaration::ClassDeclaration(class) => {
+      class.id.iter().map(|id| id.name.as_str().into()).collect()
+    }
+    _ => Vec::new(),
+  }
+}
+
+/// Whether `module_file` renders any JSX, so its components can be searched for a child. Compiled
+/// packages render through calls such as `_jsxC(Description, ...)` instead.
+pub fn module_renders_jsx(module_file: &Path) -> bool {
+  let Ok(source_text) = fs::read_to_string(module_file) else {
+    return false;
+  };
+  let allocator = Allocator::default();
+  let parser_ret =
+    oxc_parser::Parser::new(&allocator, &source_text, source_type_for_path(module_file)).parse();
+  let semantic = SemanticBuilder::new().build(&parser_ret.program).semantic;
+
+  let renders_jsx = semantic
+    .nodes()
+    .iter()
+    .any(|node| matches!(node.kind(), AstKind::JSXElement(_) | AstKind::JSXFragment(_)));
+  renders_jsx
+}
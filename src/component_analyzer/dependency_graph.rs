@@ -0,0 +1,56 @@
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use crate::component_analyzer::utils::debug;
+
+/// The dependencies last collected for each file, so a changed component file can be traced back
+/// to the pages whose transformation it affects
+#[derive(Default)]
+struct DependencyGraph {
+  dependencies: HashMap<PathBuf, BTreeSet<PathBuf>>,
+}
+
+fn dependency_graph() -> &'static Mutex<DependencyGraph> {
+  static GRAPH: OnceLock<Mutex<DependencyGraph>> = OnceLock::new();
+  GRAPH.get_or_init(|| Mutex::new(DependencyGraph::default()))
+}
+
+/// Replaces what `file_path` was recorded to depend on with `dependencies`, as returned by
+/// `collect_dependencies`
+pub fn record_dependencies(file_path: &Path, dependencies: &[PathBuf]) {
+  let Ok(mut graph) = dependency_graph().lock() else {
+    return;
+  };
+  graph
+    .dependencies
+    .insert(file_path.to_path_buf(), dependencies.iter().cloned().collect());
+}
+
+/// Drops the dependencies recorded for `file_path`, e.g. after it was deleted
+pub fn forget_dependencies(file_path: &Path) {
+  let Ok(mut graph) = dependency_graph().lock() else {
+    return;
+  };
+  graph.dependencies.remove(file_path);
+}
+
+/// The files recorded as depending on `path`, sorted. These are the files to transform again when
+/// `path` changes.
+pub fn dependents_of(path: &Path) -> Vec<PathBuf> {
+  let Ok(graph) = dependency_graph().lock() else {
+    return Vec::new();
+  };
+  let dependents: BTreeSet<PathBuf> = graph
+    .dependencies
+    .iter()
+    .filter(|(_, dependencies)| dependencies.contains(path))
+    .map(|(file, _)| file.clone())
+    .collect();
+  debug(&format!(
+    "🕸️ {} files depend on {}",
+    dependents.len(),
+    path.display()
+  ));
+  dependents.into_iter().collect()
+}
@@ -0,0 +1,477 @@
+use oxc_ast::AstKind;
+use oxc_semantic::Semantic;
+use oxc_span::{GetSpan, Span};
+use std::path::Path;
+
+use crate::component_analyzer::component_presence::{find_presence_calls, ConditionalComponent};
+use crate::component_analyzer::import_resolver::{
+  find_import_source_for_component, get_specifier_name, is_local_specifier, is_type_only_import,
+  presence_target_name, resolve_import_path, OutsideSandbox,
+};
+use crate::component_analyzer::jsx_analysis::extract_jsx_element_name;
+use crate::component_analyzer::transformations::enclosing_component_arrow;
+use crate::component_analyzer::utils::{
+  component_alternatives, debug, extract_component_name_from_argument, is_presence_call,
+  jsx_element_matches_component, presence_prop_name, ComponentPresenceCall,
+};
+use crate::Diagnostic;
+
+/// Stable codes for each class of diagnostic, so tooling can filter or escalate them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticCode {
+  /// A relative, `~/` or `#` subpath import of a rendered component can't be resolved
+  UnresolvedImport,
+  /// A compound component from an external package has no presence calls to analyze and isn't
+  /// declared in `qwik-analyzer.json`
+  ExternalRootSkipped,
+  /// A component checks for the same child more than once
+  DuplicatePresenceCheck,
+  /// `isComponentPresent` was called with something other than a component reference
+  InvalidPresenceArgument,
+  /// A manifest-declared child is injected but never read by the Root
+  UnusedInjectedProp,
+  /// The source is larger than `maxSourceBytes` and wasn't analyzed
+  SourceTooLarge,
+  /// The same local name is imported from more than one module
+  AmbiguousImport,
+  /// Under strict scoping, a presence check could only be settled heuristically
+  AmbiguousPresence,
+  /// `isComponentPresent` was called without an argument
+  MissingPresenceArgument,
+  /// An import resolves outside the configured sandbox root and wasn't read
+  OutsideSandbox,
+  /// A rendered component is chosen at runtime, e.g. `FLAG ? A : B`, so a child it may stand for
+  /// is neither present nor absent
+  ConditionalComponent,
+  /// The file isn't JavaScript or TypeScript and wasn't analyzed
+  UnsupportedFileType,
+  /// A file needed to settle a presence check couldn't be read, so the check is left unresolved
+  UnreadableDependency,
+  /// The analysis hit an internal error, so the file was left as it is
+  AnalysisFailed,
+  /// A rendered element type comes from an array by `.map`, e.g. `sections.map((S) => <S />)`, so
+  /// a child it may stand for is neither present nor absent
+  DynamicElementType,
+  /// The file has syntax errors and wasn't analyzed
+  ParseFailed,
+}
+
+impl DiagnosticCode {
+  pub fn as_str(self) -> &'static str {
+    match self {
+      DiagnosticCode::UnresolvedImport => "QA001",
+      DiagnosticCode::ExternalRootSkipped => "QA002",
+      DiagnosticCode::DuplicatePresenceCheck => "QA003",
+      DiagnosticCode::InvalidPresenceArgument => "QA004",
+      DiagnosticCode::UnusedInjectedProp => "QA005",
+      DiagnosticCode::SourceTooLarge => "QA006",
+      DiagnosticCode::AmbiguousImport => "QA007",
+      DiagnosticCode::AmbiguousPresence => "QA008",
+      DiagnosticCode::MissingPresenceArgument => "QA009",
+      DiagnosticCode::OutsideSandbox => "QA010",
+      DiagnosticCode::ConditionalComponent => "QA011",
+      DiagnosticCode::UnsupportedFileType => "QA012",
+      DiagnosticCode::UnreadableDependency => "QA013",
+      DiagnosticCode::AnalysisFailed => "QA014",
+      DiagnosticCode::DynamicElementType => "QA015",
+      DiagnosticCode::ParseFailed => "QA016",
+    }
+  }
+}
+
+/// Builds a diagnostic. It is only reported through the analysis result, so hosts decide how to
+/// surface it.
+pub fn new_diagnostic(code: DiagnosticCode, message: String, file_path: &Path, span: Span) -> Diagnostic {
+  debug(&format!("🩺 {}: {} {}", file_path.display(), code.as_str(), message));
+
+  Diagnostic {
+    code: code.as_str().to_string(),
+    message,
+    file_path: file_path.to_string_lossy().to_string(),
+    start: span.start,
+    end: span.end,
+  }
+}
+
+/// Reports `isComponentPresent` calls whose argument isn't a component reference,
+/// e.g. `isComponentPresent("Description")`. Such calls are never transformed.
+pub fn presence_argument_diagnostics(
+  semantic: &Semantic,
+  source_text: &str,
+  file_path: &Path,
+) -> Vec<Diagnostic> {
+  let mut diagnostics = Vec::new();
+
+  for node in semantic.nodes().iter() {
+    let AstKind::CallExpression(call_expr) = node.kind() else {
+      continue;
+    };
+
+    if !is_presence_call(semantic, call_expr, file_path) {
+      continue;
+    }
+
+    let Some(first_arg) = call_expr.arguments.first() else {
+      diagnostics.push(new_diagnostic(
+        DiagnosticCode::MissingPresenceArgument,
+        "isComponentPresent was called without a component to check for, e.g. `isComponentPresent(Description)`".to_string(),
+        file_path,
+        call_expr.span,
+      ));
+      continue;
+    };
+
+    if extract_component_name_from_argument(first_arg).is_some() {
+      continue;
+    }
+
+    let arg_span = first_arg.span();
+    let arg_text = &source_text[arg_span.start as usize..arg_span.end as usize];
+    let message = format!(
+      "isComponentPresent expects a component reference such as `Description`, `Checkbox.Description` or `[Description, HelperText]`, found `{}`",
+      arg_text
+    );
+
+    diagnostics.push(new_diagnostic(
+      DiagnosticCode::InvalidPresenceArgument,
+      message,
+      file_path,
+      arg_span,
+    ));
+  }
+
+  diagnostics
+}
+
+/// Reports `isComponentPresent` calls that check a child the same component already checked for.
+/// Both calls receive the same injected prop, so the repeated check is redundant.
+pub fn duplicate_presence_diagnostics(semantic: &Semantic, file_path: &Path) -> Vec<Diagnostic> {
+  let mut diagnostics = Vec::new();
+  let mut seen_checks: Vec<(Option<Span>, String)> = Vec::new();
+
+  for node in semantic.nodes().iter() {
+    let AstKind::CallExpression(call_expr) = node.kind() else {
+      continue;
+    };
+
+    if !is_presence_call(semantic, call_expr, file_path) {
+      continue;
+    }
+
+    // Named the way `find_presence_calls` names checks, so a default import under another name
+    // duplicates a check on the export it resolves to
+    let Some(component_name) = call_expr
+      .arguments
+      .first()
+      .and_then(|argument| presence_target_name(semantic, argument, file_path))
+    else {
+      continue;
+    };
+
+    let component_span = enclosing_component_arrow(semantic, node.id()).map(|arrow| arrow.span);
+    let check = (component_span, component_name);
+
+    if !seen_checks.contains(&check) {
+      seen_checks.push(check);
+      continue;
+    }
+
+    let message = format!(
+      "`{}` is already checked by an earlier isComponentPresent call in this component",
+      check.1
+    );
+    diagnostics.push(new_diagnostic(
+      DiagnosticCode::DuplicatePresenceCheck,
+      message,
+      file_path,
+      call_expr.span,
+    ));
+  }
+
+  diagnostics
+}
+
+/// Reports local names bound by imports from more than one module, e.g. `import type { Root }`
+/// from one package and `import { Root }` from another. Lookups use the value import.
+pub fn ambiguous_import_diagnostics(semantic: &Semantic, file_path: &Path) -> Vec<Diagnostic> {
+  let mut diagnostics = Vec::new();
+  let mut seen_imports: Vec<(String, String)> = Vec::new();
+
+  for node in semantic.nodes().iter() {
+    let AstKind::ImportDeclaration(import_decl) = node.kind() else {
+      continue;
+    };
+
+    let Some(specifiers) = &import_decl.specifiers else {
+      continue;
+    };
+
+    for specifier in specifiers {
+      let Some(local_name) = get_specifier_name(specifier) else {
+        continue;
+      };
+
+      let source = import_decl.source.value.as_str();
+      let Some((_, first_source)) = seen_imports.iter().find(|(name, _)| name == local_name) else {
+        seen_imports.push((local_name.to_string(), source.to_string()));
+        continue;
+      };
+
+      if first_source == source {
+        continue;
+      }
+
+      let chosen_source = find_import_source_for_component(semantic, local_name)
+        .map(|import_binding| import_binding.source)
+        .unwrap_or_default();
+      let kind = if is_type_only_import(import_decl, specifier) {
+        "type"
+      } else {
+        "value"
+      };
+      let message = format!(
+        "`{}` is imported from both `{}` and `{}` (as a {}); resolving it through `{}`",
+        local_name, first_source, source, kind, chosen_source
+      );
+
+      diagnostics.push(new_diagnostic(
+        DiagnosticCode::AmbiguousImport,
+        message,
+        file_path,
+        specifier.span(),
+      ));
+    }
+  }
+
+  diagnostics
+}
+
+/// Reports rendered components the analyzer couldn't look into: local imports that don't resolve,
+/// and compound components from external packages that have neither presence calls nor a manifest
+/// entry. `found_calls` is whether scanning `jsx_component` produced any presence calls.
+pub fn jsx_import_diagnostics(
+  semantic: &Semantic,
+  jsx_component: &str,
+  found_calls: bool,
+  file_path: &Path,
+) -> Option<Diagnostic> {
+  let local_name = jsx_component.split('.').next().unwrap_or(jsx_component);
+  let import_source = find_import_source_for_component(semantic, local_name)?.source;
+
+  let (code, message) = match resolve_import_path(&import_source, file_path) {
+    Err(e) if e.downcast_ref::<OutsideSandbox>().is_some() => (
+      DiagnosticCode::OutsideSandbox,
+      format!(
+        "`{}` imported for `{}` resolves outside the sandbox root and was not read",
+        import_source, jsx_component
+      ),
+    ),
+    // Bare specifiers may be resolved by bundler aliases the analyzer doesn't know about
+    Err(_) if is_local_specifier(&import_source) => (
+      DiagnosticCode::UnresolvedImport,
+      format!(
+        "Could not resolve `{}` imported for `{}`",
+        import_source, jsx_component
+      ),
+    ),
+    Ok(resolved_path)
+      if resolved_path.contains("node_modules") && jsx_component.contains('.') && !found_calls =>
+    {
+      (
+        DiagnosticCode::ExternalRootSkipped,
+        format!(
+          "`{}` comes from the external package `{}` and has no isComponentPresent calls to analyze; declare it in qwik-analyzer.json if it checks for children",
+          jsx_component, import_source
+        ),
+      )
+    }
+    _ => return None,
+  };
+
+  let span = find_jsx_element_name_span(semantic, jsx_component)?;
+  Some(new_diagnostic(code, message, file_path, span))
+}
+
+/// Reports presence props injected into a manifest-declared Root that the Root's definition never
+/// reads, e.g. the manifest lists `Checkbox.Title` but the Root only calls
+/// `isComponentPresent(Description)`. Roots whose source can't be resolved are skipped. Only run
+/// under `verify_manifest`, since it parses the definition the manifest lets us skip.
+pub fn unused_injected_prop_diagnostics(
+  semantic: &Semantic,
+  jsx_component: &str,
+  injected_calls: &[ComponentPresenceCall],
+  file_path: &Path,
+) -> Vec<Diagnostic> {
+  let mut diagnostics = Vec::new();
+
+  let local_name = jsx_component.split('.').next().unwrap_or(jsx_component);
+  let Some(import_source) =
+    find_import_source_for_component(semantic, local_name).map(|import_binding| import_binding.source)
+  else {
+    return diagnostics;
+  };
+
+  if resolve_import_path(&import_source, file_path).is_err() {
+    debug(&format!(
+      "⏭️ Can't resolve {} for {}, skipping the injected prop cross-check",
+      import_source, jsx_component
+    ));
+    return diagnostics;
+  }
+
+  let definition_calls = find_presence_calls(semantic, jsx_component, file_path).unwrap_or_default();
+  let read_props: Vec<String> = definition_calls
+    .iter()
+    .map(|call| presence_prop_name(&call.component_name))
+    .collect();
+
+  let Some(span) = find_jsx_element_name_span(semantic, jsx_component) else {
+    return diagnostics;
+  };
+
+  for call in injected_calls {
+    let prop_name = presence_prop_name(&call.component_name);
+    if read_props.contains(&prop_name) {
+      continue;
+    }
+
+    let message = format!(
+      "`{}` receives `{}` but its definition in `{}` never calls `isComponentPresent` for `{}`",
+      jsx_component, prop_name, import_source, call.component_name
+    );
+
+    diagnostics.push(new_diagnostic(
+      DiagnosticCode::UnusedInjectedProp,
+      message,
+      file_path,
+      span,
+    ));
+  }
+
+  diagnostics
+}
+
+/// Reports calls of `jsx_component` that were only found by scanning its module directory, which
+/// strict scoping leaves unresolved
+pub fn module_scan_diagnostics(
+  semantic: &Semantic,
+  jsx_component: &str,
+  calls: &[ComponentPresenceCall],
+  file_path: &Path,
+) -> Vec<Diagnostic> {
+  let Some(span) = find_jsx_element_name_span(semantic, jsx_component) else {
+    return Vec::new();
+  };
+
+  calls
+    .iter()
+    .filter(|call| call.resolved_by_module_scan)
+    .map(|call| {
+      let message = format!(
+        "`{}` checks for `{}` only in `{}`, found by scanning its module directory; strict scoping leaves it unresolved",
+        jsx_component, call.component_name, call.source_file
+      );
+      new_diagnostic(DiagnosticCode::AmbiguousPresence, message, file_path, span)
+    })
+    .collect()
+}
+
+/// Reports a child rendered in this file but outside any Root defined in `call.source_file`,
+/// which strict scoping leaves unresolved
+pub fn unscoped_presence_diagnostic(
+  semantic: &Semantic,
+  call: &ComponentPresenceCall,
+  file_path: &Path,
+) -> Option<Diagnostic> {
+  let span = semantic.nodes().iter().find_map(|node| match node.kind() {
+    AstKind::JSXOpeningElement(jsx_opening) => {
+      let element_name = extract_jsx_element_name(jsx_opening)?;
+      component_alternatives(&call.component_name)
+        .into_iter()
+        .any(|alternative| {
+          jsx_element_matches_component(semantic, &element_name, alternative, file_path)
+        })
+        .then(|| jsx_opening.name.span())
+    }
+    _ => None,
+  })?;
+
+  let message = format!(
+    "`{}` is rendered outside the Root from `{}` that checks for it; strict scoping leaves it unresolved",
+    call.component_name, call.source_file
+  );
+  Some(new_diagnostic(DiagnosticCode::AmbiguousPresence, message, file_path, span))
+}
+
+/// Reports a presence check left unresolved because the child may be rendered through a
+/// conditionally bound component or an element type mapped from an array
+pub fn conditional_component_diagnostic(
+  call: &ComponentPresenceCall,
+  conditional: &ConditionalComponent,
+  file_path: &Path,
+) -> Diagnostic {
+  if let Some(array) = &conditional.mapped_from {
+    let elements = if conditional.exhaustive {
+      format!("one of {}", conditional.branches.join(" or "))
+    } else {
+      "any component".to_string()
+    };
+    let message = format!(
+      "`<{}>` is a dynamic element type taken from `{}` by `.map` and may be {}, so whether it renders `{}` for the Root in `{}` can't be known; the check is left unresolved",
+      conditional.element_name, array, elements, call.component_name, call.source_file
+    );
+    return new_diagnostic(DiagnosticCode::DynamicElementType, message, file_path, conditional.span);
+  }
+
+  let message = format!(
+    "`{}` is chosen at runtime from {}, so whether it renders `{}` for the Root in `{}` can't be known; the check is left unresolved",
+    conditional.element_name,
+    conditional.branches.join(" or "),
+    call.component_name,
+    call.source_file
+  );
+  new_diagnostic(DiagnosticCode::ConditionalComponent, message, file_path, conditional.span)
+}
+
+/// Reports a presence check left unresolved because a file it depends on failed with `error`
+pub fn unreadable_dependency_diagnostic(
+  call: &ComponentPresenceCall,
+  error: &str,
+  file_path: &Path,
+) -> Diagnostic {
+  let message = format!(
+    "Whether `{}` is rendered for the Root in `{}` couldn't be analyzed: {}; the check is left unresolved",
+    call.component_name, call.source_file, error
+  );
+  new_diagnostic(DiagnosticCode::UnreadableDependency, message, file_path, Span::empty(0))
+}
+
+/// Reports an analysis abandoned because of an internal error or panic described by `reason`
+pub fn analysis_failed_diagnostic(reason: &str, file_path: &Path) -> Diagnostic {
+  let message = format!(
+    "Skipped analysis: it failed with an internal error ({}); the file is left as it is",
+    reason
+  );
+  new_diagnostic(DiagnosticCode::AnalysisFailed, message, file_path, Span::empty(0))
+}
+
+/// Reports a file skipped because it doesn't parse, naming the first of its `error_count` errors
+pub fn parse_failed_diagnostic(first_error: &str, error_count: usize, file_path: &Path) -> Diagnostic {
+  let message = format!(
+    "Skipped analysis: the file has {} syntax error(s), the first being \"{}\"; the file is left as it is",
+    error_count, first_error
+  );
+  new_diagnostic(DiagnosticCode::ParseFailed, message, file_path, Span::empty(0))
+}
+
+/// Span of the tag name of the first JSX element rendered as `jsx_component`
+fn find_jsx_element_name_span(semantic: &Semantic, jsx_component: &str) -> Option<Span> {
+  semantic.nodes().iter().find_map(|node| match node.kind() {
+    AstKind::JSXOpeningElement(jsx_opening)
+      if extract_jsx_element_name(jsx_opening).as_deref() == Some(jsx_component) =>
+    {
+      Some(jsx_opening.name.span())
+    }
+    _ => None,
+  })
+}
@@ -0,0 +1,216 @@
+use oxc_allocator::Allocator;
+use oxc_parser;
+use std::cell::RefCell;
+use std::fs;
+use std::path::Path;
+
+use crate::component_analyzer::component_presence::{
+  conditionally_bound_components, find_presence_calls,
+};
+use crate::component_analyzer::config::current_config;
+use crate::component_analyzer::import_resolver::{
+  reset_unresolved_imports, resolve_component_origin,
+};
+use crate::component_analyzer::jsx_analysis::extract_imported_jsx_components;
+use crate::component_analyzer::manifest::load_manifest;
+use crate::component_analyzer::settle_presence;
+use crate::component_analyzer::utils::{
+  component_alternatives, debug, source_type_for_path, Presence,
+};
+use crate::{PresenceExplanation, Result};
+
+/// The decisions recorded while one presence check is settled. Only collected between
+/// `recording` starting and returning, so regular analyses don't pay for it.
+#[derive(Default)]
+struct Trace {
+  active: bool,
+  steps: Vec<String>,
+  matched_element: Option<String>,
+  depth: usize,
+}
+
+thread_local! {
+  /// Per thread, so analyses running alongside an explanation, e.g. in other Vite workers, don't
+  /// record into it
+  static TRACE: RefCell<Trace> = RefCell::new(Trace::default());
+}
+
+/// Clears the trace when dropped, so a panic while settling a check doesn't leave it recording
+struct RecordingGuard;
+
+impl Drop for RecordingGuard {
+  fn drop(&mut self) {
+    TRACE.with_borrow_mut(|trace| *trace = Trace::default());
+  }
+}
+
+fn with_active_trace(record: impl FnOnce(&mut Trace)) {
+  TRACE.with_borrow_mut(|trace| {
+    if trace.active {
+      record(trace);
+    }
+  });
+}
+
+/// Records a step of the check being explained, if any. `step` is only formatted then.
+pub fn note(step: impl FnOnce() -> String) {
+  with_active_trace(|trace| trace.steps.push(step()));
+}
+
+/// Records `element` as the JSX element that settled the check, keeping the innermost one when a
+/// match is reported again by the components around it
+pub fn note_match(element: &str, step: impl FnOnce() -> String) {
+  with_active_trace(|trace| {
+    trace.matched_element.get_or_insert_with(|| element.to_string());
+    trace.steps.push(step());
+  });
+}
+
+/// Records that the search reached `depth` levels of components below the analyzed file
+pub fn note_depth(depth: usize) {
+  with_active_trace(|trace| trace.depth = trace.depth.max(depth));
+}
+
+fn recording<T>(f: impl FnOnce() -> T) -> (T, Trace) {
+  TRACE.with_borrow_mut(|trace| {
+    *trace = Trace {
+      active: true,
+      ..Trace::default()
+    };
+  });
+  let _guard = RecordingGuard;
+  let result = f();
+  let recorded = TRACE.with_borrow_mut(std::mem::take);
+  (result, recorded)
+}
+
+/// Explains how the check `root_name` rendered in `file_path` makes for `target` was settled: the
+/// file defining the Root, the components searched and the element that matched, or why none did.
+/// `target` is the component as passed to `isComponentPresent`, e.g. `Description`.
+pub fn explain_presence(
+  file_path: &Path,
+  root_name: &str,
+  target: &str,
+) -> Result<PresenceExplanation> {
+  reset_unresolved_imports();
+  let source_text = fs::read_to_string(file_path)?;
+  let allocator = Allocator::default();
+  let source_type = source_type_for_path(file_path);
+
+  let oxc_parser::ParserReturn {
+    program, errors, ..
+  } = oxc_parser::Parser::new(&allocator, &source_text, source_type).parse();
+
+  if !errors.is_empty() {
+    return Err(format!("Failed to parse {}", file_path.display()).into());
+  }
+
+  let semantic_ret = oxc_semantic::SemanticBuilder::new().build(&program);
+  let semantic = &semantic_ret.semantic;
+
+  let mut explanation = PresenceExplanation {
+    root: root_name.to_string(),
+    root_file: None,
+    target: target.to_string(),
+    presence: None,
+    matched_element: None,
+    depth: 0,
+    steps: Vec::new(),
+  };
+
+  if !extract_imported_jsx_components(semantic).iter().any(|component| component == root_name) {
+    explanation.steps.push(format!(
+      "{} doesn't render an imported {}",
+      file_path.display(),
+      root_name
+    ));
+    return Ok(explanation);
+  }
+
+  match resolve_component_origin(semantic, root_name, file_path) {
+    Ok(origin) => {
+      let root_file = origin.component_file.unwrap_or(origin.module_file);
+      explanation.steps.push(format!("{} is defined in {}", root_name, root_file));
+      explanation.root_file = Some(root_file);
+    }
+    Err(reason) => {
+      explanation.steps.push(format!("{} couldn't be resolved: {}", root_name, reason));
+    }
+  }
+
+  let is_target =
+    |component_name: &str| component_alternatives(component_name).any(|name| name == target);
+
+  // Manifest roots are settled from this file's JSX alone
+  if let Some(calls) = load_manifest(file_path)
+    .as_ref()
+    .and_then(|manifest| manifest.presence_calls_for(semantic, root_name))
+  {
+    explanation.steps.push(format!(
+      "{} is declared in qwik-analyzer.json, so only {} is searched",
+      root_name,
+      file_path.display()
+    ));
+    match calls.into_iter().find(|call| is_target(&call.component_name)) {
+      Some(call) => {
+        explanation.root_file.get_or_insert(call.source_file);
+        explanation.presence = Some(call.presence.as_str().to_string());
+      }
+      None => explanation.steps.push(format!(
+        "the manifest doesn't list {} as a child of {}",
+        target, root_name
+      )),
+    }
+    return Ok(explanation);
+  }
+
+  let calls = find_presence_calls(semantic, root_name, file_path)?;
+  let Some(mut call) = calls.into_iter().find(|call| is_target(&call.component_name)) else {
+    explanation.steps.push(format!(
+      "{} doesn't call isComponentPresent({})",
+      root_name, target
+    ));
+    return Ok(explanation);
+  };
+  explanation.root_file.get_or_insert_with(|| call.source_file.clone());
+
+  let conditional_components = conditionally_bound_components(semantic);
+  let mut diagnostics = Vec::new();
+  let (settled, trace) = recording(|| {
+    settle_presence(semantic, &mut call, &conditional_components, file_path, &mut diagnostics)
+  });
+  settled?;
+
+  explanation.steps.extend(trace.steps);
+  explanation
+    .steps
+    .extend(diagnostics.into_iter().map(|diagnostic| diagnostic.message));
+
+  if current_config()
+    .assume_present
+    .iter()
+    .any(|name| component_alternatives(&call.component_name).any(|alternative| alternative == name))
+  {
+    explanation.steps.push(format!(
+      "{} is configured as always present (assumePresent)",
+      call.component_name
+    ));
+    call.presence = Presence::Present;
+  }
+
+  if call.presence.is_present() {
+    explanation.matched_element = trace.matched_element;
+  }
+  explanation.presence = Some(call.presence.as_str().to_string());
+  explanation.depth = trace.depth as u32;
+
+  debug(&format!(
+    "🧾 Explained {} for {} in {}: {} after {} steps",
+    target,
+    root_name,
+    file_path.display(),
+    call.presence.as_str(),
+    explanation.steps.len()
+  ));
+  Ok(explanation)
+}
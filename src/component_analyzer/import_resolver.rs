@@ -1,22 +1,205 @@
 use oxc_allocator::Allocator;
 use oxc_ast::AstKind;
 use oxc_parser;
-use oxc_resolver::{ResolveOptions, Resolver};
+use oxc_resolver::{
+  ResolveOptions, Resolver, TsconfigDiscovery, TsconfigOptions, TsconfigReferences,
+};
 use oxc_semantic::Semantic;
-use oxc_span::{GetSpan, SourceType, VALID_EXTENSIONS};
-use std::fs;
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
 
-use crate::component_analyzer::jsx_analysis::extract_jsx_element_name;
+use crate::component_analyzer::config::current_config;
+use crate::ImportResolution;
+use crate::component_analyzer::jsx_analysis::{extract_imported_jsx_components, extract_jsx_element_name};
 use crate::component_analyzer::utils::{
-  debug, extract_component_name_from_argument, extract_function_name, ComponentPresenceCall,
+  component_alternatives, debug, extract_component_name_from_argument, is_presence_call,
+  normalize_path, read_dependency, same_file, source_extensions, source_type_for_path,
+  within_sandbox, ComponentPresenceCall, Presence, COMPONENT_ALTERNATIVE_SEPARATOR,
 };
 use crate::Result;
 
+/// How many `export { default } from` hops to follow before giving up
+const MAX_REEXPORT_DEPTH: usize = 8;
+
+/// Specifiers that failed to resolve from a directory during the current analysis, so repeated
+/// lookups skip probing the filesystem again
+#[derive(Default)]
+struct UnresolvedImports {
+  failures: HashSet<(PathBuf, String)>,
+  hits: usize,
+}
+
+fn unresolved_imports() -> &'static Mutex<UnresolvedImports> {
+  static UNRESOLVED: OnceLock<Mutex<UnresolvedImports>> = OnceLock::new();
+  UNRESOLVED.get_or_init(|| Mutex::new(UnresolvedImports::default()))
+}
+
+/// The tsconfig.json found for each directory imports are resolved from, and a resolver per
+/// tsconfig, reused for the current analysis. The resolvers cache the filesystem, so they're
+/// dropped with the resolution failures.
+#[derive(Default)]
+struct Resolvers {
+  tsconfigs: HashMap<PathBuf, Option<PathBuf>>,
+  resolvers: HashMap<Option<PathBuf>, Arc<Resolver>>,
+}
+
+fn resolvers() -> &'static Mutex<Resolvers> {
+  static RESOLVERS: OnceLock<Mutex<Resolvers>> = OnceLock::new();
+  RESOLVERS.get_or_init(|| Mutex::new(Resolvers::default()))
+}
+
+/// Whether each presence function import, keyed by the importing directory, specifier and
+/// imported name, leads to the analyzer's runtime. Kept for the current analysis.
+type RuntimeImports = HashMap<(PathBuf, String, String), bool>;
+
+fn runtime_imports() -> &'static Mutex<RuntimeImports> {
+  static RUNTIME_IMPORTS: OnceLock<Mutex<RuntimeImports>> = OnceLock::new();
+  RUNTIME_IMPORTS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Forgets cached resolution failures, returning how many lookups they answered since the last
+/// reset. Called at the start of each analysis since files may have been added in between.
+pub fn reset_unresolved_imports() -> usize {
+  if let Ok(mut resolvers) = resolvers().lock() {
+    *resolvers = Resolvers::default();
+  }
+  if let Ok(mut runtime_imports) = runtime_imports().lock() {
+    runtime_imports.clear();
+  }
+  let Ok(mut unresolved) = unresolved_imports().lock() else {
+    return 0;
+  };
+  unresolved.failures.clear();
+  std::mem::take(&mut unresolved.hits)
+}
+
+/// Resolutions and presence calls shared by the files of one batch, so a library imported by
+/// every page is resolved and parsed once. Only populated between `begin_batch` and `end_batch`.
+#[derive(Default)]
+struct BatchCache {
+  active: bool,
+  resolutions: HashMap<(PathBuf, String), String>,
+  index_components: HashMap<(String, String), String>,
+  calls: HashMap<(String, Option<String>), Vec<ComponentPresenceCall>>,
+  hits: usize,
+}
+
+fn batch_cache() -> &'static Mutex<BatchCache> {
+  static BATCH: OnceLock<Mutex<BatchCache>> = OnceLock::new();
+  BATCH.get_or_init(|| Mutex::new(BatchCache::default()))
+}
+
+impl BatchCache {
+  /// Drops the calls parsed from `file_path` and every resolution. Those aren't tracked back to
+  /// the files they went through, and any of them may now lead elsewhere: a barrel re-exporting
+  /// from another file, or a new file shadowing the one a specifier resolved to.
+  fn invalidate(&mut self, file_path: &str) {
+    self.resolutions.clear();
+    self.index_components.clear();
+    self.calls.retain(|(source_file, _), _| !same_file(source_file, file_path));
+  }
+}
+
+/// Starts sharing import resolution and library parsing across the files analyzed until
+/// `end_batch`, returning whether it started one. A batch that is already open, such as one a
+/// long-running host keeps across analyses, is left as is. Files are assumed not to change while
+/// the batch runs unless they are passed to `invalidate_cached_file`.
+pub fn begin_batch() -> bool {
+  let Ok(mut batch) = batch_cache().lock() else {
+    return false;
+  };
+  if batch.active {
+    return false;
+  }
+  *batch = BatchCache {
+    active: true,
+    ..Default::default()
+  };
+  true
+}
+
+/// Drops everything cached since `begin_batch`, returning how many lookups the cache answered
+pub fn end_batch() -> usize {
+  let Ok(mut batch) = batch_cache().lock() else {
+    return 0;
+  };
+  std::mem::take(&mut *batch).hits
+}
+
+/// Forgets what the caches learned from `file_path`, which changed, was added or was deleted, so
+/// the next analysis reads it again, along with every cached resolution. Failed resolutions need
+/// no care: they're only kept for the length of one analysis.
+pub fn invalidate_cached_file(file_path: &Path) {
+  if let Ok(mut batch) = batch_cache().lock() {
+    batch.invalidate(&file_path.to_string_lossy());
+  }
+}
+
+/// Empties the caches, keeping an open batch open
+pub fn clear_caches() {
+  if let Ok(mut batch) = batch_cache().lock() {
+    *batch = BatchCache {
+      active: batch.active,
+      ..Default::default()
+    };
+  }
+  reset_unresolved_imports();
+}
+
+fn batch_lookup<K, V>(select: impl FnOnce(&BatchCache) -> &HashMap<K, V>, key: &K) -> Option<V>
+where
+  K: std::hash::Hash + Eq,
+  V: Clone,
+{
+  let mut batch = batch_cache().lock().ok()?;
+  if !batch.active {
+    return None;
+  }
+  let value = select(&batch).get(key).cloned()?;
+  batch.hits += 1;
+  Some(value)
+}
+
+fn batch_store(store: impl FnOnce(&mut BatchCache)) {
+  if let Ok(mut batch) = batch_cache().lock() {
+    if batch.active {
+      store(&mut batch);
+    }
+  }
+}
+
+/// How an import binds its local name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportKind {
+  /// `import Checkbox from "./checkbox"`
+  Default,
+  /// `import { Description } from "./checkbox"`, possibly renamed
+  Named,
+  /// `import * as Checkbox from "./checkbox"`
+  Namespace,
+}
+
+/// The import that binds a local name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportBinding {
+  pub source: String,
+  /// The export it names: `default` for default imports and `None` for namespace imports
+  pub imported_name: Option<String>,
+  pub kind: ImportKind,
+}
+
+/// Finds the import that binds `component_name`, so `import Checkbox, { Description }` tells the
+/// default `Checkbox` apart from the named `Description` of the same source. When the name is
+/// imported more than once, the first value import wins over `import type` ones.
 pub fn find_import_source_for_component(
   semantic: &Semantic,
   component_name: &str,
-) -> Option<String> {
+) -> Option<ImportBinding> {
+  use oxc_ast::ast::ImportDeclarationSpecifier;
+
+  let mut type_only_binding = None;
+
   for node in semantic.nodes().iter() {
     let AstKind::ImportDeclaration(import_decl) = node.kind() else {
       continue;
@@ -27,58 +210,183 @@ pub fn find_import_source_for_component(
     };
 
     for specifier in specifiers {
-      let Some(specifier_name) = get_specifier_name(specifier) else {
+      if get_specifier_name(specifier) != Some(component_name) {
         continue;
+      }
+
+      let (imported_name, kind) = match specifier {
+        ImportDeclarationSpecifier::ImportSpecifier(spec) => {
+          (Some(spec.imported.name().to_string()), ImportKind::Named)
+        }
+        ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => {
+          (Some("default".to_string()), ImportKind::Default)
+        }
+        ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => (None, ImportKind::Namespace),
+      };
+      let binding = ImportBinding {
+        source: import_decl.source.value.to_string(),
+        imported_name,
+        kind,
       };
 
-      if specifier_name == component_name {
-        debug(&format!(
-          "📥 Found import for {}: {}",
-          component_name, import_decl.source.value
-        ));
-        return Some(import_decl.source.value.to_string());
+      if is_type_only_import(import_decl, specifier) {
+        type_only_binding.get_or_insert(binding);
+        continue;
       }
+
+      debug(&format!(
+        "📥 Found import for {}: {}",
+        component_name, import_decl.source.value
+      ));
+      return Some(binding);
     }
   }
 
-  None
+  type_only_binding
+}
+
+/// Follows the import binding `local_name`, whose module resolved to `resolved_path`, to the file
+/// defining the imported export. `import { Root as CheckboxRoot } from "./checkbox"` resolves the
+/// barrel's `Root` export rather than the barrel itself; namespace imports stay on the module.
+pub fn resolve_imported_component_file(
+  semantic: &Semantic,
+  local_name: &str,
+  resolved_path: String,
+) -> String {
+  match find_import_source_for_component(semantic, local_name) {
+    Some(ImportBinding { imported_name: Some(imported_name), .. }) => {
+      resolve_component_from_index(&resolved_path, &imported_name).unwrap_or(resolved_path)
+    }
+    _ => resolved_path,
+  }
 }
 
+/// The file a resolved module stands for: the module itself, or the `index.ts`/`index.tsx` of a
+/// module directory
+pub fn module_index_file(module_dir: &str) -> String {
+  let module_path = Path::new(module_dir);
+  if module_path.is_file() {
+    return module_dir.to_string();
+  }
+
+  ["index.ts", "index.tsx"]
+    .iter()
+    .map(|index| module_path.join(index))
+    .find(|index| index.exists())
+    .map(|index| index.to_string_lossy().to_string())
+    .unwrap_or_else(|| module_dir.to_string())
+}
+
+/// Where a rendered JSX element's import leads
+#[derive(Debug)]
+pub struct ComponentOrigin {
+  pub module_file: String,
+  /// The file defining the element, when the module's exports name it
+  pub component_file: Option<String>,
+  /// Whether the module lives in node_modules rather than the project
+  pub is_package: bool,
+}
+
+/// Follows `jsx_component`, e.g. `Checkbox.Root` or `CheckboxRoot`, to the files behind it. The
+/// error says why it couldn't be followed.
+pub fn resolve_component_origin(
+  semantic: &Semantic,
+  jsx_component: &str,
+  current_file: &Path,
+) -> std::result::Result<ComponentOrigin, String> {
+  let (local_name, member) = match jsx_component.split_once('.') {
+    Some((namespace, member)) => (namespace, Some(member)),
+    None => (jsx_component, None),
+  };
+  let Some(import_binding) = find_import_source_for_component(semantic, local_name) else {
+    return Err(format!("no import binds {}", local_name));
+  };
+  let module_path = resolve_import_path(&import_binding.source, current_file)
+    .map_err(|_| format!("import '{}' doesn't resolve", import_binding.source))?;
+
+  let module_file = module_index_file(&module_path);
+  let component_file = match member {
+    Some(member) => resolve_namespace_member(&import_binding, &module_file, member).ok(),
+    None => Some(resolve_imported_component_file(semantic, local_name, module_path.clone())),
+  };
+
+  Ok(ComponentOrigin {
+    is_package: module_path.contains("node_modules"),
+    module_file,
+    component_file,
+  })
+}
+
+/// An import that resolved outside `AnalyzerConfig::sandbox_root`, so it was refused unread
+#[derive(Debug)]
+pub struct OutsideSandbox {
+  pub resolved_path: String,
+}
+
+impl std::fmt::Display for OutsideSandbox {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{} is outside the sandbox root", self.resolved_path)
+  }
+}
+
+impl std::error::Error for OutsideSandbox {}
+
+/// Resolves `import_source` as imported from `current_file`, refusing files outside the configured
+/// sandbox root with an `OutsideSandbox` error
 pub fn resolve_import_path(import_source: &str, current_file: &Path) -> Result<String> {
-  let options = ResolveOptions {
-    extensions: VALID_EXTENSIONS
-      .iter()
-      .map(|ext| format!(".{}", ext).into())
-      .collect(),
-    main_files: vec!["index".into()],
-    main_fields: vec!["main".into()],
-    ..Default::default()
+  let batch_key = (
+    current_file.parent().unwrap_or(current_file).to_path_buf(),
+    import_source.to_string(),
+  );
+  let resolved_path = match batch_lookup(|batch| &batch.resolutions, &batch_key) {
+    Some(resolved_path) => resolved_path,
+    None => {
+      let resolved_path = resolve_specifier(import_source, current_file)?;
+      batch_store(|batch| {
+        batch.resolutions.insert(batch_key, resolved_path.clone());
+      });
+      resolved_path
+    }
   };
+  if !within_sandbox(Path::new(&resolved_path)) {
+    debug(&format!(
+      "🚫 '{}' from {} resolves to {}, outside the sandbox root",
+      import_source,
+      current_file.display(),
+      resolved_path
+    ));
+    return Err(OutsideSandbox { resolved_path }.into());
+  }
+  Ok(resolved_path)
+}
 
-  let resolver = Resolver::new(options);
+fn resolve_specifier(import_source: &str, current_file: &Path) -> Result<String> {
   let current_dir = current_file
     .parent()
     .ok_or("Could not get parent directory")?;
 
-  if import_source.starts_with("~/") {
-    let mut search_dir = current_dir;
-    let mut project_root = None;
-
-    while let Some(parent) = search_dir.parent() {
-      if search_dir.join("package.json").exists() {
-        project_root = Some(search_dir);
-        break;
-      }
-      search_dir = parent;
+  let cache_key = (current_dir.to_path_buf(), import_source.to_string());
+  if let Ok(mut unresolved) = unresolved_imports().lock() {
+    if unresolved.failures.contains(&cache_key) {
+      unresolved.hits += 1;
+      debug(&format!(
+        "♻️ '{}' from {} already failed to resolve, skipping the lookup",
+        import_source,
+        current_dir.display()
+      ));
+      return Err(format!("Could not resolve import '{}' (cached)", import_source).into());
     }
+  }
 
-    if let Some(root) = project_root {
+  let extensions = source_extensions();
+  if import_source.starts_with("~/") {
+    if let Some(root) = find_project_root(current_file) {
       let relative_path = &import_source[2..];
-      let resolved_path = root.join("src").join(relative_path);
+      let resolved_path = normalize_path(&root.join("src").join(relative_path));
       if resolved_path.exists() {
         return Ok(resolved_path.to_string_lossy().to_string());
       }
-      for ext in VALID_EXTENSIONS {
+      for ext in &extensions {
         let path_with_ext = resolved_path.with_extension(ext);
         if path_with_ext.exists() {
           return Ok(path_with_ext.to_string_lossy().to_string());
@@ -87,7 +395,18 @@ pub fn resolve_import_path(import_source: &str, current_file: &Path) -> Result<S
     }
   }
 
-  match resolver.resolve(current_dir, import_source) {
+  // Aliases from tsconfig `paths` try each mapped target in order and use the first that exists.
+  // A tsconfig that fails to load shouldn't break every other import, so a failed lookup is
+  // retried without it.
+  let tsconfig = directory_tsconfig(current_dir, current_file);
+  let resolution = match tsconfig {
+    Some(_) => resolver_for(tsconfig, &extensions)
+      .resolve(current_dir, import_source)
+      .or_else(|_| resolver_for(None, &extensions).resolve(current_dir, import_source)),
+    None => resolver_for(None, &extensions).resolve(current_dir, import_source),
+  };
+
+  match resolution {
     Ok(resolution) => {
       let resolved_path = resolution.full_path();
       Ok(resolved_path.to_string_lossy().to_string())
@@ -97,20 +416,228 @@ pub fn resolve_import_path(import_source: &str, current_file: &Path) -> Result<S
         "❌ Import resolution failed for '{}': {:?}",
         import_source, e
       ));
+      if let Ok(mut unresolved) = unresolved_imports().lock() {
+        unresolved.failures.insert(cache_key);
+      }
       Err(format!("Could not resolve import '{}': {:?}", import_source, e).into())
     }
   }
 }
 
+/// The tsconfig.json used for imports in `current_dir`, looked up once per analysis
+fn directory_tsconfig(current_dir: &Path, current_file: &Path) -> Option<PathBuf> {
+  if let Some(tsconfig) = resolvers()
+    .lock()
+    .ok()
+    .and_then(|resolvers| resolvers.tsconfigs.get(current_dir).cloned())
+  {
+    return tsconfig;
+  }
+  let tsconfig = project_tsconfig(current_file);
+  if let Ok(mut resolvers) = resolvers().lock() {
+    resolvers.tsconfigs.insert(current_dir.to_path_buf(), tsconfig.clone());
+  }
+  tsconfig
+}
+
+/// The resolver following `tsconfig`'s `paths`, or plain module resolution without one
+fn resolver_for(tsconfig: Option<PathBuf>, extensions: &[String]) -> Arc<Resolver> {
+  if let Some(resolver) = resolvers()
+    .lock()
+    .ok()
+    .and_then(|resolvers| resolvers.resolvers.get(&tsconfig).cloned())
+  {
+    return resolver;
+  }
+
+  let options = ResolveOptions {
+    extensions: extensions.iter().map(|ext| format!(".{}", ext)).collect(),
+    main_files: vec!["index".into()],
+    main_fields: vec!["main".into()],
+    // `#`-prefixed subpath imports are looked up in the nearest package.json `imports` field
+    imports_fields: vec![vec!["imports".into()]],
+    condition_names: vec!["import".into(), "module".into(), "default".into()],
+    tsconfig: tsconfig.clone().map(|config_file| {
+      TsconfigDiscovery::Manual(TsconfigOptions {
+        config_file,
+        references: TsconfigReferences::Auto,
+      })
+    }),
+    ..Default::default()
+  };
+  let resolver = Arc::new(Resolver::new(options));
+  if let Ok(mut resolvers) = resolvers().lock() {
+    resolvers.resolvers.insert(tsconfig, resolver.clone());
+  }
+  resolver
+}
+
+/// The tsconfig.json at the project root of `current_file`, if there is one
+fn project_tsconfig(current_file: &Path) -> Option<PathBuf> {
+  let tsconfig = find_project_root(current_file)?.join("tsconfig.json");
+  (tsconfig.is_file() && within_sandbox(&tsconfig)).then_some(tsconfig)
+}
+
+/// Relative, `~/` and `#` subpath imports always point into the project itself
+pub fn is_local_specifier(import_source: &str) -> bool {
+  import_source.starts_with('.') || import_source.starts_with("~/") || import_source.starts_with('#')
+}
+
+/// Finds the project root of `current_file`: the configured `project_root` when the file lives
+/// inside it, otherwise the nearest ancestor directory that contains a package.json
+pub fn find_project_root(current_file: &Path) -> Option<PathBuf> {
+  if let Some(project_root) = &current_config().project_root {
+    if current_file.starts_with(project_root) {
+      return Some(project_root.clone());
+    }
+  }
+
+  let mut search_dir = current_file.parent()?;
+
+  while let Some(parent) = search_dir.parent() {
+    if search_dir.join("package.json").exists() {
+      return Some(search_dir.to_path_buf());
+    }
+    search_dir = parent;
+  }
+
+  None
+}
+
+/// Whether `imported_name` imported from `import_source` in `current_file` is the analyzer's own
+/// presence function: imported from a runtime module, from a file of a runtime package, e.g. the
+/// package's own source, or from a file re-exporting it from one of those. A project helper that
+/// merely shares the name is not.
+pub fn is_runtime_import(import_source: &str, imported_name: &str, current_file: &Path) -> bool {
+  let cache_key = (
+    current_file.parent().unwrap_or(current_file).to_path_buf(),
+    import_source.to_string(),
+    imported_name.to_string(),
+  );
+  if let Some(is_runtime) = runtime_imports()
+    .lock()
+    .ok()
+    .and_then(|runtime_imports| runtime_imports.get(&cache_key).copied())
+  {
+    return is_runtime;
+  }
+  let is_runtime = follows_to_runtime(import_source, imported_name, current_file, 0);
+  if let Ok(mut runtime_imports) = runtime_imports().lock() {
+    runtime_imports.insert(cache_key, is_runtime);
+  }
+  is_runtime
+}
+
+fn follows_to_runtime(
+  import_source: &str,
+  imported_name: &str,
+  current_file: &Path,
+  depth: usize,
+) -> bool {
+  let config = current_config();
+  if config.is_runtime_module(import_source) {
+    return true;
+  }
+  if depth >= MAX_REEXPORT_DEPTH {
+    return false;
+  }
+  let Ok(resolved_path) = resolve_import_path(import_source, current_file) else {
+    return false;
+  };
+  let resolved_file = Path::new(&resolved_path);
+  if package_name(resolved_file).is_some_and(|name| config.is_runtime_module(&name)) {
+    return true;
+  }
+
+  let Ok(source_text) = read_dependency(&resolved_path) else {
+    return false;
+  };
+  let allocator = Allocator::default();
+  let parser_ret =
+    oxc_parser::Parser::new(&allocator, &source_text, source_type_for_path(resolved_file)).parse();
+  if !parser_ret.errors.is_empty() {
+    return false;
+  }
+  let semantic_ret = oxc_semantic::SemanticBuilder::new().build(&parser_ret.program);
+  let semantic = &semantic_ret.semantic;
+
+  for node in semantic.nodes().iter() {
+    match node.kind() {
+      // export { isComponentPresent } from "..." or import { ... } from "..."; export { ... }
+      AstKind::ExportNamedDeclaration(export_decl) => {
+        let Some(specifier) = export_decl.specifiers.iter().find(|specifier| {
+          specifier.exported.name() == imported_name && !is_type_only_export(export_decl, specifier)
+        }) else {
+          continue;
+        };
+        let local_name = specifier.local.name();
+        return match &export_decl.source {
+          Some(source) => follows_to_runtime(&source.value, &local_name, resolved_file, depth + 1),
+          None => find_import_source_for_component(semantic, &local_name).is_some_and(|binding| {
+            let name = binding.imported_name.as_deref().unwrap_or(&local_name);
+            follows_to_runtime(&binding.source, name, resolved_file, depth + 1)
+          }),
+        };
+      }
+      // export * from "..."
+      AstKind::ExportAllDeclaration(export_all)
+        if export_all.exported.is_none()
+          && follows_to_runtime(&export_all.source.value, imported_name, resolved_file, depth + 1) =>
+      {
+        return true;
+      }
+      _ => {}
+    }
+  }
+
+  debug(&format!(
+    "⏭️ {} from {} isn't the analyzer's runtime",
+    imported_name, resolved_path
+  ));
+  false
+}
+
+/// The `name` of the package.json nearest to `file`, when it may be read
+fn package_name(file: &Path) -> Option<String> {
+  let package_json = file
+    .ancestors()
+    .skip(1)
+    .map(|dir| dir.join("package.json"))
+    .find(|package_json| package_json.is_file())?;
+  if !within_sandbox(&package_json) {
+    return None;
+  }
+  let package_json: serde_json::Value =
+    serde_json::from_str(&std::fs::read_to_string(package_json).ok()?).ok()?;
+  Some(package_json.get("name")?.as_str()?.to_string())
+}
+
 pub fn resolve_component_from_index(index_file_path: &str, component_name: &str) -> Result<String> {
+  let batch_key = (index_file_path.to_string(), component_name.to_string());
+  if let Some(component_file) = batch_lookup(|batch| &batch.index_components, &batch_key) {
+    return Ok(component_file);
+  }
+  let component_file = resolve_index_component(index_file_path, component_name)?;
+  batch_store(|batch| {
+    batch.index_components.insert(batch_key, component_file.clone());
+  });
+  Ok(component_file)
+}
+
+fn resolve_index_component(index_file_path: &str, component_name: &str) -> Result<String> {
   debug(&format!(
     "🔍 Using oxc to resolve {} from index file: {}",
     component_name, index_file_path
   ));
 
-  let source_text = fs::read_to_string(index_file_path)?;
+  // The barrel's default export is itself the component, e.g. `export { default } from "./notice"`
+  if component_name == "default" {
+    return resolve_default_export(index_file_path, 0);
+  }
+
+  let source_text = read_dependency(index_file_path)?;
   let allocator = Allocator::default();
-  let source_type = SourceType::from_path(Path::new(index_file_path)).unwrap_or_default();
+  let source_type = source_type_for_path(Path::new(index_file_path));
 
   let oxc_parser::ParserReturn {
     program, errors, ..
@@ -129,6 +656,10 @@ pub fn resolve_component_from_index(index_file_path: &str, component_name: &str)
       // Handle re-export pattern: export { MyTestRoot as Root } from "./my-test-root"
       AstKind::ExportNamedDeclaration(export_decl) => {
         for specifier in &export_decl.specifiers {
+          // `export type { Root } from "./types"` names a type, never the component
+          if is_type_only_export(export_decl, specifier) {
+            continue;
+          }
           let exported_name = &specifier.exported.name();
           if exported_name == component_name {
             if let Some(source) = &export_decl.source {
@@ -138,7 +669,36 @@ pub fn resolve_component_from_index(index_file_path: &str, component_name: &str)
               ));
               
               let index_file = Path::new(index_file_path);
-              return resolve_import_path(&source.value, index_file);
+              let target_file = resolve_import_path(&source.value, index_file)?;
+
+              // export { default as Root } from "./root" names whatever root.tsx exports as default
+              if specifier.local.name() == "default" {
+                return resolve_default_export(&target_file, 0);
+              }
+              return Ok(target_file);
+            }
+
+            // A local export such as `export { ListRoot as Root }` names a binding of this file,
+            // either imported into it or defined in it
+            let local_name = specifier.local.name();
+            if let Some(import_binding) = find_import_source_for_component(semantic, &local_name) {
+              debug(&format!(
+                "📂 Found local export {} of {} imported from: {}",
+                component_name, local_name, import_binding.source
+              ));
+              let target_file =
+                resolve_import_path(&import_binding.source, Path::new(index_file_path))?;
+              if target_file == index_file_path {
+                return Ok(target_file);
+              }
+              return Ok(resolve_imported_component_file(semantic, &local_name, target_file));
+            }
+            if semantic.scoping().get_root_binding(&local_name).is_some() {
+              debug(&format!(
+                "📂 Found local export {} of {} defined in {}",
+                component_name, local_name, index_file_path
+              ));
+              return Ok(index_file_path.to_string());
             }
           }
         }
@@ -146,35 +706,30 @@ pub fn resolve_component_from_index(index_file_path: &str, component_name: &str)
       
       // Handle object export pattern: export const MyTest = { Root: MyTestRoot, Child: MyTestChild }
       AstKind::VariableDeclarator(declarator) => {
-        // Check if this variable has an object expression as init
-        if let Some(oxc_ast::ast::Expression::ObjectExpression(obj_expr)) = &declarator.init {
-          // Look through object properties for our component
-          for prop in &obj_expr.properties {
-            if let oxc_ast::ast::ObjectPropertyKind::ObjectProperty(obj_prop) = prop {
-              if let oxc_ast::ast::PropertyKey::StaticIdentifier(key) = &obj_prop.key {
-                if key.name == component_name {
-                  // Found the property! Get the value which should be an identifier
-                  if let oxc_ast::ast::Expression::Identifier(value_ident) = &obj_prop.value {
-                    let import_name = &value_ident.name;
-                    debug(&format!(
-                      "📂 Found object property {} maps to identifier: {}",
-                      component_name, import_name
-                    ));
-                    
-                    // Find the import for this identifier in the same file
-                    if let Some(import_source) = find_import_source_for_component(semantic, import_name) {
-                      debug(&format!(
-                        "📂 Found import source for {}: {}",
-                        import_name, import_source
-                      ));
-                      
-                      let index_file = Path::new(index_file_path);
-                      return resolve_import_path(&import_source, index_file);
-                    }
-                  }
-                }
-              }
-            }
+        // Check if this variable has an object expression as init, possibly under `as const` or
+        // `satisfies Record<...>`
+        if let Some(oxc_ast::ast::Expression::ObjectExpression(obj_expr)) =
+          declarator.init.as_ref().map(|init| init.get_inner_expression())
+        {
+          if let Some(resolved) =
+            resolve_object_property(semantic, obj_expr, component_name, index_file_path)
+          {
+            return resolved;
+          }
+        }
+      }
+
+      // Handle default object export pattern: export default { Root: MyTestRoot, Child: MyTestChild }
+      AstKind::ExportDefaultDeclaration(export_default) => {
+        if let Some(oxc_ast::ast::Expression::ObjectExpression(obj_expr)) = export_default
+          .declaration
+          .as_expression()
+          .map(|expression| expression.get_inner_expression())
+        {
+          if let Some(resolved) =
+            resolve_object_property(semantic, obj_expr, component_name, index_file_path)
+          {
+            return resolved;
           }
         }
       }
@@ -185,11 +740,377 @@ pub fn resolve_component_from_index(index_file_path: &str, component_name: &str)
   Err(format!("Could not find component {} in index file", component_name).into())
 }
 
+/// Resolves `member` of a namespace imported from `module_file`, e.g. `Checkbox.Root`. A default
+/// import reads the member off the module's default export, so a named `Root` export of the same
+/// module doesn't shadow it.
+pub fn resolve_namespace_member(
+  import_binding: &ImportBinding,
+  module_file: &str,
+  member: &str,
+) -> Result<String> {
+  if import_binding.kind != ImportKind::Default {
+    return resolve_component_from_index(module_file, member);
+  }
+
+  let batch_key = (module_file.to_string(), format!("default.{}", member));
+  if let Some(component_file) = batch_lookup(|batch| &batch.index_components, &batch_key) {
+    return Ok(component_file);
+  }
+  let component_file = resolve_default_export_member(module_file, member)?;
+  batch_store(|batch| {
+    batch.index_components.insert(batch_key, component_file.clone());
+  });
+  Ok(component_file)
+}
+
+/// Resolves `member` of the object `module_file` exports as default, either inline as
+/// `export default { Root }` or through a binding as
+/// `const Checkbox = { Root }; export default Checkbox`. Default exports that aren't such objects
+/// fall back to the module's named exports.
+fn resolve_default_export_member(module_file: &str, member: &str) -> Result<String> {
+  use oxc_ast::ast::{ExportDefaultDeclarationKind, Expression};
+
+  let source_text = read_dependency(module_file)?;
+  let allocator = Allocator::default();
+  let source_type = source_type_for_path(Path::new(module_file));
+  let parser_ret = oxc_parser::Parser::new(&allocator, &source_text, source_type).parse();
+  if !parser_ret.errors.is_empty() {
+    return Err("Failed to parse index file".into());
+  }
+  let semantic_ret = oxc_semantic::SemanticBuilder::new().build(&parser_ret.program);
+  let semantic = &semantic_ret.semantic;
+
+  let default_object = semantic.nodes().iter().find_map(|node| {
+    let AstKind::ExportDefaultDeclaration(export_default) = node.kind() else {
+      return None;
+    };
+    match &export_default.declaration {
+      ExportDefaultDeclarationKind::Identifier(ident) => {
+        semantic.nodes().iter().find_map(|node| match node.kind() {
+          AstKind::VariableDeclarator(declarator)
+            if declarator
+              .id
+              .get_binding_identifier()
+              .is_some_and(|binding| binding.name == ident.name) =>
+          {
+            match declarator.init.as_ref().map(|init| init.get_inner_expression()) {
+              Some(Expression::ObjectExpression(obj_expr)) => Some(obj_expr),
+              _ => None,
+            }
+          }
+          _ => None,
+        })
+      }
+      declaration => match declaration
+        .as_expression()
+        .map(|expression| expression.get_inner_expression())
+      {
+        Some(Expression::ObjectExpression(obj_expr)) => Some(obj_expr),
+        _ => None,
+      },
+    }
+  });
+
+  if let Some(obj_expr) = default_object {
+    if let Some(resolved) = resolve_object_property(semantic, obj_expr, member, module_file) {
+      debug(&format!(
+        "📂 Resolved {} through the default export of {}",
+        member, module_file
+      ));
+      return resolved;
+    }
+  }
+
+  resolve_component_from_index(module_file, member)
+}
+
+/// Resolves the file behind the `component_name` property of a namespace object such as
+/// `{ Root: MyTestRoot }`, when its value is an identifier imported into the index file
+fn resolve_object_property(
+  semantic: &Semantic,
+  obj_expr: &oxc_ast::ast::ObjectExpression,
+  component_name: &str,
+  index_file_path: &str,
+) -> Option<Result<String>> {
+  // Look through object properties for our component
+  for prop in &obj_expr.properties {
+    let oxc_ast::ast::ObjectPropertyKind::ObjectProperty(obj_prop) = prop else {
+      continue;
+    };
+    let oxc_ast::ast::PropertyKey::StaticIdentifier(key) = &obj_prop.key else {
+      continue;
+    };
+    if key.name != component_name {
+      continue;
+    }
+
+    // Found the property! Get the value which should be an identifier
+    let oxc_ast::ast::Expression::Identifier(value_ident) = &obj_prop.value else {
+      continue;
+    };
+    let import_name = &value_ident.name;
+    debug(&format!(
+      "📂 Found object property {} maps to identifier: {}",
+      component_name, import_name
+    ));
+
+    // Find the import for this identifier in the same file
+    if let Some(import_binding) = find_import_source_for_component(semantic, import_name) {
+      debug(&format!(
+        "📂 Found import source for {}: {}",
+        import_name, import_binding.source
+      ));
+
+      let index_file = Path::new(index_file_path);
+      return Some(resolve_import_path(&import_binding.source, index_file));
+    }
+
+    // A single-file compound component defines its parts next to the object
+    if semantic.scoping().get_root_binding(import_name).is_some() {
+      debug(&format!(
+        "📂 {} is defined in {} itself",
+        import_name, index_file_path
+      ));
+      return Some(Ok(index_file_path.to_string()));
+    }
+  }
+
+  None
+}
+
+/// Follows a module's default export to the file that defines it, through chains such as
+/// `export { default } from "./impl"` or `import Root from "./impl"; export default Root`.
+/// Returns `file_path` itself when the default export is defined there.
+pub fn resolve_default_export(file_path: &str, depth: usize) -> Result<String> {
+  use oxc_ast::ast::ExportDefaultDeclarationKind;
+
+  if depth >= MAX_REEXPORT_DEPTH {
+    debug(&format!("⏹️ Re-export chain too deep at {}", file_path));
+    return Ok(file_path.to_string());
+  }
+
+  let source_text = read_dependency(file_path)?;
+  let allocator = Allocator::default();
+  let source_type = source_type_for_path(Path::new(file_path));
+
+  let oxc_parser::ParserReturn {
+    program, errors, ..
+  } = oxc_parser::Parser::new(&allocator, &source_text, source_type).parse();
+
+  if !errors.is_empty() {
+    return Ok(file_path.to_string());
+  }
+
+  let semantic_ret = oxc_semantic::SemanticBuilder::new().build(&program);
+  let semantic = &semantic_ret.semantic;
+
+  for node in semantic.nodes().iter() {
+    let (source, local_name) = match node.kind() {
+      // export { default } from "./impl" or export { Impl as default } from "./impl"
+      AstKind::ExportNamedDeclaration(export_decl) => {
+        let Some(source) = &export_decl.source else {
+          continue;
+        };
+        let Some(specifier) = export_decl.specifiers.iter().find(|specifier| {
+          specifier.exported.name() == "default" && !is_type_only_export(export_decl, specifier)
+        })
+        else {
+          continue;
+        };
+        (source.value.to_string(), Some(specifier.local.name().to_string()))
+      }
+      // import Impl from "./impl"; export default Impl
+      AstKind::ExportDefaultDeclaration(export_default) => {
+        let ExportDefaultDeclarationKind::Identifier(ident) = &export_default.declaration else {
+          return Ok(file_path.to_string());
+        };
+        let Some(import_binding) = find_import_source_for_component(semantic, &ident.name) else {
+          return Ok(file_path.to_string());
+        };
+        (import_binding.source, import_binding.imported_name)
+      }
+      _ => continue,
+    };
+
+    let Ok(target_file) = resolve_import_path(&source, Path::new(file_path)) else {
+      return Ok(file_path.to_string());
+    };
+
+    debug(&format!(
+      "📂 Default export of {} continues into {}",
+      file_path, target_file
+    ));
+
+    return match local_name.as_deref() {
+      Some("default") => resolve_default_export(&target_file, depth + 1),
+      Some(name) => Ok(resolve_component_from_index(&target_file, name).unwrap_or(target_file)),
+      None => Ok(target_file),
+    };
+  }
+
+  Ok(file_path.to_string())
+}
+
+/// The name `file_path` gives its default export, e.g. `Legend` for `export default Legend` or
+/// `export default function Legend() {}`. `None` when the export is anonymous.
+fn default_export_name(file_path: &str) -> Result<Option<String>> {
+  use oxc_ast::ast::ExportDefaultDeclarationKind;
+
+  let source_text = read_dependency(file_path)?;
+  let allocator = Allocator::default();
+  let source_type = source_type_for_path(Path::new(file_path));
+
+  let oxc_parser::ParserReturn {
+    program, errors, ..
+  } = oxc_parser::Parser::new(&allocator, &source_text, source_type).parse();
+
+  if !errors.is_empty() {
+    return Ok(None);
+  }
+
+  let semantic_ret = oxc_semantic::SemanticBuilder::new().build(&program);
+
+  for node in semantic_ret.semantic.nodes().iter() {
+    match node.kind() {
+      AstKind::ExportDefaultDeclaration(export_default) => {
+        let ident = match &export_default.declaration {
+          ExportDefaultDeclarationKind::Identifier(ident) => Some(ident.name),
+          ExportDefaultDeclarationKind::FunctionDeclaration(function) => {
+            function.id.as_ref().map(|id| id.name)
+          }
+          ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+            class.id.as_ref().map(|id| id.name)
+          }
+          _ => None,
+        };
+        return Ok(ident.map(|name| name.to_string()));
+      }
+      // const Legend = component$(...); export { Legend as default }
+      AstKind::ExportNamedDeclaration(export_decl) if export_decl.source.is_none() => {
+        if let Some(specifier) = export_decl
+          .specifiers
+          .iter()
+          .find(|specifier| specifier.exported.name() == "default")
+        {
+          return Ok(Some(specifier.local.name().to_string()));
+        }
+      }
+      _ => {}
+    }
+  }
+
+  Ok(None)
+}
+
+/// Names the components a presence check's `argument` in `file_path` checks for, like
+/// `extract_component_name_from_argument`. A default import bound under another name, e.g.
+/// `import Caption from "./legend"`, is named after the export it resolves to, the name consumers
+/// render it by.
+pub fn presence_target_name(
+  semantic: &Semantic,
+  argument: &oxc_ast::ast::Argument,
+  file_path: &Path,
+) -> Option<String> {
+  let component_name = extract_component_name_from_argument(argument)?;
+
+  let alternatives: Vec<String> = component_alternatives(&component_name)
+    .map(|alternative| {
+      default_import_name(semantic, alternative, file_path)
+        .unwrap_or_else(|| alternative.to_string())
+    })
+    .collect();
+  Some(alternatives.join(&COMPONENT_ALTERNATIVE_SEPARATOR.to_string()))
+}
+
+fn default_import_name(semantic: &Semantic, local_name: &str, file_path: &Path) -> Option<String> {
+  let import_binding = find_import_source_for_component(semantic, local_name)?;
+  if import_binding.kind != ImportKind::Default {
+    return None;
+  }
+  let module_file = resolve_import_path(&import_binding.source, file_path).ok()?;
+  let defining_file = resolve_default_export(&module_file, 0).ok()?;
+  let name = default_export_name(&defining_file).ok().flatten()?;
+
+  if name != local_name {
+    debug(&format!(
+      "🏷️ {} is the default export {} of {}",
+      local_name, name, defining_file
+    ));
+  }
+  Some(name)
+}
 
 pub fn find_calls_in_file(file_path: &str) -> Result<Vec<ComponentPresenceCall>> {
-  let source_text = fs::read_to_string(file_path)?;
+  find_calls(file_path, None)
+}
+
+/// Presence calls made inside the component bound to `binding` in `file_path`, for files that
+/// define several components, such as a single-file compound component
+pub fn find_calls_in_component(file_path: &str, binding: &str) -> Result<Vec<ComponentPresenceCall>> {
+  find_calls(file_path, Some(binding))
+}
+
+/// The local component a single-file compound component exposes as `part`, e.g. `ToggleRoot` for
+/// `const ToggleRoot = component$(...); export const Toggle = { Root: ToggleRoot }`. `None` when
+/// the part isn't defined in `file_path` itself.
+pub fn local_part_binding(file_path: &str, part: &str) -> Result<Option<String>> {
+  let source_text = read_dependency(file_path)?;
   let allocator = Allocator::default();
-  let source_type = SourceType::from_path(Path::new(file_path)).unwrap_or_default();
+  let source_type = source_type_for_path(Path::new(file_path));
+
+  let oxc_parser::ParserReturn {
+    program, errors, ..
+  } = oxc_parser::Parser::new(&allocator, &source_text, source_type).parse();
+
+  if !errors.is_empty() {
+    return Ok(None);
+  }
+
+  let semantic_ret = oxc_semantic::SemanticBuilder::new().build(&program);
+  let semantic = &semantic_ret.semantic;
+  let scoping = semantic.scoping();
+
+  for node in semantic.nodes().iter() {
+    let AstKind::ObjectProperty(obj_prop) = node.kind() else {
+      continue;
+    };
+    let oxc_ast::ast::PropertyKey::StaticIdentifier(key) = &obj_prop.key else {
+      continue;
+    };
+    let oxc_ast::ast::Expression::Identifier(value_ident) = &obj_prop.value else {
+      continue;
+    };
+    if key.name != part {
+      continue;
+    }
+
+    let is_local = scoping
+      .get_root_binding(&value_ident.name)
+      .is_some_and(|symbol_id| !scoping.symbol_flags(symbol_id).is_import());
+    if is_local {
+      return Ok(Some(value_ident.name.to_string()));
+    }
+  }
+
+  Ok(None)
+}
+
+fn find_calls(file_path: &str, binding: Option<&str>) -> Result<Vec<ComponentPresenceCall>> {
+  let batch_key = (file_path.to_string(), binding.map(str::to_string));
+  if let Some(calls) = batch_lookup(|batch| &batch.calls, &batch_key) {
+    return Ok(calls);
+  }
+  let calls = parse_calls(file_path, binding)?;
+  batch_store(|batch| {
+    batch.calls.insert(batch_key, calls.clone());
+  });
+  Ok(calls)
+}
+
+fn parse_calls(file_path: &str, binding: Option<&str>) -> Result<Vec<ComponentPresenceCall>> {
+  let source_text = read_dependency(file_path)?;
+  let allocator = Allocator::default();
+  let source_type = source_type_for_path(Path::new(file_path));
 
   let oxc_parser::ParserReturn {
     program, errors, ..
@@ -209,35 +1130,36 @@ pub fn find_calls_in_file(file_path: &str) -> Result<Vec<ComponentPresenceCall>>
       continue;
     };
 
-    let Some(function_name) = extract_function_name(call_expr) else {
+    if !is_presence_call(semantic, call_expr, Path::new(file_path)) {
       continue;
-    };
+    }
 
-    if function_name != "isComponentPresent" {
-      continue;
+    // The outermost declarator is the component's, e.g. `Root` rather than `hasLabel` in
+    // `const Root = component$(() => { const hasLabel = isComponentPresent(Label); ... })`
+    if let Some(binding) = binding {
+      let enclosing_binding = semantic
+        .nodes()
+        .ancestor_ids(node.id())
+        .filter_map(|id| match semantic.nodes().kind(id) {
+          AstKind::VariableDeclarator(declarator) => declarator.id.get_binding_identifier(),
+          _ => None,
+        })
+        .last();
+      if enclosing_binding.is_none_or(|ident| ident.name != binding) {
+        continue;
+      }
     }
 
     let Some(first_arg) = call_expr.arguments.first() else {
       continue;
     };
 
-    let component_name = if let Some(name) = extract_component_name_from_argument(first_arg) {
-      name
-    } else {
-      let arg_span = first_arg.span();
-      let full_text = &source_text[arg_span.start as usize..arg_span.end as usize];
-      if full_text.contains('.') {
-        debug(&format!(
-          "Extracted full member expression from find_calls_in_file: {}",
-          full_text
-        ));
-        full_text.to_string()
-      } else {
-        debug(&format!(
-          "Could not extract component name from argument in find_calls_in_file"
-        ));
-        continue;
-      }
+    let Some(component_name) = presence_target_name(semantic, first_arg, Path::new(file_path))
+    else {
+      debug(&format!(
+        "Could not extract component name from argument in find_calls_in_file"
+      ));
+      continue;
     };
 
     debug(&format!(
@@ -247,8 +1169,10 @@ pub fn find_calls_in_file(file_path: &str) -> Result<Vec<ComponentPresenceCall>>
 
     calls.push(ComponentPresenceCall {
       component_name,
-      is_present_in_subtree: false,
+      presence: Presence::Absent,
+      found_directly: false,
       source_file: file_path.to_string(),
+      resolved_by_module_scan: false,
     });
   }
 
@@ -256,9 +1180,9 @@ pub fn find_calls_in_file(file_path: &str) -> Result<Vec<ComponentPresenceCall>>
 }
 
 pub fn file_has_component(file_path: &str, target_component: &str) -> Result<bool> {
-  let source_text = fs::read_to_string(file_path)?;
+  let source_text = read_dependency(file_path)?;
   let allocator = Allocator::default();
-  let source_type = SourceType::from_path(Path::new(file_path)).unwrap_or_default();
+  let source_type = source_type_for_path(Path::new(file_path));
 
   let oxc_parser::ParserReturn {
     program, errors, ..
@@ -290,7 +1214,30 @@ pub fn file_has_component(file_path: &str, target_component: &str) -> Result<boo
   Ok(false)
 }
 
-fn get_specifier_name<'a>(
+/// Whether `specifier` only imports a type, through `import type { .. }` or `import { type .. }`
+pub fn is_type_only_import(
+  import_decl: &oxc_ast::ast::ImportDeclaration,
+  specifier: &oxc_ast::ast::ImportDeclarationSpecifier,
+) -> bool {
+  if import_decl.import_kind.is_type() {
+    return true;
+  }
+
+  matches!(
+    specifier,
+    oxc_ast::ast::ImportDeclarationSpecifier::ImportSpecifier(spec) if spec.import_kind.is_type()
+  )
+}
+
+/// Whether `specifier` only exports a type, through `export type { .. }` or `export { type .. }`
+pub fn is_type_only_export(
+  export_decl: &oxc_ast::ast::ExportNamedDeclaration,
+  specifier: &oxc_ast::ast::ExportSpecifier,
+) -> bool {
+  export_decl.export_kind.is_type() || specifier.export_kind.is_type()
+}
+
+pub fn get_specifier_name<'a>(
   specifier: &'a oxc_ast::ast::ImportDeclarationSpecifier,
 ) -> Option<&'a str> {
   match specifier {
@@ -303,3 +1250,55 @@ fn get_specifier_name<'a>(
     }
   }
 }
+
+/// Reports how each import binding a component rendered in this file resolves, since presence
+/// analysis silently stops at imports it can't follow
+pub fn import_resolutions(semantic: &Semantic, file_path: &Path) -> Vec<ImportResolution> {
+  let rendered_names: Vec<String> = extract_imported_jsx_components(semantic)
+    .into_iter()
+    .map(|component| component.split('.').next().unwrap_or(&component).to_string())
+    .collect();
+
+  let mut resolutions = Vec::new();
+
+  for node in semantic.nodes().iter() {
+    let AstKind::ImportDeclaration(import_decl) = node.kind() else {
+      continue;
+    };
+
+    let local_names: Vec<String> = import_decl
+      .specifiers
+      .iter()
+      .flatten()
+      .filter(|specifier| !is_type_only_import(import_decl, specifier))
+      .filter_map(get_specifier_name)
+      .filter(|name| rendered_names.iter().any(|rendered| rendered == name))
+      .map(str::to_string)
+      .collect();
+
+    if local_names.is_empty() {
+      continue;
+    }
+
+    let source = import_decl.source.value.to_string();
+    let (status, resolved_path) = match resolve_import_path(&source, file_path) {
+      Ok(resolved_path) if resolved_path.contains("node_modules") => ("package", Some(resolved_path)),
+      Ok(resolved_path) => ("local", Some(resolved_path)),
+      Err(_) => ("unresolved", None),
+    };
+
+    debug(&format!(
+      "🩺 Import '{}' for {:?} -> {}",
+      source, local_names, status
+    ));
+
+    resolutions.push(ImportResolution {
+      source,
+      local_names,
+      status: status.to_string(),
+      resolved_path,
+    });
+  }
+
+  resolutions
+}
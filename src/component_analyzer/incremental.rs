@@ -0,0 +1,122 @@
+use oxc_allocator::Allocator;
+use oxc_ast::AstKind;
+use oxc_parser;
+use oxc_span::Span;
+use std::path::Path;
+
+use crate::component_analyzer::utils::{
+  debug, extract_function_name, source_type_for_path, PRESENCE_FUNCTIONS,
+};
+use crate::Transformation;
+
+/// A single text edit against the source a previous analysis ran on, in byte offsets
+#[derive(Debug, Clone)]
+pub struct TextEdit {
+  pub offset: u32,
+  pub removed_length: u32,
+  pub inserted_text: String,
+}
+
+impl TextEdit {
+  fn end(&self) -> u32 {
+    self.offset.saturating_add(self.removed_length)
+  }
+
+  /// Whether the edit changes anything inside `start..end`. Insertion-only spans count as touched
+  /// when the edit starts or ends exactly on them, since the new text's position relative to the
+  /// insertion would be ambiguous.
+  fn touches(&self, start: u32, end: u32) -> bool {
+    if start == end {
+      return self.offset <= start && start <= self.end();
+    }
+    self.offset < end && self.end() > start
+  }
+}
+
+/// Shifts the previous `transformations` past `edit` so they apply to the edited source, without
+/// re-running the analysis. Returns the shifted transformations and whether the edit touched
+/// something that can change the analysis (an import, JSX, an `isComponentPresent` call or an
+/// existing transformation), in which case the caller should analyze the file again.
+pub fn adjust_transformations_for_edit(
+  source_text: &str,
+  file_path: &Path,
+  transformations: Vec<Transformation>,
+  edit: &TextEdit,
+) -> (Vec<Transformation>, bool) {
+  let mut requires_reanalysis = edit.end() as usize > source_text.len()
+    || edit_touches_analyzed_code(source_text, file_path, edit);
+
+  let inserted_length = edit.inserted_text.len() as i64;
+  let delta = inserted_length - edit.removed_length as i64;
+  let mut adjusted = Vec::with_capacity(transformations.len());
+
+  for transformation in transformations {
+    if edit.touches(transformation.start, transformation.end) {
+      debug(&format!(
+        "✏️ Edit at {} touches transformation {}..{}, re-analysis required",
+        edit.offset, transformation.start, transformation.end
+      ));
+      requires_reanalysis = true;
+      continue;
+    }
+
+    if transformation.start < edit.offset {
+      adjusted.push(transformation);
+      continue;
+    }
+
+    adjusted.push(Transformation {
+      start: (transformation.start as i64 + delta) as u32,
+      end: (transformation.end as i64 + delta) as u32,
+      replacement: transformation.replacement,
+    });
+  }
+
+  (adjusted, requires_reanalysis)
+}
+
+fn edit_touches_analyzed_code(source_text: &str, file_path: &Path, edit: &TextEdit) -> bool {
+  // New JSX, imports or presence calls can change the result wherever they are typed
+  let inserted = &edit.inserted_text;
+  if inserted.contains('<')
+    || inserted.contains("import")
+    || PRESENCE_FUNCTIONS.iter().any(|function| inserted.contains(function))
+  {
+    return true;
+  }
+
+  let allocator = Allocator::default();
+  let source_type = source_type_for_path(file_path);
+
+  let oxc_parser::ParserReturn {
+    program, errors, ..
+  } = oxc_parser::Parser::new(&allocator, source_text, source_type).parse();
+
+  if !errors.is_empty() {
+    return true;
+  }
+
+  let semantic_ret = oxc_semantic::SemanticBuilder::new().build(&program);
+  let semantic = &semantic_ret.semantic;
+
+  let touches_analyzed_code = semantic.nodes().iter().any(|node| {
+    let span: Span = match node.kind() {
+      AstKind::ImportDeclaration(import_decl) => import_decl.span,
+      AstKind::ExportNamedDeclaration(export_decl) if export_decl.source.is_some() => export_decl.span,
+      AstKind::ExportAllDeclaration(export_decl) => export_decl.span,
+      AstKind::JSXElement(jsx_element) => jsx_element.span,
+      AstKind::JSXFragment(jsx_fragment) => jsx_fragment.span,
+      AstKind::CallExpression(call_expr)
+        if extract_function_name(call_expr)
+          .is_some_and(|name| PRESENCE_FUNCTIONS.contains(&name.as_str())) =>
+      {
+        call_expr.span
+      }
+      _ => return false,
+    };
+
+    span.start != span.end && edit.touches(span.start, span.end)
+  });
+
+  touches_analyzed_code
+}
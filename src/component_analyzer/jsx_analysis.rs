@@ -4,8 +4,10 @@ use oxc_semantic::Semantic;
 use oxc_syntax::identifier::is_identifier_name;
 use oxc_syntax::keyword::is_reserved_keyword_or_global_object;
 use phf::phf_set;
-use std::collections::HashSet;
+use std::collections::BTreeSet;
 
+use crate::component_analyzer::config::current_config;
+use crate::component_analyzer::import_resolver::find_import_source_for_component;
 use crate::component_analyzer::utils::debug;
 
 const HTML_TAGS: phf::Set<&'static str> = phf_set![
@@ -160,8 +162,12 @@ const HTML_TAGS: phf::Set<&'static str> = phf_set![
   "xmp",
 ];
 
+/// Distinct component names rendered in JSX, e.g. `Checkbox.Root` or `Panel`, sorted by name so
+/// everything derived from them comes out in the same order on every run. Limited to the
+/// configured `only_components` when set.
 pub fn extract_imported_jsx_components(semantic: &Semantic) -> Vec<String> {
-  let mut components = HashSet::new();
+  let config = current_config();
+  let mut components = BTreeSet::new();
 
   debug(&format!("🔍 Starting JSX component extraction"));
 
@@ -195,10 +201,36 @@ pub fn extract_imported_jsx_components(semantic: &Semantic) -> Vec<String> {
     }
   }
 
+  if config.only_components.is_some() {
+    components.retain(|component| {
+      let analyzed = config.analyzes_component(component);
+      if !analyzed {
+        debug(&format!("⏭️ {} is not in onlyComponents, skipping it", component));
+      }
+      analyzed
+    });
+  }
+
   debug(&format!("🔍 Finished JSX component extraction, found {} components", components.len()));
   components.into_iter().collect()
 }
 
+/// Distinct namespaces of the imported compound components rendered in JSX, e.g. `Checkbox` for
+/// `<Checkbox.Root>`, sorted by name. Namespaces declared in the file itself are left out.
+pub fn collect_namespaces(semantic: &Semantic) -> Vec<String> {
+  let mut namespaces: Vec<String> = extract_imported_jsx_components(semantic)
+    .into_iter()
+    .filter_map(|component| {
+      let (namespace, _) = component.split_once('.')?;
+      find_import_source_for_component(semantic, namespace).map(|_| namespace.to_string())
+    })
+    .collect();
+
+  namespaces.sort();
+  namespaces.dedup();
+  namespaces
+}
+
 fn parse_member_component(element_name: &str) -> Option<String> {
   let parts: Vec<&str> = element_name.split('.').collect();
   if parts.len() == 2 {
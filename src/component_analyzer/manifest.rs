@@ -0,0 +1,213 @@
+use oxc_ast::AstKind;
+use oxc_semantic::Semantic;
+use std::fs;
+use std::path::Path;
+
+use crate::component_analyzer::import_resolver::{
+  find_import_source_for_component, find_project_root, ImportBinding,
+};
+use crate::component_analyzer::jsx_analysis::extract_jsx_element_name;
+use crate::component_analyzer::utils::{debug, within_sandbox, ComponentPresenceCall};
+
+pub const MANIFEST_FILE_NAME: &str = "qwik-analyzer.json";
+
+/// Marks `ComponentPresenceCall::source_file` values that come from the manifest rather than a
+/// scanned source file
+const MANIFEST_SOURCE_PREFIX: &str = "qwik-analyzer.json:";
+
+/// A compound component root declared in `qwik-analyzer.json`, e.g.
+/// `{ "components": { "Checkbox.Root": { "source": "@kunai-consulting/qwik", "children": ["Checkbox.Description"] } } }`
+#[derive(Debug, Clone)]
+pub struct ManifestComponent {
+  /// Exported name of the root as seen from `source`, e.g. `Checkbox.Root`
+  pub root: String,
+  /// Module specifier the root is imported from
+  pub source: String,
+  /// Optional children the root checks for with `isComponentPresent`
+  pub children: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Manifest {
+  pub components: Vec<ManifestComponent>,
+}
+
+/// Loads `qwik-analyzer.json` from the project root of `current_file`, if there is one
+pub fn load_manifest(current_file: &Path) -> Option<Manifest> {
+  let project_root = find_project_root(current_file)?;
+  let manifest_path = project_root.join(MANIFEST_FILE_NAME);
+  if !within_sandbox(&manifest_path) {
+    return None;
+  }
+  let manifest_text = fs::read_to_string(&manifest_path).ok()?;
+
+  match parse_manifest(&manifest_text) {
+    Ok(manifest) => {
+      debug(&format!(
+        "📜 Loaded {} with {} component(s)",
+        manifest_path.display(),
+        manifest.components.len()
+      ));
+      Some(manifest)
+    }
+    Err(e) => {
+      eprintln!("Invalid {}: {}", manifest_path.display(), e);
+      None
+    }
+  }
+}
+
+pub fn parse_manifest(manifest_text: &str) -> crate::Result<Manifest> {
+  let value: serde_json::Value = serde_json::from_str(manifest_text)?;
+  let components = value
+    .get("components")
+    .and_then(|components| components.as_object())
+    .ok_or("expected a \"components\" object")?;
+
+  let mut manifest = Manifest::default();
+
+  for (root, entry) in components {
+    let source = entry
+      .get("source")
+      .and_then(|source| source.as_str())
+      .ok_or_else(|| format!("component {} is missing a \"source\" string", root))?;
+
+    let children = entry
+      .get("children")
+      .and_then(|children| children.as_array())
+      .map(|children| {
+        children
+          .iter()
+          .filter_map(|child| child.as_str().map(str::to_string))
+          .collect()
+      })
+      .unwrap_or_default();
+
+    manifest.components.push(ManifestComponent {
+      root: root.clone(),
+      source: source.to_string(),
+      children,
+    });
+  }
+
+  Ok(manifest)
+}
+
+pub fn is_manifest_source(source_file: &str) -> bool {
+  source_file.starts_with(MANIFEST_SOURCE_PREFIX)
+}
+
+fn manifest_source(component: &ManifestComponent) -> String {
+  format!(
+    "{}{}#{}",
+    MANIFEST_SOURCE_PREFIX, component.source, component.root
+  )
+}
+
+impl Manifest {
+  fn find_component(&self, semantic: &Semantic, jsx_element_name: &str) -> Option<&ManifestComponent> {
+    let (source, exported_name) = exported_jsx_name(semantic, jsx_element_name)?;
+
+    self
+      .components
+      .iter()
+      .find(|component| {
+        specifier_matches_source(&source, &component.source) && component.root == exported_name
+      })
+  }
+
+  /// Builds the presence calls for a JSX root declared in the manifest, without reading the
+  /// library source. Returns `None` when the element isn't a manifest root.
+  pub fn presence_calls_for(
+    &self,
+    semantic: &Semantic,
+    jsx_element_name: &str,
+  ) -> Option<Vec<ComponentPresenceCall>> {
+    let component = self.find_component(semantic, jsx_element_name)?;
+
+    debug(&format!(
+      "📜 {} is declared in the manifest as {} from {}",
+      jsx_element_name, component.root, component.source
+    ));
+
+    let calls = component
+      .children
+      .iter()
+      .map(|child| {
+        // Manifest children are only ever matched against the current file's JSX
+        let is_present = jsx_uses_export(semantic, &component.source, child);
+        ComponentPresenceCall {
+          component_name: child.clone(),
+          presence: is_present.into(),
+          found_directly: is_present,
+          source_file: manifest_source(component),
+          resolved_by_module_scan: false,
+        }
+      })
+      .collect();
+
+    Some(calls)
+  }
+
+  /// Whether `jsx_element_name` is the manifest root that `source_file` was generated for
+  pub fn jsx_element_matches_source(
+    &self,
+    semantic: &Semantic,
+    jsx_element_name: &str,
+    source_file: &str,
+  ) -> bool {
+    self
+      .find_component(semantic, jsx_element_name)
+      .is_some_and(|component| manifest_source(component) == source_file)
+  }
+}
+
+/// Maps a JSX element name to the module it's imported from and its exported name there,
+/// e.g. `CB.Root` with `import { Checkbox as CB } from "lib"` becomes `("lib", "Checkbox.Root")`
+fn exported_jsx_name(semantic: &Semantic, jsx_element_name: &str) -> Option<(String, String)> {
+  let (local_name, member) = match jsx_element_name.split_once('.') {
+    Some((local_name, member)) => (local_name, Some(member)),
+    None => (jsx_element_name, None),
+  };
+
+  let ImportBinding { source, imported_name, .. } =
+    find_import_source_for_component(semantic, local_name)?;
+
+  let exported_name = match (imported_name, member) {
+    (Some(imported_name), Some(member)) => format!("{}.{}", imported_name, member),
+    (Some(imported_name), None) => imported_name,
+    (None, Some(member)) => member.to_string(),
+    (None, None) => return None,
+  };
+
+  Some((source, exported_name))
+}
+
+/// Whether an import `specifier` comes from the manifest `source`. Package sources also cover their
+/// subpaths, so `@kunai-consulting/qwik/checkbox` matches `@kunai-consulting/qwik` while
+/// `@kunai-consulting/qwik-icons` doesn't. Relative sources must match exactly.
+fn specifier_matches_source(specifier: &str, source: &str) -> bool {
+  if specifier == source {
+    return true;
+  }
+
+  let is_package = !source.starts_with('.') && !source.starts_with('/');
+  is_package
+    && specifier
+      .strip_prefix(source)
+      .is_some_and(|subpath| subpath.starts_with('/'))
+}
+
+fn jsx_uses_export(semantic: &Semantic, source: &str, exported_name: &str) -> bool {
+  semantic.nodes().iter().any(|node| {
+    let AstKind::JSXOpeningElement(jsx_opening) = node.kind() else {
+      return false;
+    };
+
+    extract_jsx_element_name(jsx_opening)
+      .and_then(|element_name| exported_jsx_name(semantic, &element_name))
+      .is_some_and(|(element_source, element_export)| {
+        specifier_matches_source(&element_source, source) && element_export == exported_name
+      })
+  })
+}
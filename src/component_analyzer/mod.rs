@@ -1,97 +1,590 @@
 use oxc_allocator::Allocator;
 use oxc_parser;
-use oxc_span::SourceType;
+use oxc_span::Span;
 use std::fs;
-use std::path::Path;
+use std::collections::BTreeSet;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
 
-use crate::{AnalysisResult, Result};
+use crate::{AnalysisResult, Diagnostic, ImportResolution, PresenceCheck, Result};
 
 pub mod component_presence;
+pub mod config;
+pub mod coverage;
+pub mod declarations;
+pub mod dependency_graph;
+pub mod diagnostics;
+pub mod explanation;
 pub mod import_resolver;
+pub mod incremental;
 pub mod jsx_analysis;
+pub mod manifest;
+pub mod preview;
 pub mod transformations;
 pub mod utils;
 
 use component_presence::find_presence_calls;
-use jsx_analysis::extract_imported_jsx_components;
-use transformations::{transform_components, transform_file};
-use utils::debug;
+use config::current_config;
+use diagnostics::{
+  ambiguous_import_diagnostics, analysis_failed_diagnostic, conditional_component_diagnostic,
+  duplicate_presence_diagnostics, jsx_import_diagnostics, module_scan_diagnostics, new_diagnostic, presence_argument_diagnostics,
+  parse_failed_diagnostic, unreadable_dependency_diagnostic, unscoped_presence_diagnostic,
+  unused_injected_prop_diagnostics, DiagnosticCode,
+};
+use import_resolver::{import_resolutions, reset_unresolved_imports, resolve_component_origin};
+use jsx_analysis::{collect_namespaces, extract_imported_jsx_components};
+use manifest::load_manifest;
+use transformations::{has_component_present_calls, transform_components, transform_file};
+use component_presence::ConditionalComponent;
+use utils::{
+  component_alternatives, debug,
+  find_jsx_element_for_component, source_type_for_path, split_alternatives,
+  supported_source_type, ComponentPresenceCall, FileRole, Presence,
+};
 
 pub fn analyze_file_with_semantics(file_path: &Path) -> Result<AnalysisResult> {
   let source_text = fs::read_to_string(file_path)?;
   analyze_code_with_semantics(&source_text, file_path)
 }
 
-pub fn analyze_code_with_semantics(source_text: &str, file_path: &Path) -> Result<AnalysisResult> {
+/// The resolved file and local/package classification of `jsx_component`, or why it has none,
+/// as `key=value` pairs for its summary log line
+fn resolution_decision(
+  semantic: &oxc_semantic::Semantic,
+  jsx_component: &str,
+  file_path: &Path,
+) -> String {
+  match resolve_component_origin(semantic, jsx_component, file_path) {
+    Ok(origin) => format!(
+      "file={} origin={}",
+      origin.component_file.unwrap_or(origin.module_file),
+      if origin.is_package { "package" } else { "local" }
+    ),
+    Err(reason) => format!("origin=unresolved reason=\"{}\"", reason),
+  }
+}
+
+/// Lists the compound-component namespaces `source_text` renders, see [`collect_namespaces`]
+pub fn collect_namespaces_in_code(source_text: &str, file_path: &Path) -> Result<Vec<String>> {
   let allocator = Allocator::default();
-  let source_type = SourceType::from_path(file_path).unwrap_or_default();
+  let source_type = source_type_for_path(file_path);
 
   let oxc_parser::ParserReturn {
     program, errors, ..
   } = oxc_parser::Parser::new(&allocator, source_text, source_type).parse();
 
   if !errors.is_empty() {
-    eprintln!("Parser errors: {:?}", errors);
-    return Ok(AnalysisResult {
-      has_component: false,
-      file_path: file_path.to_string_lossy().to_string(),
-      dependencies: Vec::new(),
-      transformations: Vec::new(),
-    });
+    return Err(format!("Failed to parse {}", file_path.display()).into());
+  }
+
+  let semantic_ret = oxc_semantic::SemanticBuilder::new().build(&program);
+  Ok(collect_namespaces(&semantic_ret.semantic))
+}
+
+/// Classifies `file_path` as defining compound components, consuming them, both or neither. A file
+/// consumes one when it renders an imported component whose definition calls `isComponentPresent`.
+pub fn classify_file(file_path: &Path) -> Result<FileRole> {
+  reset_unresolved_imports();
+  let source_text = fs::read_to_string(file_path)?;
+  let allocator = Allocator::default();
+  let source_type = source_type_for_path(file_path);
+
+  let oxc_parser::ParserReturn {
+    program, errors, ..
+  } = oxc_parser::Parser::new(&allocator, &source_text, source_type).parse();
+
+  if !errors.is_empty() {
+    return Err(format!("Failed to parse {}", file_path.display()).into());
   }
 
   let semantic_ret = oxc_semantic::SemanticBuilder::new().build(&program);
   let semantic = &semantic_ret.semantic;
 
-  if !semantic_ret.errors.is_empty() {
-    eprintln!("Semantic errors: {:?}", semantic_ret.errors);
+  let is_definition = has_component_present_calls(semantic, file_path);
+  let manifest = load_manifest(file_path);
+  let is_consumer = extract_imported_jsx_components(semantic).iter().any(|jsx_component| {
+    let manifest_calls = manifest
+      .as_ref()
+      .and_then(|manifest| manifest.presence_calls_for(semantic, jsx_component));
+    match manifest_calls {
+      Some(calls) => !calls.is_empty(),
+      None => find_presence_calls(semantic, jsx_component, file_path)
+        .is_ok_and(|calls| !calls.is_empty()),
+    }
+  });
+  debug(&format!(
+    "🏷️ {} defines compound components: {}, consumes them: {}",
+    file_path.display(),
+    is_definition,
+    is_consumer
+  ));
+
+  Ok(match (is_definition, is_consumer) {
+    (true, true) => FileRole::Both,
+    (true, false) => FileRole::Definition,
+    (false, true) => FileRole::Consumer,
+    (false, false) => FileRole::Neither,
+  })
+}
+
+/// Lists how the imports of components rendered in `file_path` resolve, see [`import_resolutions`]
+pub fn import_resolutions_in_file(file_path: &Path) -> Result<Vec<ImportResolution>> {
+  reset_unresolved_imports();
+  let source_text = fs::read_to_string(file_path)?;
+  let allocator = Allocator::default();
+  let source_type = source_type_for_path(file_path);
+
+  let oxc_parser::ParserReturn {
+    program, errors, ..
+  } = oxc_parser::Parser::new(&allocator, &source_text, source_type).parse();
+
+  if !errors.is_empty() {
+    return Err(format!("Failed to parse {}", file_path.display()).into());
+  }
+
+  let semantic_ret = oxc_semantic::SemanticBuilder::new().build(&program);
+  Ok(import_resolutions(&semantic_ret.semantic, file_path))
+}
+
+/// Files whose changes can alter how `file_path` is transformed, for setting up watchers without a
+/// full analysis. For every imported component the file renders, these are the module it is
+/// imported from and the file defining it, followed through the components those files render in
+/// turn. Packages in node_modules are left out.
+pub fn collect_dependencies(file_path: &Path) -> Result<Vec<PathBuf>> {
+  reset_unresolved_imports();
+  let mut dependencies = BTreeSet::new();
+  let mut visited = BTreeSet::from([file_path.to_path_buf()]);
+  let mut pending = vec![file_path.to_path_buf()];
+
+  while let Some(current_file) = pending.pop() {
+    let Some(source_type) = supported_source_type(&current_file) else {
+      continue;
+    };
+    let source_text = fs::read_to_string(&current_file)?;
+    let allocator = Allocator::default();
+
+    let oxc_parser::ParserReturn {
+      program, errors, ..
+    } = oxc_parser::Parser::new(&allocator, &source_text, source_type).parse();
+
+    if !errors.is_empty() {
+      debug(&format!(
+        "⏭️ {} doesn't parse, not following its imports",
+        current_file.display()
+      ));
+      continue;
+    }
+
+    let semantic_ret = oxc_semantic::SemanticBuilder::new().build(&program);
+    let semantic = &semantic_ret.semantic;
+
+    for jsx_component in extract_imported_jsx_components(semantic) {
+      let Ok(origin) = resolve_component_origin(semantic, &jsx_component, &current_file) else {
+        continue;
+      };
+      if origin.is_package {
+        continue;
+      }
+
+      dependencies.insert(PathBuf::from(origin.module_file));
+      if let Some(component_file) = origin.component_file.map(PathBuf::from) {
+        dependencies.insert(component_file.clone());
+        if visited.insert(component_file.clone()) {
+          pending.push(component_file);
+        }
+      }
+    }
+  }
+
+  dependencies.remove(file_path);
+  debug(&format!(
+    "👀 {} depends on {} files",
+    file_path.display(),
+    dependencies.len()
+  ));
+  Ok(dependencies.into_iter().collect())
+}
+
+/// Settles whether the child `call` checks for is rendered by the file `semantic` was built from,
+/// following the components it renders
+fn settle_presence(
+  semantic: &oxc_semantic::Semantic,
+  call: &mut ComponentPresenceCall,
+  conditional_components: &[ConditionalComponent],
+  file_path: &Path,
+  diagnostics: &mut Vec<Diagnostic>,
+) -> Result<()> {
+  let strict_scoping = current_config().strict_scoping;
+  let named_slots = current_config().named_slots;
+
+  // `isComponentPresent([A, B])` is satisfied by any of its alternatives
+  call.found_directly = false;
+  call.presence = Presence::Absent;
+  if strict_scoping && call.resolved_by_module_scan {
+    explanation::note(|| {
+      format!(
+        "{} was only found by scanning its module directory, which strictScoping doesn't trust",
+        call.source_file
+      )
+    });
+    call.presence = Presence::Unknown;
+    return Ok(());
+  }
+  let mut conditional_match = None;
+  for alternative in component_alternatives(&call.component_name) {
+    // An element picked at runtime, e.g. behind a feature flag, can't settle the check
+    if let Some(conditional) = conditional_components
+      .iter()
+      .find(|conditional| conditional.may_render(semantic, alternative, file_path))
+    {
+      conditional_match.get_or_insert(conditional);
+      explanation::note(|| {
+        format!("{} may be rendered through an element type picked at runtime", alternative)
+      });
+      // A mapped element type doesn't share a name with the child, which may still be rendered
+      // elsewhere on the page
+      if conditional.mapped_from.is_none() {
+        continue;
+      }
+    }
+    if let Some(element) = find_jsx_element_for_component(semantic, alternative, file_path) {
+      // A child sent to a named slot the Root doesn't render is never projected
+      if named_slots
+        && component_presence::component_slotted_out(
+          semantic,
+          alternative,
+          &call.source_file,
+          file_path,
+        )?
+      {
+        explanation::note(|| {
+          format!("<{}> is sent to a named slot {} doesn't render", element, call.source_file)
+        });
+        continue;
+      }
+      explanation::note_match(&element, || {
+        format!("<{}> in {} renders {}", element, file_path.display(), alternative)
+      });
+      call.found_directly = true;
+      call.presence = Presence::Present;
+      break;
+    }
+    if call.presence.is_present() {
+      continue;
+    }
+    match component_presence::has_component(semantic, alternative, file_path)? {
+      Presence::Absent => {}
+      presence => call.presence = presence,
+    }
+    // The Root may render the child itself, e.g. Root renders Panel renders Description
+    if !call.presence.is_present()
+      && component_presence::root_renders_component(&call.source_file, alternative)?
+    {
+      explanation::note(|| format!("{} renders {} itself", call.source_file, alternative));
+      call.presence = Presence::Present;
+    }
+  }
+  // Strict scoping only counts children rendered inside the Root that checks for them
+  if strict_scoping && call.found_directly {
+    let mut within_root = false;
+    for alternative in component_alternatives(&call.component_name) {
+      if component_presence::component_within_root(
+        semantic,
+        alternative,
+        &call.source_file,
+        file_path,
+      )? {
+        within_root = true;
+        break;
+      }
+    }
+    if !within_root {
+      diagnostics.extend(unscoped_presence_diagnostic(semantic, call, file_path));
+      call.found_directly = false;
+      call.presence = Presence::Unknown;
+    }
+  }
+  // A Root around a <Slot /> may receive the child from whoever renders this component
+  if call.presence == Presence::Absent
+    && component_presence::root_projects_slot(semantic, &call.source_file, file_path)?
+  {
+    explanation::note(|| {
+      format!(
+        "the Root projects a <Slot />, so whoever renders {} may still supply {}",
+        file_path.display(),
+        call.component_name
+      )
+    });
+    call.presence = Presence::Unknown;
+  }
+  // A package shipping only compiled JS can't be searched past the members it declares
+  if call.presence == Presence::Absent
+    && component_presence::declared_member_may_render(semantic, call, file_path)?
+  {
+    explanation::note(|| {
+      format!(
+        "a compiled package member rendered inside the Root may render {}",
+        call.component_name
+      )
+    });
+    call.presence = Presence::Unknown;
+  }
+  if let Some(conditional) = conditional_match.filter(|_| call.presence == Presence::Absent) {
+    diagnostics.push(conditional_component_diagnostic(call, conditional, file_path));
+    call.presence = Presence::Unknown;
+  }
+  debug(&format!(
+    "📋 Component call '{}' from '{}' -> {} (direct: {})",
+    call.component_name,
+    call.source_file,
+    call.presence.as_str(),
+    call.found_directly
+  ));
+  Ok(())
+}
+
+/// The result for a file left unanalyzed, carrying the `diagnostic` that explains why
+fn skipped_analysis(file_path: &Path, diagnostic: Diagnostic) -> AnalysisResult {
+  AnalysisResult {
+    has_component: false,
+    found_directly: false,
+    file_path: file_path.to_string_lossy().to_string(),
+    dependencies: Vec::new(),
+    transformations: Vec::new(),
+    diagnostics: vec![diagnostic],
+    presence_checks: Vec::new(),
+    injected_props: Vec::new(),
+  }
+}
+
+/// Analyzes `source_text` as the contents of `file_path`. An internal error or panic never fails
+/// the build: the file gets no transformations and a diagnostic explaining why.
+pub fn analyze_code_with_semantics(source_text: &str, file_path: &Path) -> Result<AnalysisResult> {
+  let analysis = panic::catch_unwind(AssertUnwindSafe(|| analyze_code(source_text, file_path)));
+  let reason = match analysis {
+    Ok(Ok(result)) => return Ok(result),
+    Ok(Err(error)) => error.to_string(),
+    Err(payload) => match payload.downcast::<String>() {
+      Ok(message) => format!("panicked: {}", message),
+      Err(payload) => match payload.downcast::<&str>() {
+        Ok(message) => format!("panicked: {}", message),
+        Err(_) => "panicked".to_string(),
+      },
+    },
+  };
+
+  Ok(skipped_analysis(file_path, analysis_failed_diagnostic(&reason, file_path)))
+}
+
+fn analyze_code(source_text: &str, file_path: &Path) -> Result<AnalysisResult> {
+  reset_unresolved_imports();
+  if let Some(max_source_bytes) = current_config().max_source_bytes {
+    if source_text.len() > max_source_bytes {
+      let message = format!(
+        "Skipped analysis: source is {} bytes, larger than the {} byte limit (maxSourceBytes)",
+        source_text.len(),
+        max_source_bytes
+      );
+      return Ok(skipped_analysis(
+        file_path,
+        new_diagnostic(DiagnosticCode::SourceTooLarge, message, file_path, Span::empty(0)),
+      ));
+    }
+  }
+
+  // Parsing e.g. a stylesheet as JavaScript would only produce misleading results
+  let Some(source_type) = supported_source_type(file_path) else {
+    let extension = file_path
+      .extension()
+      .map(|extension| format!(".{}", extension.to_string_lossy()))
+      .unwrap_or_else(|| "extensionless".to_string());
+    let message = format!(
+      "Skipped analysis: unsupported file type, {} files aren't JavaScript or TypeScript (see sourceExtensions)",
+      extension
+    );
+    return Ok(skipped_analysis(
+      file_path,
+      new_diagnostic(DiagnosticCode::UnsupportedFileType, message, file_path, Span::empty(0)),
+    ));
+  };
+
+  let allocator = Allocator::default();
+
+  let oxc_parser::ParserReturn {
+    program, errors, ..
+  } = oxc_parser::Parser::new(&allocator, source_text, source_type).parse();
+
+  if let Some(error) = errors.first() {
+    return Ok(skipped_analysis(
+      file_path,
+      parse_failed_diagnostic(&error.to_string(), errors.len(), file_path),
+    ));
   }
 
+  // Semantic errors such as a name imported twice still leave a usable tree; the ones the
+  // analysis cares about are reported as their own diagnostics, e.g. `QA007`
+  let semantic_ret = oxc_semantic::SemanticBuilder::new().build(&program);
+  let semantic = &semantic_ret.semantic;
+
   let jsx_components = extract_imported_jsx_components(semantic);
   debug(&format!("🔍 Found JSX components: {:?}", jsx_components));
 
+  let manifest = load_manifest(file_path);
+  let strict_scoping = current_config().strict_scoping;
+  let verify_manifest = current_config().verify_manifest;
+
   let mut all_component_calls = Vec::new();
+  let mut manifest_component_calls = Vec::new();
+  let mut diagnostics = Vec::new();
+  // What each rendered element resolved to, summarized once its props are known
+  let mut resolution_decisions = Vec::new();
   for jsx_component in jsx_components {
-    if let Ok(calls) = find_presence_calls(semantic, &jsx_component, file_path) {
-      all_component_calls.extend(calls);
+    resolution_decisions.push((
+      jsx_component.clone(),
+      resolution_decision(semantic, &jsx_component, file_path),
+    ));
+
+    // Roots declared in qwik-analyzer.json don't need their source scanned
+    if let Some(calls) = manifest
+      .as_ref()
+      .and_then(|manifest| manifest.presence_calls_for(semantic, &jsx_component))
+    {
+      if verify_manifest {
+        diagnostics.extend(unused_injected_prop_diagnostics(
+          semantic,
+          &jsx_component,
+          &calls,
+          file_path,
+        ));
+      }
+      manifest_component_calls.extend(calls);
+      continue;
+    }
+
+    let calls = find_presence_calls(semantic, &jsx_component, file_path).unwrap_or_default();
+    diagnostics.extend(jsx_import_diagnostics(
+      semantic,
+      &jsx_component,
+      !calls.is_empty(),
+      file_path,
+    ));
+    if strict_scoping {
+      diagnostics.extend(module_scan_diagnostics(semantic, &jsx_component, &calls, file_path));
     }
+    all_component_calls.extend(calls);
   }
 
+  let conditional_components = component_presence::conditionally_bound_components(semantic);
   for call in &mut all_component_calls {
-    call.is_present_in_subtree =
-      component_presence::has_component(semantic, &call.component_name, file_path)?;
-    debug(&format!(
-      "📋 Component call '{}' from '{}' -> present: {}",
-      call.component_name, call.source_file, call.is_present_in_subtree
-    ));
+    // A dependency that can't be read, e.g. one deleted mid-build, leaves only this check open
+    if let Err(error) = settle_presence(
+      semantic,
+      call,
+      &conditional_components,
+      file_path,
+      &mut diagnostics,
+    ) {
+      diagnostics.push(unreadable_dependency_diagnostic(call, &error.to_string(), file_path));
+      call.found_directly = false;
+      call.presence = Presence::Unknown;
+    }
+  }
+
+  all_component_calls.extend(manifest_component_calls);
+
+  // Children provided at runtime, e.g. through a context, are opted in by configuration
+  let config = current_config();
+  let assume_present = &config.assume_present;
+  for call in &mut all_component_calls {
+    if component_alternatives(&call.component_name)
+      .any(|alternative| assume_present.iter().any(|name| name == alternative))
+    {
+      debug(&format!(
+        "📌 {} from {} is configured as always present",
+        call.component_name, call.source_file
+      ));
+      call.presence = Presence::Present;
+    }
   }
 
   debug(&format!("📊 Analysis found {} isComponentPresent calls from imported components, {} have target components in current file",
              all_component_calls.len(),
-             all_component_calls.iter().filter(|c| c.is_present_in_subtree).count()));
+             all_component_calls.iter().filter(|c| c.presence.is_present()).count()));
 
   let mut transformations = Vec::new();
+  let mut injected_props = Vec::new();
   let mut has_any_component = false;
 
   for call in &all_component_calls {
-    if call.is_present_in_subtree {
+    if call.presence.is_present() {
       has_any_component = true;
     }
   }
 
   // Apply JSX prop transformations for all component calls (both true and false)
   if !all_component_calls.is_empty() {
-    let current_file_transformations = transform_file(semantic, &all_component_calls, file_path)?;
+    let (current_file_transformations, current_file_props) = transform_file(
+      semantic,
+      &all_component_calls,
+      manifest.as_ref(),
+      file_path,
+    )?;
     transformations.extend(current_file_transformations);
+    injected_props.extend(current_file_props);
+  }
+
+  for (element, decision) in &resolution_decisions {
+    let injected: Vec<String> = injected_props
+      .iter()
+      .filter(|prop| &prop.element == element)
+      .map(|prop| format!("{}={}", prop.prop_name, prop.value))
+      .collect();
+    debug(&format!(
+      "🧾 element={} {} injected=[{}]",
+      element,
+      decision,
+      injected.join(",")
+    ));
   }
 
-  let current_file_component_transformations = transform_components(semantic, file_path)?;
+  diagnostics.extend(presence_argument_diagnostics(semantic, source_text, file_path));
+  diagnostics.extend(duplicate_presence_diagnostics(semantic, file_path));
+  diagnostics.extend(ambiguous_import_diagnostics(semantic, file_path));
+
+  let current_file_component_transformations = transform_components(semantic, source_text, file_path)?;
   transformations.extend(current_file_component_transformations);
 
+  debug(&format!(
+    "♻️ {} import resolutions served from the negative cache",
+    reset_unresolved_imports()
+  ));
+
+  let mut presence_checks: Vec<PresenceCheck> = Vec::new();
+  for call in &all_component_calls {
+    let (component, alternatives) = split_alternatives(&call.component_name);
+    let is_repeated = presence_checks.iter().any(|check| {
+      check.component == component
+        && check.alternatives == alternatives
+        && check.source_file == call.source_file
+    });
+    if !is_repeated {
+      presence_checks.push(PresenceCheck {
+        component,
+        alternatives,
+        source_file: call.source_file.clone(),
+        presence: call.presence.as_str().to_string(),
+      });
+    }
+  }
+
   Ok(AnalysisResult {
     has_component: has_any_component,
+    found_directly: all_component_calls.iter().any(|call| call.found_directly),
     file_path: file_path.to_string_lossy().to_string(),
     dependencies: Vec::new(),
     transformations,
+    diagnostics,
+    presence_checks,
+    injected_props,
   })
 }
@@ -0,0 +1,148 @@
+/// Unchanged lines shown around each change, as in `diff -u`
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DiffLine<'a> {
+  Same(&'a str),
+  Removed(&'a str),
+  Added(&'a str),
+}
+
+/// A unified diff turning `old` into `new`, labelled with `file_path`. Empty when the two are the
+/// same line for line.
+pub fn unified_diff(old: &str, new: &str, file_path: &str) -> String {
+  let old_lines: Vec<&str> = old.lines().collect();
+  let new_lines: Vec<&str> = new.lines().collect();
+  let script = diff_lines(&old_lines, &new_lines);
+
+  let changes: Vec<usize> = script
+    .iter()
+    .enumerate()
+    .filter(|(_, line)| !matches!(line, DiffLine::Same(_)))
+    .map(|(index, _)| index)
+    .collect();
+  if changes.is_empty() {
+    return String::new();
+  }
+
+  // Old and new line numbers reached before each entry of the script
+  let mut positions = Vec::with_capacity(script.len() + 1);
+  let (mut old_line, mut new_line) = (0, 0);
+  for line in &script {
+    positions.push((old_line, new_line));
+    match line {
+      DiffLine::Same(_) => {
+        old_line += 1;
+        new_line += 1;
+      }
+      DiffLine::Removed(_) => old_line += 1,
+      DiffLine::Added(_) => new_line += 1,
+    }
+  }
+  positions.push((old_line, new_line));
+
+  let mut diff = format!("--- {}\n+++ {}\n", file_path, file_path);
+  let mut first = 0;
+  while first < changes.len() {
+    // Changes whose context would touch or overlap share a hunk
+    let mut last = first;
+    while last + 1 < changes.len() && changes[last + 1] - changes[last] <= 2 * CONTEXT_LINES + 1 {
+      last += 1;
+    }
+
+    let start = changes[first].saturating_sub(CONTEXT_LINES);
+    let end = (changes[last] + CONTEXT_LINES + 1).min(script.len());
+    let (old_start, new_start) = positions[start];
+    let (old_end, new_end) = positions[end];
+    diff.push_str(&format!(
+      "@@ -{} +{} @@\n",
+      hunk_range(old_start, old_end - old_start),
+      hunk_range(new_start, new_end - new_start)
+    ));
+    for line in &script[start..end] {
+      let (prefix, text) = match line {
+        DiffLine::Same(text) => (' ', text),
+        DiffLine::Removed(text) => ('-', text),
+        DiffLine::Added(text) => ('+', text),
+      };
+      diff.push(prefix);
+      diff.push_str(text);
+      diff.push('\n');
+    }
+
+    first = last + 1;
+  }
+
+  diff
+}
+
+/// `start,count` of a hunk header, with 1-based lines. An empty range names the line before it.
+fn hunk_range(start: usize, count: usize) -> String {
+  if count == 0 {
+    format!("{},0", start)
+  } else {
+    format!("{},{}", start + 1, count)
+  }
+}
+
+/// Shortest line edit script from `old` to `new`, using Myers' O(ND) algorithm. Transformations
+/// only touch a few lines, so D stays small even for large files.
+fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<DiffLine<'a>> {
+  let (n, m) = (old.len() as isize, new.len() as isize);
+  let offset = n + m + 1;
+  let index = |k: isize| (k + offset) as usize;
+  let mut furthest = vec![0isize; 2 * offset as usize + 1];
+  let mut trace = Vec::new();
+
+  'search: for d in 0..=(n + m) {
+    trace.push(furthest.clone());
+    for k in (-d..=d).step_by(2) {
+      let mut x = if k == -d || (k != d && furthest[index(k - 1)] < furthest[index(k + 1)]) {
+        furthest[index(k + 1)]
+      } else {
+        furthest[index(k - 1)] + 1
+      };
+      let mut y = x - k;
+      while x < n && y < m && old[x as usize] == new[y as usize] {
+        x += 1;
+        y += 1;
+      }
+      furthest[index(k)] = x;
+      if x >= n && y >= m {
+        break 'search;
+      }
+    }
+  }
+
+  let mut script = Vec::new();
+  let (mut x, mut y) = (n, m);
+  for (d, furthest) in trace.iter().enumerate().rev() {
+    let d = d as isize;
+    let k = x - y;
+    let previous_k = if k == -d || (k != d && furthest[index(k - 1)] < furthest[index(k + 1)]) {
+      k + 1
+    } else {
+      k - 1
+    };
+    let previous_x = furthest[index(previous_k)];
+    let previous_y = previous_x - previous_k;
+
+    while x > previous_x && y > previous_y {
+      script.push(DiffLine::Same(old[x as usize - 1]));
+      x -= 1;
+      y -= 1;
+    }
+    if d > 0 {
+      if x == previous_x {
+        script.push(DiffLine::Added(new[y as usize - 1]));
+      } else {
+        script.push(DiffLine::Removed(old[x as usize - 1]));
+      }
+    }
+    x = previous_x;
+    y = previous_y;
+  }
+
+  script.reverse();
+  script
+}
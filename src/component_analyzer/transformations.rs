@@ -1,43 +1,112 @@
+use oxc_ast::ast::{
+  Argument, ArrowFunctionExpression, BindingPatternKind, JSXAttribute, JSXAttributeItem,
+  JSXAttributeName, JSXChild, JSXElement, JSXElementName, JSXExpression, JSXOpeningElement,
+};
 use oxc_ast::AstKind;
-use oxc_semantic::Semantic;
+use oxc_semantic::{NodeId, Semantic};
 use oxc_span::GetSpan;
+use std::collections::HashMap;
 use std::path::Path;
 
+use crate::component_analyzer::config::{
+  current_config, AnalyzerConfig, ExistingPropStrategy, InjectionPosition, PropsType,
+};
+use crate::component_analyzer::component_presence::{component_within_root, root_renders_component};
 use crate::component_analyzer::jsx_analysis::extract_jsx_element_name;
-use crate::component_analyzer::import_resolver::{find_import_source_for_component, resolve_import_path, resolve_component_from_index};
+use crate::component_analyzer::manifest::{is_manifest_source, Manifest};
+use crate::component_analyzer::import_resolver::{
+  find_calls_in_component, find_import_source_for_component, local_part_binding, module_index_file,
+  presence_target_name, resolve_import_path, resolve_imported_component_file,
+  resolve_namespace_member,
+};
 use crate::component_analyzer::utils::{
-  debug, extract_component_name_from_argument, extract_function_name, ComponentPresenceCall,
+  component_alternatives, debug, extract_function_name, injected_prop_name, is_presence_call, prop_access, same_file, source_type_for_path,
+  split_alternatives, ComponentPresenceCall, Presence,
 };
-use crate::{Result, Transformation};
+use crate::{InjectedProp, Result, Transformation};
 
 pub fn transform_file(
   semantic: &Semantic,
   component_calls: &Vec<ComponentPresenceCall>,
+  manifest: Option<&Manifest>,
   current_file: &Path,
-) -> Result<Vec<Transformation>> {
-  let mut transformations = Vec::new();
+) -> Result<(Vec<Transformation>, Vec<InjectedProp>)> {
+  if !current_config().transform_side.transforms_consumers() {
+    return Ok((Vec::new(), Vec::new()));
+  }
+
+  let mut injections = Vec::new();
+  let mut seen_calls = Vec::new();
+  let inject_only_when_present = current_config().inject_only_when_present;
 
   for call in component_calls {
+    // Roots are identified by their source file; leave one alone when every check it makes is false
+    if inject_only_when_present
+      && !component_calls
+        .iter()
+        .any(|other| other.source_file == call.source_file && other.presence.is_present())
+    {
+      debug(&format!(
+        "⏭️ Nothing checked by {} is present, skipping injection",
+        call.source_file
+      ));
+      continue;
+    }
+
+    // Without a prop the Root's isComponentPresent call falls back to its runtime value
+    if call.presence == Presence::Unknown {
+      debug(&format!(
+        "❔ Presence of {} for {} is unknown, skipping injection",
+        call.component_name, call.source_file
+      ));
+      continue;
+    }
+
+    // A Root checking for the same component twice still gets a single prop
+    let call_key = (&call.component_name, &call.source_file);
+    if seen_calls.contains(&call_key) {
+      continue;
+    }
+    seen_calls.push(call_key);
+
     // Generate JSX props for all calls, not just the ones that are present
-    let current_file_transformations = generate_jsx_prop_transformations(semantic, &call, current_file)?;
-    transformations.extend(current_file_transformations);
+    injections.extend(generate_jsx_prop_transformations(
+      semantic,
+      call,
+      manifest,
+      current_file,
+    )?);
   }
 
-  Ok(transformations)
+  // Props sharing an element come out sorted by name rather than in the Root's check order, so
+  // the output only changes when the checks themselves do
+  injections.sort_by(|(transformation, prop), (other_transformation, other_prop)| {
+    transformation
+      .start
+      .cmp(&other_transformation.start)
+      .then_with(|| prop.prop_name.cmp(&other_prop.prop_name))
+  });
+
+  Ok(injections.into_iter().unzip())
 }
 
 fn generate_jsx_prop_transformations(
   semantic: &Semantic,
   call: &ComponentPresenceCall,
+  manifest: Option<&Manifest>,
   current_file: &Path,
-) -> Result<Vec<Transformation>> {
-  let mut transformations = Vec::new();
+) -> Result<Vec<(Transformation, InjectedProp)>> {
+  let mut injections = Vec::new();
+  // Every usage gets its own prop, but an element name such as `CB.Root` is only resolved once.
+  // Aliases of one namespace are distinct names that each resolve to the same file.
+  let mut receives_prop: HashMap<String, bool> = HashMap::new();
 
   debug(&format!(
     "🔍 Looking for JSX component corresponding to source file: {}",
     call.source_file
   ));
 
+  let mut roots = Vec::new();
   for node in semantic.nodes().iter() {
     let AstKind::JSXOpeningElement(jsx_opening) = node.kind() else {
       continue;
@@ -52,11 +121,15 @@ fn generate_jsx_prop_transformations(
       element_name, call.source_file
     ));
 
-    if !jsx_element_resolves_to_source_file(semantic, &element_name, &call.source_file, current_file)? {
-      debug(&format!(
-        "❌ JSX element {} does NOT resolve to source file {}",
-        element_name, call.source_file
-      ));
+    let receives = match receives_prop.get(&element_name) {
+      Some(&receives) => receives,
+      None => {
+        let receives = element_receives_prop(semantic, &element_name, call, manifest, current_file)?;
+        receives_prop.insert(element_name.clone(), receives);
+        receives
+      }
+    };
+    if !receives {
       continue;
     }
 
@@ -64,31 +137,291 @@ fn generate_jsx_prop_transformations(
       "✅ JSX element {} SHOULD receive props for source file {}",
       element_name, call.source_file
     ));
+    roots.push((node.id(), jsx_opening, element_name));
+  }
+
+  let scoped_per_root = presence_scoped_per_root(semantic, call, roots.len(), current_file)?;
+  let empty_root_settles = call.presence != Presence::Absent
+    && !roots.is_empty()
+    && !is_manifest_source(&call.source_file)
+    && !root_renders_child(call);
 
+  let config = current_config();
+  for (node_id, jsx_opening, element_name) in roots {
     debug(&format!(
       "🔧 Adding prop to JSX component: {}",
       element_name
     ));
 
-    let prop_name = format!(
-      "__qwik_analyzer_has_{}",
-      call.component_name.replace(".", "_")
+    let presence = match semantic.nodes().parent_kind(node_id) {
+      // A Root given nothing to project can't have the child, wherever else the page renders it
+      Some(AstKind::JSXElement(jsx_element))
+        if empty_root_settles && !receives_children(jsx_element) =>
+      {
+        Presence::Absent
+      }
+      // Another Root on the page renders the child; this one's children can't
+      Some(AstKind::JSXElement(jsx_element))
+        if scoped_per_root
+          && !passes_children_attribute(jsx_opening)
+          && children_render_no_components(&jsx_element.children) =>
+      {
+        Presence::Absent
+      }
+      _ => call.presence,
+    };
+
+    let prop_name = injected_prop_name(&call.component_name, &call.source_file);
+    let prop_value = presence.is_present();
+    let prop_code = config.annotate(
+      prop_value.to_string(),
+      &format!("{} {}", call.component_name, presence.as_str()),
     );
-    let prop_value = call.is_present_in_subtree;
-    let new_prop = format!(" {}={{{}}}", prop_name, prop_value);
-    let insert_pos = jsx_opening.span.end - 1;
+    let (component, alternatives) = split_alternatives(&call.component_name);
+    let injected_prop = InjectedProp {
+      start: jsx_opening.span.start,
+      end: jsx_opening.span.end,
+      element: element_name.clone(),
+      component,
+      alternatives,
+      prop_name: prop_name.clone(),
+      value: prop_value,
+    };
+
+    if let Some(existing_attr) = find_jsx_attribute(jsx_opening, &prop_name) {
+      match config.existing_props {
+        ExistingPropStrategy::Respect => {
+          debug(&format!(
+            "⏭️ {} already has {}, keeping the user-supplied value",
+            element_name, prop_name
+          ));
+        }
+        ExistingPropStrategy::Override => {
+          debug(&format!(
+            "🔧 Overriding user-supplied {} on {} with {}",
+            prop_name, element_name, prop_value
+          ));
+          let transformation = Transformation {
+            start: existing_attr.span.start,
+            end: existing_attr.span.end,
+            replacement: format!("{}={{{}}}", prop_name, prop_code),
+          };
+          injections.push((transformation, injected_prop));
+        }
+      }
+      continue;
+    }
 
-    transformations.push(Transformation {
+    let new_prop = format!(" {}={{{}}}", prop_name, prop_code);
+    let insert_pos = match config.injection_position {
+      InjectionPosition::First => tag_name_end(jsx_opening),
+      InjectionPosition::Last if is_self_closing(semantic, node_id) => {
+        last_tag_item_end(jsx_opening)
+      }
+      // Spans are byte offsets into the original source, `\r` included, so the closing `>` is
+      // found the same way for CRLF and LF files
+      InjectionPosition::Last => jsx_opening.span.end - 1,
+    };
+
+    let transformation = Transformation {
       start: insert_pos,
       end: insert_pos,
       replacement: new_prop,
-    });
+    };
+    injections.push((transformation, injected_prop));
   }
 
-  Ok(transformations)
+  Ok(injections)
 }
 
-fn jsx_element_resolves_to_source_file(
+/// Whether the presence of `call`, settled by the child rendered inside one of the page's
+/// `root_count` Roots, should be told apart per Root
+fn presence_scoped_per_root(
+  semantic: &Semantic,
+  call: &ComponentPresenceCall,
+  root_count: usize,
+  current_file: &Path,
+) -> Result<bool> {
+  if root_count < 2
+    || !call.found_directly
+    || !call.presence.is_present()
+    || is_manifest_source(&call.source_file)
+  {
+    return Ok(false);
+  }
+
+  // A Root rendering the child itself has it whatever its children are
+  if root_renders_child(call) {
+    return Ok(false);
+  }
+  for alternative in component_alternatives(&call.component_name) {
+    if component_within_root(semantic, alternative, &call.source_file, current_file)? {
+      return Ok(true);
+    }
+  }
+
+  Ok(false)
+}
+
+/// Whether the Root making `call` renders one of the components it checks for itself. A Root
+/// whose definition can't be searched, e.g. because a component it renders can't be read, is
+/// taken to render it.
+fn root_renders_child(call: &ComponentPresenceCall) -> bool {
+  component_alternatives(&call.component_name).any(|alternative| {
+    root_renders_component(&call.source_file, alternative).unwrap_or_else(|error| {
+      debug(&format!(
+        "⚠️ Can't tell whether {} renders {} itself: {}",
+        call.source_file, alternative, error
+      ));
+      true
+    })
+  })
+}
+
+/// Whether `jsx_element` is given children to project, either between its tags or through a
+/// `children` or spread attribute. Whitespace and `{/* comments */}` between the tags don't count.
+fn receives_children(jsx_element: &JSXElement) -> bool {
+  if passes_children_attribute(&jsx_element.opening_element) {
+    return true;
+  }
+
+  jsx_element.children.iter().any(|child| match child {
+    JSXChild::Text(text) => !text.value.trim().is_empty(),
+    JSXChild::ExpressionContainer(container) => {
+      !matches!(container.expression, JSXExpression::EmptyExpression(_))
+    }
+    _ => true,
+  })
+}
+
+/// Whether `children` hold nothing but text, HTML elements and empty or literal expressions, so
+/// they can't render a component
+fn children_render_no_components(children: &[JSXChild]) -> bool {
+  children.iter().all(|child| match child {
+    JSXChild::Text(_) => true,
+    JSXChild::Element(jsx_element) => {
+      matches!(
+        jsx_element.opening_element.name,
+        JSXElementName::Identifier(_) | JSXElementName::NamespacedName(_)
+      ) && children_render_no_components(&jsx_element.children)
+    }
+    JSXChild::Fragment(jsx_fragment) => children_render_no_components(&jsx_fragment.children),
+    JSXChild::ExpressionContainer(container) => matches!(
+      container.expression,
+      JSXExpression::EmptyExpression(_)
+        | JSXExpression::StringLiteral(_)
+        | JSXExpression::NumericLiteral(_)
+    ),
+    JSXChild::Spread(_) => false,
+  })
+}
+
+/// Whether `jsx_opening` may pass children through its attributes, with `children={...}` or a
+/// spread such as `{...rootProps}`
+fn passes_children_attribute(jsx_opening: &JSXOpeningElement) -> bool {
+  find_jsx_attribute(jsx_opening, "children").is_some()
+    || jsx_opening
+      .attributes
+      .iter()
+      .any(|item| matches!(item, JSXAttributeItem::SpreadAttribute(_)))
+}
+
+/// Whether JSX elements named `element_name` render the Root that makes `call`
+fn element_receives_prop(
+  semantic: &Semantic,
+  element_name: &str,
+  call: &ComponentPresenceCall,
+  manifest: Option<&Manifest>,
+  current_file: &Path,
+) -> Result<bool> {
+  let resolves_to_source = if is_manifest_source(&call.source_file) {
+    manifest.is_some_and(|manifest| {
+      manifest.jsx_element_matches_source(semantic, element_name, &call.source_file)
+    })
+  } else {
+    jsx_element_resolves_to_source_file(semantic, element_name, &call.source_file, current_file)?
+  };
+
+  if !resolves_to_source {
+    debug(&format!(
+      "❌ JSX element {} does NOT resolve to source file {}",
+      element_name, call.source_file
+    ));
+    return Ok(false);
+  }
+
+  // Every part of a single-file compound component resolves to the same file, so only the part
+  // that makes the call receives its prop
+  if !is_manifest_source(&call.source_file) && !element_makes_call(element_name, call)? {
+    debug(&format!(
+      "❌ JSX element {} shares {} but doesn't check for {}",
+      element_name, call.source_file, call.component_name
+    ));
+    return Ok(false);
+  }
+
+  Ok(true)
+}
+
+/// Whether the part `element_name` names makes `call`, when the part is defined in a single-file
+/// compound component. Elements of any other shape are taken to make the calls of their file.
+fn element_makes_call(element_name: &str, call: &ComponentPresenceCall) -> Result<bool> {
+  let Some((_, part)) = element_name.split_once('.') else {
+    return Ok(true);
+  };
+  let Some(binding) = local_part_binding(&call.source_file, part)? else {
+    return Ok(true);
+  };
+
+  Ok(
+    find_calls_in_component(&call.source_file, &binding)?
+      .iter()
+      .any(|part_call| part_call.component_name == call.component_name),
+  )
+}
+
+/// Whether the opening element `node_id` is a self-closing tag such as `<Kit.Root/>`
+fn is_self_closing(semantic: &Semantic, node_id: NodeId) -> bool {
+  matches!(
+    semantic.nodes().parent_kind(node_id),
+    Some(AstKind::JSXElement(element)) if element.closing_element.is_none()
+  )
+}
+
+/// End of the last attribute, type argument list or name in an opening tag. For self-closing
+/// tags, the `/` before the `>` may come right after it, as in minified `<Kit.Root a={1}/>`.
+fn last_tag_item_end(jsx_opening: &JSXOpeningElement) -> u32 {
+  if let Some(attribute) = jsx_opening.attributes.last() {
+    return attribute.span().end;
+  }
+  tag_name_end(jsx_opening)
+}
+
+/// End of the element name, or of its type arguments in a generic `<Root<Props>`
+fn tag_name_end(jsx_opening: &JSXOpeningElement) -> u32 {
+  jsx_opening
+    .type_arguments
+    .as_ref()
+    .map_or(jsx_opening.name.span().end, |type_arguments| type_arguments.span.end)
+}
+
+fn find_jsx_attribute<'a>(
+  jsx_opening: &'a JSXOpeningElement<'a>,
+  attribute_name: &str,
+) -> Option<&'a JSXAttribute<'a>> {
+  jsx_opening.attributes.iter().find_map(|item| {
+    let JSXAttributeItem::Attribute(attr) = item else {
+      return None;
+    };
+    match &attr.name {
+      JSXAttributeName::Identifier(ident) if ident.name == attribute_name => Some(&**attr),
+      _ => None,
+    }
+  })
+}
+
+/// Whether the JSX element `element_name` renders the component defined in `target_source_file`
+pub fn jsx_element_resolves_to_source_file(
   semantic: &Semantic,
   element_name: &str,
   target_source_file: &str,
@@ -105,43 +438,29 @@ fn jsx_element_resolves_to_source_file(
     let component_name = parts[1];
 
     // Find the import source for the module
-    let Some(import_source) = find_import_source_for_component(semantic, module_name) else {
+    let Some(import_binding) = find_import_source_for_component(semantic, module_name) else {
       return Ok(false);
     };
 
     // Use the passed current_file for import resolution
 
     // Resolve the import path to get the module directory
-    let module_path = match resolve_import_path(&import_source, current_file) {
+    let module_path = match resolve_import_path(&import_binding.source, current_file) {
       Ok(path) => path,
       Err(_) => {
         debug(&format!(
           "❌ Could not resolve import path for {} - skipping external package",
-          import_source
+          import_binding.source
         ));
         return Ok(false);
       }
     };
 
     // Find the index file in the module directory
-    let module_path_obj = Path::new(&module_path);
-    let index_file = if module_path_obj.is_file() {
-      module_path.clone()
-    } else {
-      // Look for index.ts or index.tsx in the directory
-      let index_ts = module_path_obj.join("index.ts");
-      let index_tsx = module_path_obj.join("index.tsx");
-      if index_ts.exists() {
-        index_ts.to_string_lossy().to_string()
-      } else if index_tsx.exists() {
-        index_tsx.to_string_lossy().to_string()
-      } else {
-        module_path.clone() // Fallback to original behavior
-      }
-    };
+    let index_file = module_index_file(&module_path);
 
     // Use oxc semantic to analyze the index file and find the export for this component
-    if let Ok(component_file) = resolve_component_from_index(&index_file, component_name) {
+    if let Ok(component_file) = resolve_namespace_member(&import_binding, &index_file, component_name) {
       debug(&format!(
         "🔍 Resolved JSX component {} to file: {}",
         element_name, component_file
@@ -152,29 +471,12 @@ fn jsx_element_resolves_to_source_file(
       ));
       
       // Compare the resolved component file with the target source file
-      let component_path = Path::new(&component_file);
-      let target_path = Path::new(target_source_file);
-      
-      // Use canonical paths if possible, otherwise compare as strings
-      match (component_path.canonicalize(), target_path.canonicalize()) {
-        (Ok(comp_canonical), Ok(target_canonical)) => {
-          let matches = comp_canonical == target_canonical;
-          debug(&format!(
-            "🔍 Canonical path comparison: {} == {} -> {}",
-            comp_canonical.display(), target_canonical.display(), matches
-          ));
-          return Ok(matches);
-        }
-        _ => {
-          // Fallback to string comparison
-          let matches = component_file == target_source_file;
-          debug(&format!(
-            "🔍 String comparison fallback: {} == {} -> {}",
-            component_file, target_source_file, matches
-          ));
-          return Ok(matches);
-        }
-      }
+      let matches = same_file(&component_file, target_source_file);
+      debug(&format!(
+        "🔍 Path comparison: {} == {} -> {}",
+        component_file, target_source_file, matches
+      ));
+      return Ok(matches);
     } else {
       debug(&format!(
         "🔍 Could not find component file for {} in module {}",
@@ -183,185 +485,446 @@ fn jsx_element_resolves_to_source_file(
     }
   } else {
     // For simple components, try to resolve directly
-    let Some(import_source) = find_import_source_for_component(semantic, element_name) else {
+    let Some(import_binding) = find_import_source_for_component(semantic, element_name) else {
       return Ok(false);
     };
 
     // Use the passed current_file for import resolution
 
-    if let Ok(resolved_path) = resolve_import_path(&import_source, current_file) {
+    if let Ok(resolved_path) = resolve_import_path(&import_binding.source, current_file) {
+      let resolved_path = resolve_imported_component_file(semantic, element_name, resolved_path);
       debug(&format!(
         "🔍 Resolved JSX component {} to file: {}",
         element_name, resolved_path
       ));
-      
-      let resolved_path_obj = Path::new(&resolved_path);
-      let target_path = Path::new(target_source_file);
-      
-      match (resolved_path_obj.canonicalize(), target_path.canonicalize()) {
-        (Ok(resolved_canonical), Ok(target_canonical)) => {
-          let matches = resolved_canonical == target_canonical;
-          debug(&format!(
-            "🔍 Path comparison: {} == {} -> {}",
-            resolved_canonical.display(), target_canonical.display(), matches
-          ));
-          return Ok(matches);
-        }
-        _ => {
-          let matches = resolved_path == target_source_file;
-          debug(&format!(
-            "🔍 String comparison: {} == {} -> {}",
-            resolved_path, target_source_file, matches
-          ));
-          return Ok(matches);
-        }
-      }
+
+      let matches = same_file(&resolved_path, target_source_file);
+      debug(&format!(
+        "🔍 Path comparison: {} == {} -> {}",
+        resolved_path, target_source_file, matches
+      ));
+      return Ok(matches);
     }
   }
 
   Ok(false)
 }
 
-pub fn transform_components(semantic: &Semantic, file_path: &Path) -> Result<Vec<Transformation>> {
-  if !has_component_present_calls(semantic) {
+/// Transforms a component definition: adds a props parameter when needed and forwards the injected
+/// presence props into each `isComponentPresent` call.
+/// All spans are computed against `source_text`, the exact code the semantic was built from.
+pub fn transform_components(
+  semantic: &Semantic,
+  source_text: &str,
+  file_path: &Path,
+) -> Result<Vec<Transformation>> {
+  if !current_config().transform_side.transforms_definitions()
+    || !has_component_present_calls(semantic, file_path)
+  {
     return Ok(Vec::new());
   }
 
-  let source_text = std::fs::read_to_string(file_path)?;
   let mut transformations = Vec::new();
+  let mut call_transformations = Vec::new();
+  let mut component_arrows: Vec<(&ArrowFunctionExpression, PropsAccess)> = Vec::new();
+  let config = current_config();
 
-  if let Some(transformation) =
-    create_props_parameter_transformation(semantic, &source_text, file_path)?
-  {
-    transformations.push(transformation);
+  for node in semantic.nodes().iter() {
+    let AstKind::CallExpression(call_expr) = node.kind() else {
+      continue;
+    };
+
+    if !is_presence_call(semantic, call_expr, file_path) {
+      continue;
+    }
+
+    let Some(first_arg) = call_expr.arguments.first() else {
+      debug(&format!(
+        "⏭️ isComponentPresent() without arguments in {}, leaving it untouched",
+        file_path.display()
+      ));
+      continue;
+    };
+
+    // Calls without a component argument are reported as diagnostics and left untouched
+    let Some(component_name) = presence_target_name(semantic, first_arg, file_path) else {
+      continue;
+    };
+
+    // The call may sit directly in the render body or in a nested closure such as useTask$
+    let Some(component_arrow) = enclosing_component_arrow(semantic, node.id()) else {
+      debug(&format!(
+        "⏭️ isComponentPresent({}) in {} is not inside a component$, leaving it untouched",
+        component_name,
+        file_path.display()
+      ));
+      continue;
+    };
+
+    let Some(props_access) = props_access(component_arrow, source_text) else {
+      debug(&format!(
+        "⏭️ The props of the component calling isComponentPresent({}) in {} can't be read, leaving it untouched",
+        component_name,
+        file_path.display()
+      ));
+      continue;
+    };
+    let props = match &props_access {
+      PropsAccess::Binding(name) | PropsAccess::RestParameter(name) => name.as_str(),
+      PropsAccess::AddParameter | PropsAccess::AddRest { .. } => config.props_binding(),
+    };
+    if !reads_props_binding(semantic, node.id(), props, &props_access, component_arrow) {
+      debug(&format!(
+        "⏭️ `{}` at isComponentPresent({}) in {} isn't the component's props, leaving it untouched",
+        props,
+        component_name,
+        file_path.display()
+      ));
+      continue;
+    }
+
+    if !component_arrows
+      .iter()
+      .any(|(arrow, _)| arrow.span == component_arrow.span)
+    {
+      component_arrows.push((component_arrow, props_access.clone()));
+    }
+
+    let arg_span = first_arg.span();
+    let arg_text = &source_text[arg_span.start as usize..arg_span.end as usize];
+    let prop_name = injected_prop_name(&component_name, &file_path.to_string_lossy());
+    let props_expression = match &props_access {
+      PropsAccess::RestParameter(name) => format!("{}[0]", name),
+      _ => props.to_string(),
+    };
+    // `presenceCallTemplate` shapes `isComponentPresent` calls only; the hook always takes the
+    // initial value as its second argument
+    let rewritten_call = match extract_function_name(call_expr).as_deref() {
+      Some("usePresence") => {
+        format!("usePresence({}, {})", arg_text, prop_access(&props_expression, &prop_name))
+      }
+      _ => config.presence_call(&props_expression, arg_text, &prop_name),
+    };
+    let new_call = config.annotate(
+      rewritten_call,
+      &format!("{} presence comes from the {} prop", component_name, prop_name),
+    );
+
+    call_transformations.push(Transformation {
+      start: call_expr.span.start,
+      end: call_expr.span.end,
+      replacement: new_call,
+    });
+
+    debug(&format!(
+      "🔧 Transforming isComponentPresent({}) call in {}",
+      component_name,
+      file_path.display()
+    ));
   }
 
-  transformations.extend(create_component_present_call_transformations(
-    semantic, file_path,
-  )?);
+  for (component_arrow, props_access) in component_arrows {
+    let transformation = match props_access {
+      PropsAccess::Binding(_) | PropsAccess::RestParameter(_) => None,
+      PropsAccess::AddParameter => create_props_parameter_transformation(
+        semantic,
+        component_arrow,
+        source_text,
+        &config,
+        file_path,
+      ),
+      PropsAccess::AddRest {
+        insert_pos,
+        separator,
+      } => Some(create_props_rest_transformation(
+        insert_pos,
+        separator,
+        &config,
+        file_path,
+      )),
+    };
+    transformations.extend(transformation);
+  }
+
+  transformations.extend(call_transformations);
 
   Ok(transformations)
 }
 
-fn has_component_present_calls(semantic: &Semantic) -> bool {
+pub fn has_component_present_calls(semantic: &Semantic, file_path: &Path) -> bool {
   for node in semantic.nodes().iter() {
     let AstKind::CallExpression(call_expr) = node.kind() else {
       continue;
     };
 
-    let Some(function_name) = extract_function_name(call_expr) else {
+    if is_presence_call(semantic, call_expr, file_path) {
+      return true;
+    }
+  }
+
+  false
+}
+
+/// Finds the arrow function passed to `component$` that encloses `node_id`, looking through nested
+/// closures such as `useTask$(() => ...)` or `useComputed$(() => ...)`
+pub fn enclosing_component_arrow<'a>(
+  semantic: &Semantic<'a>,
+  node_id: NodeId,
+) -> Option<&'a ArrowFunctionExpression<'a>> {
+  let nodes = semantic.nodes();
+
+  for ancestor_id in nodes.ancestor_ids(node_id) {
+    let AstKind::ArrowFunctionExpression(arrow_fn) = nodes.kind(ancestor_id) else {
       continue;
     };
 
-    if function_name == "isComponentPresent" {
-      return true;
+    let call_expr = nodes.ancestor_ids(ancestor_id).skip(1).find_map(|id| match nodes.kind(id) {
+      AstKind::CallExpression(call_expr) => Some(Some(call_expr)),
+      AstKind::Argument(_) => None,
+      _ => Some(None),
+    })??;
+
+    let is_component_call = extract_function_name(call_expr).as_deref() == Some("component$");
+    let is_first_argument = matches!(
+      call_expr.arguments.first(),
+      Some(Argument::ArrowFunctionExpression(first_arrow)) if first_arrow.span == arrow_fn.span
+    );
+
+    if is_component_call && is_first_argument {
+      return Some(arrow_fn);
     }
   }
 
-  false
+  None
 }
 
-fn create_props_parameter_transformation(
-  semantic: &Semantic,
-  source_text: &str,
-  file_path: &Path,
-) -> Result<Option<Transformation>> {
-  let Some((component_start, component_has_props)) = find_component_info(semantic) else {
-    return Ok(None);
+/// Where a component reads the presence props injected into it
+#[derive(Debug, Clone)]
+enum PropsAccess {
+  /// A binding the component already declares, e.g. `(props)` or the rest of `({ a, ...rest })`
+  Binding(String),
+  /// A rest parameter such as `(...args)`, whose first element is the props
+  RestParameter(String),
+  /// The component takes no parameter, so `props_parameter` is added
+  AddParameter,
+  /// The props are destructured without a rest element, e.g. `({ label })`, so one binding the
+  /// remaining props is inserted at `insert_pos`, following `separator`
+  AddRest { insert_pos: u32, separator: &'static str },
+}
+
+/// How `component_arrow` can read its props, `None` for parameter lists such as `([a])` that have
+/// no binding for them and no place to add one
+fn props_access(component_arrow: &ArrowFunctionExpression, source_text: &str) -> Option<PropsAccess> {
+  let params = &component_arrow.params;
+  let Some(first) = params.items.first() else {
+    // Prepending a parameter to `(...args)` would shift what `args` holds
+    return match params.rest.as_ref().map(|rest| &rest.argument.kind) {
+      None => Some(PropsAccess::AddParameter),
+      Some(BindingPatternKind::BindingIdentifier(identifier)) => {
+        Some(PropsAccess::RestParameter(identifier.name.to_string()))
+      }
+      Some(_) => None,
+    };
   };
 
-  if component_has_props {
-    return Ok(None);
+  // `(props = {})` binds the same object as `(props)`
+  let mut pattern = &first.pattern.kind;
+  while let BindingPatternKind::AssignmentPattern(assignment) = pattern {
+    pattern = &assignment.left.kind;
   }
 
-  let component_text = &source_text[component_start as usize..];
-  let Some(paren_pos) = component_text.find('(') else {
-    return Ok(None);
-  };
+  match pattern {
+    BindingPatternKind::BindingIdentifier(identifier) => {
+      Some(PropsAccess::Binding(identifier.name.to_string()))
+    }
+    BindingPatternKind::ObjectPattern(object) => match &object.rest {
+      Some(rest) => match &rest.argument.kind {
+        BindingPatternKind::BindingIdentifier(identifier) => {
+          Some(PropsAccess::Binding(identifier.name.to_string()))
+        }
+        _ => None,
+      },
+      None => Some(match object.properties.last() {
+        // Nothing may follow a rest element, so it goes after a trailing comma, e.g. `({ label, })`
+        Some(property) => match trailing_comma(source_text, property.span.end) {
+          Some(comma) => PropsAccess::AddRest {
+            insert_pos: comma + 1,
+            separator: " ",
+          },
+          None => PropsAccess::AddRest {
+            insert_pos: property.span.end,
+            separator: ", ",
+          },
+        },
+        None => PropsAccess::AddRest {
+          insert_pos: object.span.start + 1,
+          separator: "",
+        },
+      }),
+    },
+    BindingPatternKind::ArrayPattern(_) | BindingPatternKind::AssignmentPattern(_) => None,
+  }
+}
+
+/// Offset of the comma right after `end`, skipping whitespace
+fn trailing_comma(source_text: &str, end: u32) -> Option<u32> {
+  let rest = source_text.get(end as usize..)?;
+  let skipped = rest.len() - rest.trim_start().len();
+  rest[skipped..]
+    .starts_with(',')
+    .then_some(end + skipped as u32)
+}
 
-  let insert_pos = component_start + paren_pos as u32 + 1;
+/// Whether `name` read at `node_id` is the props binding `props_access` provides. An existing
+/// binding must be the component's own parameter rather than e.g. `const props = useStore(...)`;
+/// an added one must not collide with anything the component declares.
+fn reads_props_binding(
+  semantic: &Semantic,
+  node_id: NodeId,
+  name: &str,
+  props_access: &PropsAccess,
+  component_arrow: &ArrowFunctionExpression,
+) -> bool {
+  let scope_id = semantic.nodes().get_node(node_id).scope_id();
+  let declared_at = semantic
+    .scoping()
+    .find_binding(scope_id, name)
+    .map(|symbol_id| semantic.scoping().symbol_span(symbol_id));
+
+  match (props_access, declared_at) {
+    (PropsAccess::Binding(_) | PropsAccess::RestParameter(_), Some(span)) => {
+      component_arrow.params.span.contains_inclusive(span)
+    }
+    (PropsAccess::Binding(_) | PropsAccess::RestParameter(_), None) => false,
+    (_, Some(span)) => !component_arrow.span.contains_inclusive(span),
+    (_, None) => true,
+  }
+}
+
+/// Appends a rest element binding the props to a parameter destructured without one, e.g.
+/// `({ label })` becomes `({ label, ...props })`. A type annotation can't go on a rest element,
+/// so only the name of `props_parameter` is used.
+fn create_props_rest_transformation(
+  insert_pos: u32,
+  separator: &str,
+  config: &AnalyzerConfig,
+  file_path: &Path,
+) -> Transformation {
   debug(&format!(
-    "🔧 Adding props parameter at position {} in {}",
+    "🔧 Adding rest element `...{}` at position {} in {}",
+    config.props_binding(),
     insert_pos,
     file_path.display()
   ));
 
-  Ok(Some(Transformation {
+  Transformation {
     start: insert_pos,
     end: insert_pos,
-    replacement: "props: any".to_string(),
-  }))
-}
-
-fn find_component_info(semantic: &Semantic) -> Option<(u32, bool)> {
-  use oxc_ast::ast::*;
-
-  semantic
-    .nodes()
-    .iter()
-    .filter_map(|node| {
-      if let AstKind::CallExpression(call_expr) = node.kind() {
-        if extract_function_name(call_expr)? == "component$" {
-          if let Some(Argument::ArrowFunctionExpression(arrow_fn)) = call_expr.arguments.first() {
-            return Some((arrow_fn.span.start, !arrow_fn.params.items.is_empty()));
-          }
-        }
-      }
-      None
-    })
-    .next()
+    replacement: config.annotate(
+      format!("{}...{}", separator, config.props_binding()),
+      "rest element added to read injected presence props",
+    ),
+  }
 }
 
-fn create_component_present_call_transformations(
+fn create_props_parameter_transformation(
   semantic: &Semantic,
+  component_arrow: &ArrowFunctionExpression,
+  source_text: &str,
+  config: &AnalyzerConfig,
   file_path: &Path,
-) -> Result<Vec<Transformation>> {
-  let mut transformations = Vec::new();
-  let source_text = std::fs::read_to_string(file_path)?;
-
-  for node in semantic.nodes().iter() {
-    let AstKind::CallExpression(call_expr) = node.kind() else {
-      continue;
-    };
-
-    let Some(function_name) = extract_function_name(call_expr) else {
-      continue;
-    };
-
-    if function_name != "isComponentPresent" {
-      continue;
-    }
+) -> Option<Transformation> {
+
+  // The parameter list span starts at its opening paren, so a return type annotation such as
+  // `(): JSX.Element =>` can't be mistaken for the parameter list. Nothing is searched for in the
+  // raw text, which may hold JSX-like strings or comments.
+  let params_start = component_arrow.params.span.start;
+  if source_text.as_bytes().get(params_start as usize) != Some(&b'(') {
+    return None;
+  }
 
-    let Some(first_arg) = call_expr.arguments.first() else {
-      continue;
-    };
+  let insert_pos = params_start + 1;
+  let props_parameter = props_parameter_text(semantic, component_arrow, config, file_path);
+  debug(&format!(
+    "🔧 Adding props parameter `{}` at position {} in {}",
+    props_parameter,
+    insert_pos,
+    file_path.display()
+  ));
 
-    let arg_span = first_arg.span();
-    let arg_text = &source_text[arg_span.start as usize..arg_span.end as usize];
+  Some(Transformation {
+    start: insert_pos,
+    end: insert_pos,
+    replacement: config.annotate(
+      props_parameter,
+      "parameter added to read injected presence props",
+    ),
+  })
+}
 
-    let component_name = if let Some(name) = extract_component_name_from_argument(first_arg) {
-      name
-    } else if arg_text.contains('.') {
-      arg_text.to_string()
-    } else {
-      continue;
-    };
+const RECORD_PROPS_TYPE: &str = "Record<string, unknown>";
 
-    let prop_name = format!("__qwik_analyzer_has_{}", component_name.replace(".", "_"));
-    let new_call = format!("isComponentPresent({}, props.{})", arg_text, prop_name);
+/// `props_parameter` as added to `component_arrow`, annotated with `props_type` in TypeScript
+/// files unless it carries an annotation of its own
+fn props_parameter_text(
+  semantic: &Semantic,
+  component_arrow: &ArrowFunctionExpression,
+  config: &AnalyzerConfig,
+  file_path: &Path,
+) -> String {
+  let parameter = config.props_parameter.text.clone();
+  if config.props_parameter.annotated || !source_type_for_path(file_path).is_typescript() {
+    return parameter;
+  }
 
-    transformations.push(Transformation {
-      start: call_expr.span.start,
-      end: call_expr.span.end,
-      replacement: new_call,
-    });
+  let props_type = match &config.props_type {
+    PropsType::None => return parameter,
+    PropsType::Record => RECORD_PROPS_TYPE.to_string(),
+    PropsType::Declared => declared_props_type(semantic, component_arrow)
+      .unwrap_or_else(|| RECORD_PROPS_TYPE.to_string()),
+    PropsType::Custom(props_type) => props_type.clone(),
+  };
+  format!("{}: {}", parameter, props_type)
+}
 
-    debug(&format!(
-      "🔧 Transforming isComponentPresent({}) call in {}",
-      component_name,
-      file_path.display()
-    ));
+/// The props type `component_arrow` declares without taking a parameter: the type argument of
+/// `component$<RootProps>(() => ...)`, else a type named after the binding it's assigned to, e.g.
+/// `RootProps` for `const Root = component$(...)`, declared in or imported into the file
+fn declared_props_type(
+  semantic: &Semantic,
+  component_arrow: &ArrowFunctionExpression,
+) -> Option<String> {
+  let arrow_node = semantic.nodes().iter().find(|node| {
+    matches!(
+      node.kind(),
+      AstKind::ArrowFunctionExpression(arrow) if arrow.span == component_arrow.span
+    )
+  })?;
+  let mut ancestors = semantic
+    .nodes()
+    .ancestors(arrow_node.id())
+    .filter(|ancestor| ancestor.id() != arrow_node.id())
+    .skip_while(|ancestor| matches!(ancestor.kind(), AstKind::Argument(_)));
+  let call_expr = ancestors.next()?.kind().as_call_expression()?;
+
+  if let Some(type_argument) = call_expr
+    .type_arguments
+    .as_ref()
+    .and_then(|type_arguments| type_arguments.params.first())
+  {
+    return Some(type_argument.span().source_text(semantic.source_text()).to_string());
   }
 
-  Ok(transformations)
+  let AstKind::VariableDeclarator(declarator) = ancestors.next()?.kind() else {
+    return None;
+  };
+  let component_binding = declarator.id.get_binding_identifier()?;
+  let props_type = format!("{}Props", component_binding.name);
+  let symbol_id = semantic.scoping().get_root_binding(&props_type)?;
+  let is_type = semantic.scoping().symbol_flags(symbol_id).is_type()
+    || matches!(
+      semantic.symbol_declaration(symbol_id).kind(),
+      AstKind::ImportSpecifier(_)
+    );
+  is_type.then_some(props_type)
 }
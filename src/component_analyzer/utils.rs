@@ -1,17 +1,197 @@
 use oxc_ast::ast::CallExpression;
 use oxc_semantic::Semantic;
+use oxc_span::{SourceType, VALID_EXTENSIONS};
+use std::path::{Component, Path, PathBuf};
+
+use crate::component_analyzer::config::{current_config, PropNaming};
+use crate::component_analyzer::import_resolver::{
+  find_import_source_for_component, find_project_root, is_runtime_import,
+};
+use crate::component_analyzer::manifest::is_manifest_source;
+use crate::component_analyzer::jsx_analysis::extract_jsx_element_name;
+
+/// Outcome of looking for a component in a file's JSX subtree
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Presence {
+  Present,
+  Absent,
+  /// A rendered component from the project couldn't be resolved or parsed, so the target may be
+  /// inside it
+  Unknown,
+}
+
+impl Presence {
+  pub fn is_present(self) -> bool {
+    self == Presence::Present
+  }
+
+  pub fn as_str(self) -> &'static str {
+    match self {
+      Presence::Present => "present",
+      Presence::Absent => "absent",
+      Presence::Unknown => "unknown",
+    }
+  }
+}
+
+/// What a file does with compound components, see `classify_file`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileRole {
+  /// Checks for children with `isComponentPresent`, like a Root
+  Definition,
+  /// Renders a component that checks for children
+  Consumer,
+  Both,
+  Neither,
+}
+
+impl FileRole {
+  pub fn as_str(self) -> &'static str {
+    match self {
+      FileRole::Definition => "definition",
+      FileRole::Consumer => "consumer",
+      FileRole::Both => "both",
+      FileRole::Neither => "neither",
+    }
+  }
+}
+
+impl From<bool> for Presence {
+  fn from(is_present: bool) -> Self {
+    if is_present {
+      Presence::Present
+    } else {
+      Presence::Absent
+    }
+  }
+}
 
 #[derive(Debug, Clone)]
 pub struct ComponentPresenceCall {
   pub component_name: String,
-  pub is_present_in_subtree: bool,
+  pub presence: Presence,
+  /// Whether the component was found in the current file's own JSX, as opposed to inside an
+  /// imported component's definition
+  pub found_directly: bool,
   pub source_file: String,
+  /// Whether the call was found by scanning a whole module directory because its index didn't
+  /// name the Root, rather than in the Root's own file
+  pub resolved_by_module_scan: bool,
 }
 
 pub fn debug(msg: &str) {
   println!("{}", msg);
 }
 
+/// A file the analysis reached through an import but couldn't read, e.g. deleted mid-build or not
+/// UTF-8
+#[derive(Debug)]
+pub struct UnreadableFile {
+  pub path: String,
+  pub reason: std::io::Error,
+}
+
+impl std::fmt::Display for UnreadableFile {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{} can't be read ({})", self.path, self.reason)
+  }
+}
+
+impl std::error::Error for UnreadableFile {}
+
+/// Reads a file reached through an import, naming it in the error when that fails
+pub fn read_dependency(path: impl AsRef<Path>) -> Result<String, UnreadableFile> {
+  std::fs::read_to_string(path.as_ref()).map_err(|reason| UnreadableFile {
+    path: path.as_ref().display().to_string(),
+    reason,
+  })
+}
+
+/// Resolves `.` and `..` segments lexically, without touching the filesystem, making relative
+/// paths absolute against the working directory
+pub fn normalize_path(path: &Path) -> PathBuf {
+  let absolute = if path.is_absolute() {
+    path.to_path_buf()
+  } else {
+    std::env::current_dir()
+      .map(|cwd| cwd.join(path))
+      .unwrap_or_else(|_| path.to_path_buf())
+  };
+
+  let mut normalized = PathBuf::new();
+  for component in absolute.components() {
+    match component {
+      Component::CurDir => {}
+      Component::ParentDir => {
+        normalized.pop();
+      }
+      other => normalized.push(other.as_os_str()),
+    }
+  }
+  normalized
+}
+
+/// Whether `path` lies inside the configured `sandbox_root`, following symlinks when the path
+/// exists. Always true when no sandbox is configured.
+pub fn within_sandbox(path: &Path) -> bool {
+  let config = current_config();
+  let Some(sandbox_root) = &config.sandbox_root else {
+    return true;
+  };
+  let sandbox_root = sandbox_root
+    .canonicalize()
+    .unwrap_or_else(|_| normalize_path(sandbox_root));
+  let path = path.canonicalize().unwrap_or_else(|_| normalize_path(path));
+  path.starts_with(sandbox_root)
+}
+
+/// Whether `a` and `b` name the same file. Canonical paths are compared when both exist; when
+/// either can't be canonicalized (missing or unreadable), the normalized paths are compared instead.
+pub fn same_file(a: &str, b: &str) -> bool {
+  match (Path::new(a).canonicalize(), Path::new(b).canonicalize()) {
+    (Ok(a_canonical), Ok(b_canonical)) => a_canonical == b_canonical,
+    _ => normalize_path(Path::new(a)) == normalize_path(Path::new(b)),
+  }
+}
+
+/// Infer the `SourceType` used to parse a file.
+/// Extensions configured in `AnalyzerConfig::source_extensions` take precedence. Qwik projects
+/// commonly put JSX in plain `.js` files, so JSX is enabled for the JavaScript family as well.
+/// TypeScript files keep `SourceType::from_path` semantics so `<T>value` casts still parse.
+/// `None` when the extension is neither in the JS/TS family nor configured.
+pub fn supported_source_type(file_path: &Path) -> Option<SourceType> {
+  let configured = file_path
+    .extension()
+    .and_then(|extension| extension.to_str())
+    .and_then(|extension| current_config().source_extensions.get(extension).copied());
+
+  if configured.is_some() {
+    return configured;
+  }
+
+  let source_type = SourceType::from_path(file_path).ok()?;
+  if source_type.is_javascript() {
+    Some(source_type.with_jsx(true))
+  } else {
+    Some(source_type)
+  }
+}
+
+/// [`supported_source_type`] for files reached through imports, which the resolver only finds
+/// with a supported extension
+pub fn source_type_for_path(file_path: &Path) -> SourceType {
+  supported_source_type(file_path).unwrap_or_default()
+}
+
+/// All file extensions the analyzer parses: the standard JS/TS family plus configured ones
+pub fn source_extensions() -> Vec<String> {
+  let mut extensions: Vec<String> = VALID_EXTENSIONS.iter().map(|ext| ext.to_string()).collect();
+  let mut configured: Vec<String> = current_config().source_extensions.keys().cloned().collect();
+  configured.sort();
+  extensions.extend(configured);
+  extensions
+}
+
 pub fn extract_function_name(call_expr: &CallExpression) -> Option<String> {
   match &call_expr.callee {
     oxc_ast::ast::Expression::Identifier(identifier) => Some(identifier.name.to_string()),
@@ -19,56 +199,291 @@ pub fn extract_function_name(call_expr: &CallExpression) -> Option<String> {
   }
 }
 
+/// The analyzer's presence checks: `isComponentPresent` returns a boolean, `usePresence` a value
+/// holder whose first-render value is the same injected prop
+pub const PRESENCE_FUNCTIONS: [&str; 2] = ["isComponentPresent", "usePresence"];
+
+/// Whether `call_expr`, in `file_path`, calls one of the analyzer's [`PRESENCE_FUNCTIONS`] imported
+/// from its runtime, rather than a function of the same name that is unbound, declared in the file
+/// itself or imported from a project helper or another package
+pub fn is_presence_call(semantic: &Semantic, call_expr: &CallExpression, file_path: &Path) -> bool {
+  let oxc_ast::ast::Expression::Identifier(identifier) = &call_expr.callee else {
+    return false;
+  };
+  if !PRESENCE_FUNCTIONS.contains(&identifier.name.as_str()) {
+    return false;
+  }
+
+  let scoping = semantic.scoping();
+  let Some(symbol_id) = identifier
+    .reference_id
+    .get()
+    .and_then(|reference_id| scoping.get_reference(reference_id).symbol_id())
+  else {
+    return false;
+  };
+
+  if !scoping.symbol_flags(symbol_id).is_import() {
+    return false;
+  }
+  let Some(import_binding) = find_import_source_for_component(semantic, &identifier.name) else {
+    return false;
+  };
+  let imported_name = import_binding.imported_name.as_deref().unwrap_or(&identifier.name);
+  if !is_runtime_import(&import_binding.source, imported_name, file_path) {
+    debug(&format!(
+      "⏭️ {} is imported from {}, not the analyzer's runtime",
+      identifier.name, import_binding.source
+    ));
+    return false;
+  }
+  true
+}
+
+/// Separates the alternatives of `isComponentPresent([Description, HelperText])` in a component name
+pub const COMPONENT_ALTERNATIVE_SEPARATOR: char = '|';
+
+/// Extracts the component reference passed to `isComponentPresent`, either `Description` or
+/// `Checkbox.Description`. An array of references, any of which counts as present, yields the
+/// names joined by `COMPONENT_ALTERNATIVE_SEPARATOR`, e.g. `Description|HelperText`.
+/// Any other expression (string literals, calls, ...) yields `None`.
 pub fn extract_component_name_from_argument(argument: &oxc_ast::ast::Argument) -> Option<String> {
   use oxc_ast::ast::*;
 
   match argument {
-    Argument::Identifier(identifier) => Some(identifier.name.to_string()),
+    Argument::ArrayExpression(array_expr) => {
+      let names = array_expr
+        .elements
+        .iter()
+        .map(|element| match element {
+          ArrayExpressionElement::Identifier(_)
+          | ArrayExpressionElement::StaticMemberExpression(_) => {
+            extract_component_name_from_expression(element.to_expression())
+          }
+          _ => None,
+        })
+        .collect::<Option<Vec<String>>>()?;
+
+      if names.is_empty() {
+        return None;
+      }
+      Some(names.join(&COMPONENT_ALTERNATIVE_SEPARATOR.to_string()))
+    }
+    _ => extract_component_name_from_expression(argument.as_expression()?),
+  }
+}
+
+fn extract_component_name_from_expression(expression: &oxc_ast::ast::Expression) -> Option<String> {
+  use oxc_ast::ast::*;
+
+  match expression {
+    Expression::Identifier(identifier) => Some(identifier.name.to_string()),
+    Expression::StaticMemberExpression(member_expr) => {
+      let Expression::Identifier(object) = &member_expr.object else {
+        return None;
+      };
+      Some(format!("{}.{}", object.name, member_expr.property.name))
+    }
     _ => None,
   }
 }
 
+/// The alternatives a presence check is satisfied by, e.g. `["Description", "HelperText"]`
+pub fn component_alternatives(component_name: &str) -> impl Iterator<Item = &str> {
+  component_name.split(COMPONENT_ALTERNATIVE_SEPARATOR)
+}
+
+/// Splits a presence check's component name into the component it lists first and the other
+/// alternatives, as reported to hosts, which never see the joined form
+pub fn split_alternatives(component_name: &str) -> (String, Vec<String>) {
+  let mut alternatives = component_alternatives(component_name).map(str::to_string);
+  let component = alternatives.next().unwrap_or_default();
+  (component, alternatives.collect())
+}
+
+/// Name of the prop injected into a Root for a presence check, e.g.
+/// `__qwik_analyzer_has_Checkbox_Description` or `__qwik_analyzer_has_Description_or_HelperText`
+pub fn presence_prop_name(component_name: &str) -> String {
+  let alternatives: Vec<String> = component_alternatives(component_name)
+    .map(|alternative| alternative.replace(".", "_"))
+    .collect();
+  format!("__qwik_analyzer_has_{}", alternatives.join("_or_"))
+}
+
+/// Name of the prop injected for `component_name` into the Root defined in `root_file`, following
+/// `AnalyzerConfig::prop_naming`. Manifest Roots keep the readable name their package reads.
+pub fn injected_prop_name(component_name: &str, root_file: &str) -> String {
+  let config = current_config();
+  let naming = &config.prop_naming;
+  if *naming == PropNaming::Readable || is_manifest_source(root_file) {
+    return presence_prop_name(component_name);
+  }
+
+  if *naming == PropNaming::DataAttribute {
+    return data_attribute_name(component_name);
+  }
+
+  let readable = presence_prop_name(component_name);
+  let name = readable.trim_start_matches("__qwik_analyzer_has_");
+  let hash = prop_name_hash(component_name, root_file);
+  match naming {
+    PropNaming::Custom(template) => template.replace("{name}", name).replace("{hash}", &hash),
+    _ => format!("__qa_{}", hash),
+  }
+}
+
+/// Kebab-cased attribute carrying a presence check, e.g.
+/// `data-qwik-analyzer-has-checkbox-description` or `data-qwik-analyzer-has-description-or-helper-text`
+fn data_attribute_name(component_name: &str) -> String {
+  let alternatives: Vec<String> = component_alternatives(component_name)
+    .map(|alternative| {
+      alternative
+        .split('.')
+        .map(kebab_case)
+        .collect::<Vec<_>>()
+        .join("-")
+    })
+    .collect();
+  format!("data-qwik-analyzer-has-{}", alternatives.join("-or-"))
+}
+
+/// `HelperText` -> `helper-text`, keeping acronyms together: `HTMLLabel` -> `html-label`
+fn kebab_case(name: &str) -> String {
+  let chars: Vec<char> = name.chars().collect();
+  let mut kebab = String::new();
+  for (index, &c) in chars.iter().enumerate() {
+    if c == '_' || c == '-' {
+      kebab.push('-');
+      continue;
+    }
+    if c.is_uppercase() && index > 0 {
+      let previous = chars[index - 1];
+      let next_is_lower = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+      let starts_word = previous.is_lowercase()
+        || previous.is_ascii_digit()
+        || (previous.is_uppercase() && next_is_lower);
+      if starts_word {
+        kebab.push('-');
+      }
+    }
+    kebab.extend(c.to_lowercase());
+  }
+  kebab
+}
+
+/// Reads `prop_name` from the `props` expression, with brackets when it isn't an identifier such
+/// as a `data-*` attribute
+pub fn prop_access(props: &str, prop_name: &str) -> String {
+  if is_identifier(prop_name) {
+    format!("{}.{}", props, prop_name)
+  } else {
+    format!("{}[\"{}\"]", props, prop_name)
+  }
+}
+
+/// Whether `name` can be read with dot access
+pub fn is_identifier(name: &str) -> bool {
+  let mut chars = name.chars();
+  chars
+    .next()
+    .is_some_and(|first| first.is_alphabetic() || first == '_' || first == '$')
+    && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
+/// FNV-1a over the Root's project-relative path and the checked component, so the Root's rewrite
+/// and its consumers derive the same token however they reached the file
+fn prop_name_hash(component_name: &str, root_file: &str) -> String {
+  let root_path = Path::new(root_file)
+    .canonicalize()
+    .unwrap_or_else(|_| normalize_path(Path::new(root_file)));
+  let relative = find_project_root(&root_path)
+    .and_then(|project_root| {
+      let project_root = project_root.canonicalize().unwrap_or(project_root);
+      root_path.strip_prefix(project_root).ok().map(Path::to_path_buf)
+    })
+    .unwrap_or_else(|| root_path.clone());
+  let key: Vec<String> = relative
+    .components()
+    .map(|component| component.as_os_str().to_string_lossy().into_owned())
+    .collect();
+
+  let mut hash: u64 = 0xcbf29ce484222325;
+  for byte in format!("{}#{}", key.join("/"), component_name).bytes() {
+    hash ^= u64::from(byte);
+    hash = hash.wrapping_mul(0x100000001b3);
+  }
+  format!("{:010x}", hash >> 24)
+}
+
 pub fn component_exists_in_jsx_with_path(
   semantic: &Semantic,
   component_name: &str,
   current_file: &std::path::Path,
 ) -> bool {
+  find_jsx_element_for_component(semantic, component_name, current_file).is_some()
+}
+
+/// The name of the first JSX element that renders `component_name`, e.g. `Checkbox.Description`
+pub fn find_jsx_element_for_component(
+  semantic: &Semantic,
+  component_name: &str,
+  current_file: &std::path::Path,
+) -> Option<String> {
   use oxc_ast::AstKind;
 
-  for node in semantic.nodes().iter() {
+  semantic.nodes().iter().find_map(|node| {
     let AstKind::JSXOpeningElement(jsx_opening) = node.kind() else {
-      continue;
+      return None;
     };
 
-    if let Some(element_name) = extract_jsx_element_name(jsx_opening) {
-      if element_name == component_name {
-        // For member expressions like Checkbox.Description, check if it's external
-        if element_name.contains('.') {
-          let parts: Vec<&str> = element_name.split('.').collect();
-          if parts.len() == 2 {
-            let namespace = parts[0];
-            // Only return true if the namespace can be resolved locally (not external)
-            return can_resolve_namespace_locally(semantic, namespace, current_file);
-          }
-        }
-        return true;
+    extract_jsx_element_name(jsx_opening).filter(|element_name| {
+      jsx_element_matches_component(semantic, element_name, component_name, current_file)
+    })
+  })
+}
+
+/// Whether the JSX element `element_name` renders `component_name`, e.g. `Checkbox.Description`
+/// for `Description` when `Checkbox` is a local module
+pub fn jsx_element_matches_component(
+  semantic: &Semantic,
+  element_name: &str,
+  component_name: &str,
+  current_file: &std::path::Path,
+) -> bool {
+  if element_name == component_name {
+    // For member expressions like Checkbox.Description, check if it's external
+    if element_name.contains('.') {
+      let parts: Vec<&str> = element_name.split('.').collect();
+      if parts.len() == 2 {
+        let namespace = parts[0];
+        // Only match if the namespace can be resolved locally (not external)
+        return can_resolve_namespace_locally(semantic, namespace, current_file);
       }
+    }
+    return true;
+  }
 
-      if !component_name.contains('.') && element_name.contains('.') {
-        let parts: Vec<&str> = element_name.split('.').collect();
-        if parts.len() == 2 {
-          let namespace = parts[0];
-          let component = parts[1];
+  if !component_name.contains('.') && element_name.contains('.') {
+    let parts: Vec<&str> = element_name.split('.').collect();
+    if parts.len() == 2 {
+      let namespace = parts[0];
+      let component = parts[1];
 
-          if component == component_name
-            && can_resolve_namespace_locally(semantic, namespace, current_file)
-          {
-            return true;
-          }
-        }
-      }
+      return component == component_name
+        && can_resolve_namespace_locally(semantic, namespace, current_file);
     }
   }
+
+  // A flat part renamed on import, e.g. `import { Item as AccordionItem }`, renders the export it
+  // names
+  if !component_name.contains('.') && !element_name.contains('.') {
+    use crate::component_analyzer::import_resolver::{find_import_source_for_component, ImportKind};
+
+    return find_import_source_for_component(semantic, element_name).is_some_and(|binding| {
+      binding.kind == ImportKind::Named && binding.imported_name.as_deref() == Some(component_name)
+    }) && can_resolve_namespace_locally(semantic, element_name, current_file);
+  }
+
   false
 }
 
@@ -81,8 +496,8 @@ fn can_resolve_namespace_locally(
     find_import_source_for_component, resolve_import_path,
   };
 
-  if let Some(import_source) = find_import_source_for_component(semantic, namespace) {
-    match resolve_import_path(&import_source, current_file) {
+  if let Some(import_binding) = find_import_source_for_component(semantic, namespace) {
+    match resolve_import_path(&import_binding.source, current_file) {
       Ok(resolved_path) => {
         // Check if the resolved path contains node_modules (external package)
         !resolved_path.contains("node_modules")
@@ -93,19 +508,3 @@ fn can_resolve_namespace_locally(
     false
   }
 }
-
-fn extract_jsx_element_name(jsx_opening: &oxc_ast::ast::JSXOpeningElement) -> Option<String> {
-  use oxc_ast::ast::JSXElementName;
-
-  match &jsx_opening.name {
-    JSXElementName::Identifier(ident) => Some(ident.name.to_string()),
-    JSXElementName::MemberExpression(member_expr) => {
-      if let Some(obj) = member_expr.object.get_identifier() {
-        Some(format!("{}.{}", obj.name, member_expr.property.name))
-      } else {
-        None
-      }
-    }
-    _ => None,
-  }
-}
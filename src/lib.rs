@@ -1,8 +1,17 @@
 use napi_derive::napi;
-use std::path::Path;
+use oxc_span::SourceType;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 pub mod component_analyzer;
 
+pub use component_analyzer::config::{
+  AnalyzerConfig, ExistingPropStrategy, InjectionPosition, PropNaming, PropsParameter, PropsType,
+  TransformSide,
+};
+pub use component_analyzer::utils::ComponentPresenceCall;
+pub use component_analyzer::{analyze_code_with_semantics, analyze_file_with_semantics};
+
 #[derive(Debug)]
 #[napi(object)]
 pub struct Transformation {
@@ -11,25 +20,234 @@ pub struct Transformation {
   pub replacement: String,
 }
 
+#[derive(Debug)]
+#[napi(object)]
+pub struct Diagnostic {
+  /// Stable identifier of the diagnostic class, e.g. `QA004` for an invalid `isComponentPresent`
+  /// argument
+  pub code: String,
+  pub message: String,
+  pub file_path: String,
+  pub start: u32,
+  pub end: u32,
+}
+
+/// The outcome of one `isComponentPresent` check made by a rendered Root
+#[derive(Debug)]
+#[napi(object)]
+pub struct PresenceCheck {
+  /// The checked component, the first one listed when the check takes several
+  pub component: String,
+  /// The other components satisfying the check, e.g. `["HelperText"]` for
+  /// `isComponentPresent([Description, HelperText])`
+  pub alternatives: Vec<String>,
+  /// The file containing the `isComponentPresent` call
+  pub source_file: String,
+  /// `"present"`, `"absent"` or `"unknown"`. Unknown checks get no injected prop, so the call
+  /// falls back to its runtime value.
+  pub presence: String,
+}
+
+/// A presence prop injected into a rendered Root, for explaining a transformation
+#[derive(Debug)]
+#[napi(object)]
+pub struct InjectedProp {
+  /// Span of the Root's opening tag that received the prop
+  pub start: u32,
+  pub end: u32,
+  /// The Root element's name as written, e.g. `Checkbox.Root`
+  pub element: String,
+  /// The checked component, the first one listed when the check takes several
+  pub component: String,
+  /// The other components satisfying the check
+  pub alternatives: Vec<String>,
+  pub prop_name: String,
+  pub value: bool,
+}
+
 #[derive(Debug)]
 #[napi(object)]
 pub struct AnalysisResult {
   pub has_component: bool,
+  /// True when at least one checked component appears in this file's own JSX rather than only
+  /// through recursion into imported components
+  pub found_directly: bool,
   pub file_path: String,
   pub dependencies: Vec<String>,
   pub transformations: Vec<Transformation>,
+  pub diagnostics: Vec<Diagnostic>,
+  pub presence_checks: Vec<PresenceCheck>,
+  /// One entry per injected presence prop. They come first in `transformations` and in the same
+  /// order, so `injected_props[i]` explains `transformations[i]`
+  pub injected_props: Vec<InjectedProp>,
+}
+
+/// How an import of a rendered component resolved
+#[derive(Debug)]
+#[napi(object)]
+pub struct ImportResolution {
+  /// The import specifier, e.g. `../components/checkbox`
+  pub source: String,
+  /// Local names bound by the import that are rendered in the file
+  pub local_names: Vec<String>,
+  /// `"local"` for a file in the project, `"package"` for one in node_modules, or `"unresolved"`
+  pub status: String,
+  pub resolved_path: Option<String>,
+}
+
+/// How often one child checked by a Root came out present, absent or unknown
+#[derive(Debug)]
+#[napi(object)]
+pub struct ChildCoverage {
+  /// The checked component, the first one listed when the check takes several
+  pub component: String,
+  /// The other components satisfying the check
+  pub alternatives: Vec<String>,
+  pub present: u32,
+  pub absent: u32,
+  pub unknown: u32,
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct ComponentCoverage {
+  /// The file defining the Root, where its `isComponentPresent` calls live
+  pub root_file: String,
+  /// Number of analyzed files rendering the Root
+  pub usages: u32,
+  pub children: Vec<ChildCoverage>,
+}
+
+/// Presence results aggregated over a set of files, per Root
+#[derive(Debug)]
+#[napi(object)]
+pub struct CoverageReport {
+  pub files_analyzed: u32,
+  pub components: Vec<ComponentCoverage>,
+}
+
+/// How one `isComponentPresent` check made by a rendered Root was settled, for debugging a
+/// result that looks wrong
+#[derive(Debug)]
+#[napi(object)]
+pub struct PresenceExplanation {
+  /// The Root element as written, e.g. `Checkbox.Root`
+  pub root: String,
+  /// The file defining the Root, when its import resolves
+  pub root_file: Option<String>,
+  pub target: String,
+  /// `"present"`, `"absent"` or `"unknown"`, or `None` when the Root doesn't check for `target`
+  pub presence: Option<String>,
+  /// The JSX element that made `target` present, e.g. `Checkbox.Description`
+  pub matched_element: Option<String>,
+  /// How many levels of imported components were searched below the file
+  pub depth: u32,
+  /// The files searched and the decisions made, in order
+  pub steps: Vec<String>,
+}
+
+/// An editor change against the code a previous analysis ran on, in byte offsets
+#[derive(Debug)]
+#[napi(object)]
+pub struct SourceEdit {
+  pub offset: u32,
+  pub removed_length: u32,
+  pub inserted_text: String,
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct IncrementalUpdate {
+  /// The previous transformations shifted to apply to the edited code
+  pub transformations: Vec<Transformation>,
+  /// True when the edit touched an import, JSX, an `isComponentPresent` call or a transformed
+  /// span, so the shifted transformations may be stale and the file should be analyzed again
+  pub requires_reanalysis: bool,
+}
+
+/// The transformed code of one file with the diagnostics its analysis produced
+#[derive(Debug)]
+#[napi(object)]
+pub struct TransformOutput {
+  pub code: String,
+  pub diagnostics: Vec<Diagnostic>,
+}
+
+/// One file's outcome in `analyze_and_transform_files`
+#[derive(Debug)]
+#[napi(object)]
+pub struct BatchTransformResult {
+  pub file_path: String,
+  /// The transformed code, or `None` when the file couldn't be read or analyzed
+  pub code: Option<String>,
+  pub error: Option<String>,
+}
+
+#[derive(Debug, Default)]
+#[napi(object)]
+pub struct AnalyzerOptions {
+  pub props_parameter: Option<String>,
+  /// Annotation of an added props parameter in TypeScript files: `"none"` (default),
+  /// `"record"` for `Record<string, unknown>`, `"declared"` for the component's own props type,
+  /// or a type such as `"PropsOf<typeof Root>"`
+  pub props_type: Option<String>,
+  /// Maps extra file extensions to the syntax they are parsed as, e.g. `{ qwik: "tsx" }`
+  pub source_extensions: Option<HashMap<String, String>>,
+  /// `"respect"` (default) keeps presence props the user already wrote on a Root, `"override"`
+  /// replaces their value
+  pub existing_props: Option<String>,
+  /// `"last"` (default) places injected props after a Root's attributes, `"first"` right after
+  /// its name
+  pub injection_position: Option<String>,
+  /// Files larger than this many bytes are skipped with a diagnostic (default 2 MiB, `0` disables
+  /// the limit)
+  pub max_source_bytes: Option<u32>,
+  /// Overrides the project root used for `~/` imports and `qwik-analyzer.json`, which otherwise is
+  /// the nearest directory with a package.json
+  pub project_root: Option<String>,
+  /// Skip injecting props into a Root when none of its presence checks are true (default `false`)
+  pub inject_only_when_present: Option<bool>,
+  /// Replacement for each `isComponentPresent` call, with `{arg}`, `{prop}` and `{props}`
+  /// placeholders (default `isComponentPresent({arg}, {props}.{prop})`)
+  pub presence_call_template: Option<String>,
+  /// Report and leave unresolved presence checks only a heuristic could settle (default `false`)
+  pub strict_scoping: Option<bool>,
+  /// Count children sent to a `q:slot` the Root doesn't render as absent (default `false`)
+  pub named_slots: Option<bool>,
+  /// `"readable"` (default), `"hashed"`, `"data-attribute"`, or a template for injected prop names with `{name}`
+  /// and/or `{hash}` placeholders, e.g. `"qa_{hash}"`
+  pub prop_naming: Option<String>,
+  /// Refuse to read files that imports resolve to outside this directory (default: no limit)
+  pub sandbox_root: Option<String>,
+  /// Children always counted as present, as named in the Root's `isComponentPresent` call
+  pub assume_present: Option<Vec<String>>,
+  /// Only analyze these components or namespaces, e.g. `["Checkbox"]` (default: all)
+  pub only_components: Option<Vec<String>>,
+  /// Explain each transformation with a `/* qwik-analyzer: ... */` comment (default `false`)
+  pub annotate_transformations: Option<bool>,
+  /// `"both"` (default), or `"definition"`/`"consumer"` to only rewrite Root definitions or only
+  /// inject props into the Roots a file renders
+  pub transform_side: Option<String>,
+  /// How many levels of nested components are searched for a child (default 8)
+  pub max_component_depth: Option<u32>,
+  /// Cross-check manifest-declared Roots against their definitions, reporting `QA005` for
+  /// injected props they never read (default `false`)
+  pub verify_manifest: Option<bool>,
+  /// Other packages `isComponentPresent` and `usePresence` may be imported from, besides
+  /// `@jackshelton/qwik-analyzer` and files re-exporting them from either
+  pub runtime_modules: Option<Vec<String>>,
 }
 
 use oxc_allocator::Allocator;
 use oxc_parser::{Parser, ParserReturn};
 use oxc_semantic::SemanticBuilder;
-use oxc_span::SourceType;
+use component_analyzer::utils::source_type_for_path;
 
 pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
 
 pub fn parse_file_with_semantic(source_text: &str, file_path: &Path) -> Result<()> {
   let allocator = Allocator::default();
-  let source_type = SourceType::from_path(file_path).unwrap_or_default();
+  let source_type = source_type_for_path(file_path);
 
   let ParserReturn {
     program, errors, ..
@@ -52,6 +270,197 @@ pub fn parse_file_with_semantic(source_text: &str, file_path: &Path) -> Result<(
   Ok(())
 }
 
+#[napi]
+pub fn configure_analyzer(options: AnalyzerOptions) -> napi::Result<()> {
+  let mut config = AnalyzerConfig::default();
+
+  if let Some(props_parameter) = options.props_parameter {
+    config.props_parameter = PropsParameter::parse(&props_parameter).map_err(|reason| {
+      napi::Error::new(
+        napi::Status::InvalidArg,
+        format!("propsParameter '{}' {}", props_parameter, reason),
+      )
+    })?;
+  }
+
+  if let Some(props_type) = options.props_type {
+    config.props_type = match props_type.as_str() {
+      "none" => PropsType::None,
+      "record" => PropsType::Record,
+      "declared" => PropsType::Declared,
+      "" => {
+        return Err(napi::Error::new(
+          napi::Status::InvalidArg,
+          "Empty propsType, expected \"none\", \"record\", \"declared\" or a type".to_string(),
+        ))
+      }
+      type_text => PropsType::Custom(type_text.to_string()),
+    };
+  }
+
+  if let Some(existing_props) = options.existing_props {
+    config.existing_props = match existing_props.as_str() {
+      "respect" => ExistingPropStrategy::Respect,
+      "override" => ExistingPropStrategy::Override,
+      other => {
+        return Err(napi::Error::new(
+          napi::Status::InvalidArg,
+          format!(
+            "Unknown existingProps strategy '{}', expected \"respect\" or \"override\"",
+            other
+          ),
+        ))
+      }
+    };
+  }
+
+  if let Some(injection_position) = options.injection_position {
+    config.injection_position = match injection_position.as_str() {
+      "first" => InjectionPosition::First,
+      "last" => InjectionPosition::Last,
+      other => {
+        return Err(napi::Error::new(
+          napi::Status::InvalidArg,
+          format!(
+            "Unknown injectionPosition '{}', expected \"first\" or \"last\"",
+            other
+          ),
+        ))
+      }
+    };
+  }
+
+  if let Some(max_component_depth) = options.max_component_depth {
+    if max_component_depth == 0 {
+      return Err(napi::Error::new(
+        napi::Status::InvalidArg,
+        "maxComponentDepth must be at least 1".to_string(),
+      ));
+    }
+    config.max_component_depth = max_component_depth as usize;
+  }
+
+  if let Some(max_source_bytes) = options.max_source_bytes {
+    config.max_source_bytes = (max_source_bytes > 0).then_some(max_source_bytes as usize);
+  }
+
+  if let Some(inject_only_when_present) = options.inject_only_when_present {
+    config.inject_only_when_present = inject_only_when_present;
+  }
+
+  if let Some(presence_call_template) = options.presence_call_template {
+    if !presence_call_template.contains("{arg}") || !presence_call_template.contains("{prop}") {
+      return Err(napi::Error::new(
+        napi::Status::InvalidArg,
+        format!(
+          "presenceCallTemplate '{}' must contain the {{arg}} and {{prop}} placeholders",
+          presence_call_template
+        ),
+      ));
+    }
+    config.presence_call_template = presence_call_template;
+  }
+
+  if let Some(strict_scoping) = options.strict_scoping {
+    config.strict_scoping = strict_scoping;
+  }
+
+  if let Some(verify_manifest) = options.verify_manifest {
+    config.verify_manifest = verify_manifest;
+  }
+
+  if let Some(named_slots) = options.named_slots {
+    config.named_slots = named_slots;
+  }
+
+  if let Some(prop_naming) = options.prop_naming {
+    config.prop_naming = match prop_naming.as_str() {
+      "readable" => PropNaming::Readable,
+      "hashed" => PropNaming::Hashed,
+      "data-attribute" => PropNaming::DataAttribute,
+      template if template.contains("{name}") || template.contains("{hash}") => {
+        PropNaming::Custom(template.to_string())
+      }
+      other => {
+        return Err(napi::Error::new(
+          napi::Status::InvalidArg,
+          format!(
+            "Unknown propNaming '{}', expected \"readable\", \"hashed\", \"data-attribute\" or a template with {{name}} or {{hash}}",
+            other
+          ),
+        ));
+      }
+    };
+  }
+
+  if let Some(project_root) = options.project_root {
+    let project_root = PathBuf::from(project_root);
+    config.project_root = Some(if project_root.is_absolute() {
+      project_root
+    } else {
+      std::env::current_dir()?.join(project_root)
+    });
+  }
+
+  if let Some(assume_present) = options.assume_present {
+    config.assume_present = assume_present;
+  }
+
+  if let Some(runtime_modules) = options.runtime_modules {
+    config.runtime_modules = runtime_modules;
+  }
+
+  config.only_components = options.only_components;
+
+  if let Some(annotate_transformations) = options.annotate_transformations {
+    config.annotate_transformations = annotate_transformations;
+  }
+
+  if let Some(transform_side) = options.transform_side {
+    config.transform_side = match transform_side.as_str() {
+      "definition" => TransformSide::Definition,
+      "consumer" => TransformSide::Consumer,
+      "both" => TransformSide::Both,
+      other => {
+        return Err(napi::Error::new(
+          napi::Status::InvalidArg,
+          format!(
+            "Unknown transformSide '{}', expected \"definition\", \"consumer\" or \"both\"",
+            other
+          ),
+        ))
+      }
+    };
+  }
+
+  if let Some(sandbox_root) = options.sandbox_root {
+    let sandbox_root = PathBuf::from(sandbox_root);
+    config.sandbox_root = Some(if sandbox_root.is_absolute() {
+      sandbox_root
+    } else {
+      std::env::current_dir()?.join(sandbox_root)
+    });
+  }
+
+  for (extension, syntax) in options.source_extensions.unwrap_or_default() {
+    let source_type = SourceType::from_extension(&syntax).map_err(|_| {
+      napi::Error::new(
+        napi::Status::InvalidArg,
+        format!(
+          "Unknown syntax '{}' for extension '{}', expected one of js, jsx, ts, tsx",
+          syntax, extension
+        ),
+      )
+    })?;
+    config
+      .source_extensions
+      .insert(extension.trim_start_matches('.').to_string(), source_type);
+  }
+
+  component_analyzer::config::set_config(config);
+  Ok(())
+}
+
 #[napi]
 pub fn analyze_file(file_path: String) -> napi::Result<AnalysisResult> {
   component_analyzer::analyze_file_with_semantics(Path::new(&file_path)).map_err(|e| {
@@ -63,7 +472,12 @@ pub fn analyze_file(file_path: String) -> napi::Result<AnalysisResult> {
 }
 
 #[napi]
-pub fn analyze_file_changed(file_path: String, _event: String) {
+pub fn analyze_file_changed(file_path: String, event: String) {
+  component_analyzer::import_resolver::invalidate_cached_file(Path::new(&file_path));
+  if event == "delete" {
+    component_analyzer::dependency_graph::forget_dependencies(Path::new(&file_path));
+    return;
+  }
   if let Err(e) = analyze_file(file_path.clone()) {
     eprintln!("Error analyzing changed file {}: {}", file_path, e);
   }
@@ -71,6 +485,50 @@ pub fn analyze_file_changed(file_path: String, _event: String) {
 
 #[napi]
 pub fn analyze_and_transform_code(code: String, file_path: String) -> napi::Result<String> {
+  transform_with_diagnostics(code, file_path).map(|output| output.code)
+}
+
+/// Same as `analyze_and_transform_code`, also returning the analysis diagnostics for the host to
+/// report, e.g. through Vite's `this.warn`
+#[napi]
+pub fn transform_with_diagnostics(code: String, file_path: String) -> napi::Result<TransformOutput> {
+  let path = Path::new(&file_path);
+  let result = component_analyzer::analyze_code_with_semantics(&code, path).map_err(|e| {
+    napi::Error::new(
+      napi::Status::GenericFailure,
+      format!("Analysis failed: {}", e),
+    )
+  })?;
+
+  let code = if result.transformations.is_empty() {
+    code
+  } else {
+    apply_transformations(&code, result.transformations)
+  };
+
+  Ok(TransformOutput {
+    code,
+    diagnostics: result.diagnostics,
+  })
+}
+
+/// A unified diff of what `analyze_and_transform_code` would change in `code`, for review tooling.
+/// Empty when the file is left as is.
+#[napi]
+pub fn preview_transform(code: String, file_path: String) -> napi::Result<String> {
+  let transformed = analyze_and_transform_code(code.clone(), file_path.clone())?;
+  Ok(component_analyzer::preview::unified_diff(&code, &transformed, &file_path))
+}
+
+/// The edits `analyze_and_transform_code` would make to `code`, for hosts that apply them
+/// themselves, e.g. with magic-string to keep a single source map. They come sorted by position,
+/// with offsets in UTF-16 code units like JS string indices. Insertions sharing a position are
+/// listed in the order they apply.
+#[napi]
+pub fn collect_transformations(
+  code: String,
+  file_path: String,
+) -> napi::Result<Vec<Transformation>> {
   let path = Path::new(&file_path);
   let result = component_analyzer::analyze_code_with_semantics(&code, path).map_err(|e| {
     napi::Error::new(
@@ -79,24 +537,279 @@ pub fn analyze_and_transform_code(code: String, file_path: String) -> napi::Resu
     )
   })?;
 
-  if result.transformations.is_empty() {
-    return Ok(code);
+  let mut utf16_offset = 0;
+  let mut byte_offset = 0;
+  let mut to_utf16 = |offset: u32| {
+    let offset = offset as usize;
+    utf16_offset += code[byte_offset..offset].encode_utf16().count();
+    byte_offset = offset;
+    utf16_offset as u32
+  };
+
+  Ok(
+    applicable_transformations(&code, result.transformations)
+      .into_iter()
+      .map(|transformation| Transformation {
+        start: to_utf16(transformation.start),
+        end: to_utf16(transformation.end),
+        replacement: transformation.replacement,
+      })
+      .collect(),
+  )
+}
+
+/// Transforms each file in `file_paths` like `analyze_and_transform_code`, sharing import
+/// resolution and parsed library components across the batch. A failing file is reported in its
+/// result without stopping the rest.
+#[napi]
+pub fn analyze_and_transform_files(file_paths: Vec<String>) -> Vec<BatchTransformResult> {
+  let started_batch = component_analyzer::import_resolver::begin_batch();
+  let results = file_paths
+    .into_iter()
+    .map(|file_path| {
+      let transformed = std::fs::read_to_string(&file_path)
+        .map_err(|e| format!("Could not read file: {}", e))
+        .and_then(|code| {
+          analyze_and_transform_code(code, file_path.clone()).map_err(|e| e.reason)
+        });
+      match transformed {
+        Ok(code) => BatchTransformResult {
+          file_path,
+          code: Some(code),
+          error: None,
+        },
+        Err(error) => BatchTransformResult {
+          file_path,
+          code: None,
+          error: Some(error),
+        },
+      }
+    })
+    .collect();
+  // A session opened by the host outlives the batch
+  if started_batch {
+    let hits = component_analyzer::import_resolver::end_batch();
+    component_analyzer::utils::debug(&format!("📦 {} lookups served from the batch cache", hits));
+  }
+  results
+}
+
+/// Keeps import resolution and parsed library components cached across analyses until
+/// `end_cache_session`, for long-running hosts such as editors and watchers. Files that change
+/// meanwhile must be passed to `invalidate_cached_file`.
+#[napi]
+pub fn begin_cache_session() {
+  component_analyzer::import_resolver::begin_batch();
+}
+
+/// Closes the session opened by `begin_cache_session`, returning how many lookups it answered
+#[napi]
+pub fn end_cache_session() -> u32 {
+  component_analyzer::import_resolver::end_batch() as u32
+}
+
+/// Drops everything cached from `file_path` after it changed, was added or was deleted
+#[napi]
+pub fn invalidate_cached_file(file_path: String) {
+  component_analyzer::import_resolver::invalidate_cached_file(Path::new(&file_path));
+}
+
+/// Empties the caches without closing a session
+#[napi]
+pub fn clear_caches() {
+  component_analyzer::import_resolver::clear_caches();
+}
+
+/// Aggregates presence results over files and directories into a per-Root coverage report
+#[napi]
+pub fn presence_coverage(paths: Vec<String>) -> napi::Result<CoverageReport> {
+  let paths: Vec<PathBuf> = paths.into_iter().map(PathBuf::from).collect();
+  component_analyzer::coverage::presence_coverage(&paths).map_err(|e| {
+    napi::Error::new(
+      napi::Status::GenericFailure,
+      format!("Coverage failed: {}", e),
+    )
+  })
+}
+
+/// Same as `presence_coverage`, serialized as JSON for CI dashboards
+#[napi]
+pub fn presence_coverage_json(paths: Vec<String>) -> napi::Result<String> {
+  let report = presence_coverage(paths)?;
+  Ok(component_analyzer::coverage::coverage_report_json(&report))
+}
+
+/// Lists the distinct compound-component namespaces rendered in `code`, e.g. `["Checkbox", "Form"]`
+#[napi]
+pub fn collect_namespaces(code: String, file_path: String) -> napi::Result<Vec<String>> {
+  component_analyzer::collect_namespaces_in_code(&code, Path::new(&file_path)).map_err(|e| {
+    napi::Error::new(
+      napi::Status::GenericFailure,
+      format!("Namespace collection failed: {}", e),
+    )
+  })
+}
+
+/// Lists the children a Root definition file checks for with `isComponentPresent`, sorted
+#[napi]
+pub fn declared_optional_children(root_file: String) -> napi::Result<Vec<String>> {
+  component_analyzer::component_presence::declared_optional_children(Path::new(&root_file)).map_err(
+    |e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("Reading declared children failed: {}", e),
+      )
+    },
+  )
+}
+
+/// Classifies a file as `"definition"` (calls `isComponentPresent`), `"consumer"` (renders a Root
+/// that does), `"both"` or `"neither"`
+#[napi]
+pub fn classify_file(file_path: String) -> napi::Result<String> {
+  component_analyzer::classify_file(Path::new(&file_path))
+    .map(|role| role.as_str().to_string())
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("Classifying file failed: {}", e),
+      )
+    })
+}
+
+/// Lists the library files a file's transformation depends on, for watching them without analyzing
+/// the file. They are recorded for `dependents_of`.
+#[napi]
+pub fn collect_dependencies(file_path: String) -> napi::Result<Vec<String>> {
+  component_analyzer::collect_dependencies(Path::new(&file_path))
+    .map(|dependencies| {
+      component_analyzer::dependency_graph::record_dependencies(
+        Path::new(&file_path),
+        &dependencies,
+      );
+      dependencies
+        .iter()
+        .map(|dependency| dependency.to_string_lossy().to_string())
+        .collect()
+    })
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("Collecting dependencies failed: {}", e),
+      )
+    })
+}
+
+/// Lists the files whose recorded dependencies, from earlier `collect_dependencies` calls, include
+/// `path`, i.e. the pages to transform again after a component file changed
+#[napi]
+pub fn dependents_of(path: String) -> Vec<String> {
+  component_analyzer::dependency_graph::dependents_of(Path::new(&path))
+    .iter()
+    .map(|dependent| dependent.to_string_lossy().to_string())
+    .collect()
+}
+
+/// Lists how the imports of components rendered in a file resolve, to debug presence analysis
+/// that never fires
+#[napi]
+pub fn check_imports(file_path: String) -> napi::Result<Vec<ImportResolution>> {
+  component_analyzer::import_resolutions_in_file(Path::new(&file_path)).map_err(|e| {
+    napi::Error::new(
+      napi::Status::GenericFailure,
+      format!("Import check failed: {}", e),
+    )
+  })
+}
+
+/// Explains why `target`, as passed to `isComponentPresent`, came out present or absent for the
+/// `root_name` element rendered in a file
+#[napi]
+pub fn explain_presence(
+  file_path: String,
+  root_name: String,
+  target: String,
+) -> napi::Result<PresenceExplanation> {
+  component_analyzer::explanation::explain_presence(Path::new(&file_path), &root_name, &target)
+    .map_err(|e| {
+      napi::Error::new(
+        napi::Status::GenericFailure,
+        format!("Explaining presence failed: {}", e),
+      )
+    })
+}
+
+/// Adjusts a previous analysis of `code` for a single edit without analyzing the file again
+#[napi]
+pub fn adjust_transformations_for_edit(
+  code: String,
+  file_path: String,
+  previous: AnalysisResult,
+  edit: SourceEdit,
+) -> IncrementalUpdate {
+  let edit = component_analyzer::incremental::TextEdit {
+    offset: edit.offset,
+    removed_length: edit.removed_length,
+    inserted_text: edit.inserted_text,
+  };
+
+  let (transformations, requires_reanalysis) =
+    component_analyzer::incremental::adjust_transformations_for_edit(
+      &code,
+      Path::new(&file_path),
+      previous.transformations,
+      &edit,
+    );
+
+  IncrementalUpdate {
+    transformations,
+    requires_reanalysis,
+  }
+}
+
+/// Applies transformations whose spans all refer to the original `code`, as picked by
+/// `applicable_transformations`.
+pub fn apply_transformations(code: &str, transformations: Vec<Transformation>) -> String {
+  let mut transformed_code = code.to_string();
+
+  for transformation in applicable_transformations(code, transformations).iter().rev() {
+    transformed_code.replace_range(
+      transformation.start as usize..transformation.end as usize,
+      &transformation.replacement,
+    );
   }
 
-  let mut transformed_code = code;
-  let mut transformations = result.transformations;
-  transformations.sort_by(|a, b| b.start.cmp(&a.start));
+  transformed_code
+}
+
+/// The transformations that can be applied to `code`, sorted by start. Edits are picked back to
+/// front so earlier offsets stay valid; insertions sharing a position keep the order they were
+/// emitted in, and any edit overlapping an already picked one is skipped.
+fn applicable_transformations(
+  code: &str,
+  mut transformations: Vec<Transformation>,
+) -> Vec<Transformation> {
+  transformations.sort_by_key(|transformation| transformation.start);
+
+  let mut applicable = Vec::new();
+  let mut applied_start = code.len();
 
-  for transformation in &transformations {
+  for transformation in transformations.into_iter().rev() {
     let start = transformation.start as usize;
     let end = transformation.end as usize;
 
-    if start <= transformed_code.len() && end <= transformed_code.len() && start <= end {
-      let before = &transformed_code[..start];
-      let after = &transformed_code[end..];
-      transformed_code = format!("{}{}{}", before, transformation.replacement, after);
+    if start > end || end > applied_start {
+      continue;
     }
+
+    if !code.is_char_boundary(start) || !code.is_char_boundary(end) {
+      continue;
+    }
+
+    applied_start = start;
+    applicable.push(transformation);
   }
 
-  Ok(transformed_code)
+  applicable.reverse();
+  applicable
 }
@@ -1,16 +1,144 @@
 import type { PluginOption } from "vite";
 interface QwikAnalyzerOptions {
     debug?: boolean;
+    /**
+     * Parameter text inserted into `component$` arrows that call `isComponentPresent`
+     * but declare no props, e.g. `"props: PropsOf<typeof Root>"`. It must bind a single name,
+     * optionally annotated. Defaults to `"props"`.
+     */
+    propsParameter?: string;
+    /**
+     * Annotation of an inserted `propsParameter` in TypeScript files that doesn't carry its own:
+     * none (`"none"`, the default), `Record<string, unknown>` (`"record"`), the component's own
+     * props type such as `component$<RootProps>` or `RootProps` (`"declared"`), or a given type.
+     */
+    propsType?: "none" | "record" | "declared" | (string & {});
+    /**
+     * Extra file extensions to analyze and the syntax to parse them as, e.g. `{ qwik: "tsx" }`.
+     */
+    sourceExtensions?: Record<string, "js" | "jsx" | "ts" | "tsx">;
+    /**
+     * What to do when a Root already has a presence prop written by hand: keep it (`"respect"`,
+     * the default) or replace its value with the analyzed one (`"override"`).
+     */
+    existingProps?: "respect" | "override";
+    /**
+     * Where injected presence props go among a Root's attributes: after the user's attributes
+     * (`"last"`, the default) or right after the element name (`"first"`).
+     */
+    injectionPosition?: "first" | "last";
+    /**
+     * Files larger than this many bytes are skipped instead of analyzed, so generated or minified
+     * sources don't stall the build. Defaults to 2 MiB; `0` disables the limit.
+     */
+    maxSourceBytes?: number;
+    /**
+     * Directory that `~/` imports resolve against (`<projectRoot>/src`) and where `qwik-analyzer.json`
+     * is read from. Defaults to the nearest directory with a `package.json`, which in a monorepo
+     * may be an inner package rather than the intended root.
+     */
+    projectRoot?: string;
+    /**
+     * Leave a Root untouched when none of the components it checks for are present, instead of
+     * injecting all-`false` props. A missing prop already reads as absent. Defaults to `false`.
+     */
+    injectOnlyWhenPresent?: boolean;
+    /**
+     * Code each `isComponentPresent` call is rewritten to. `{arg}` is the original argument, `{prop}`
+     * the injected prop name and `{props}` the component's props binding, or `propsParameter`, e.g.
+     * `'isComponentPresent({arg}, {props}["{prop}"])'`. Defaults to
+     * `"isComponentPresent({arg}, {props}.{prop})"`.
+     */
+    presenceCallTemplate?: string;
+    /**
+     * Report presence checks the analyzer could only settle heuristically, such as a child rendered
+     * next to its Root instead of inside it, with a `QA008` diagnostic and leave their props
+     * uninjected. Defaults to `false`.
+     */
+    strictScoping?: boolean;
+    /**
+     * Follow Qwik's named slots: a child inside an element marked `q:slot="name"` only counts as
+     * present when the Root renders `<Slot name="name" />`. Defaults to `false`.
+     */
+    namedSlots?: boolean;
+    /**
+     * How injected presence props are named: `"readable"` (`__qwik_analyzer_has_Description`),
+     * `"hashed"` (a short stable token such as `__qa_96405e0e27`), `"data-attribute"`
+     * (`data-qwik-analyzer-has-description`), or a template with `{name}` and/or
+     * `{hash}` placeholders, e.g. `"qa_{hash}"`. Defaults to `"readable"`.
+     */
+    propNaming?: "readable" | "hashed" | "data-attribute" | (string & {});
+    /**
+     * Directory the analyzer may read from. Imports that resolve outside it are reported with a
+     * `QA010` diagnostic and never read, for analyzing untrusted code. Defaults to no limit.
+     */
+    sandboxRoot?: string;
+    /**
+     * Children always counted as present, named as the Root checks for them, e.g.
+     * `["Checkbox.Description"]`. An escape hatch for children provided at runtime, such as through
+     * a context, that static analysis can't see. Defaults to `[]`.
+     */
+    assumePresent?: string[];
+    /**
+     * Only analyze JSX elements rendering these components or namespaces, e.g. `["Checkbox"]` for
+     * every `Checkbox.*` part. Narrows the work on large pages that use one design system. Children
+     * rendered through components outside the list aren't seen. Defaults to all components.
+     */
+    onlyComponents?: string[];
+    /**
+     * Follow each injected prop, rewritten `isComponentPresent` call and added props parameter with
+     * a `/* qwik-analyzer: ... *\/` comment explaining it, e.g. `Description present`. Useful while
+     * debugging transformed output. Defaults to `false`.
+     */
+    annotateTransformations?: boolean;
+    /**
+     * Which side of a compound component to transform: `"definition"` only rewrites Root
+     * definitions (their `isComponentPresent` calls and props parameter), `"consumer"` only injects
+     * presence props into the Roots a file renders. Split the two between a library build and an
+     * app build. Defaults to `"both"`.
+     */
+    transformSide?: "definition" | "consumer" | "both";
+    /**
+     * How many levels of nested components are searched for a child, e.g. Root renders Panel
+     * renders Description. Defaults to 8.
+     */
+    maxComponentDepth?: number;
+    /**
+     * Parse the definition of each Root declared in `qwik-analyzer.json` and report injected
+     * props it never checks for with a `QA005` diagnostic. Defaults to `false`, since skipping
+     * that parse is what the manifest is for.
+     */
+    verifyManifest?: boolean;
+    /**
+     * Other packages `isComponentPresent` and `usePresence` may be imported from, e.g. a design
+     * system re-exporting them. Calls of same-named functions from any other package are left
+     * alone. `@jackshelton/qwik-analyzer` is always accepted, and so are local files re-exporting
+     * the functions from an accepted package.
+     */
+    runtimeModules?: string[];
 }
 export declare function debug(message: string): void;
 /**
  * Utility function to check if a component is present in the current component tree.
  * This function is analyzed at build time by qwik-analyzer.
  *
- * @param component - The component reference to check for
+ * @param component - The component reference to check for, or an array of references any of
+ *   which counts as present
  * @param injectedValue - Optional boolean value injected by qwik-analyzer at build time
  * @returns boolean indicating if the component is present
  */
 export declare function isComponentPresent<T>(component: unknown, injectedValue?: boolean): boolean;
+/**
+ * Hook form of `isComponentPresent` for components that read presence through `.value`.
+ * This function is analyzed at build time by qwik-analyzer.
+ *
+ * @param component - The component reference to check for, or an array of references any of
+ *   which counts as present
+ * @param injectedValue - Optional boolean value injected by qwik-analyzer at build time
+ * @returns a read-only holder whose `value` is the component's presence on first render
+ */
+export declare function usePresence<T>(component: unknown, injectedValue?: boolean): {
+    readonly value: boolean;
+};
 export default function qwikAnalyzer(options?: QwikAnalyzerOptions): PluginOption;
 export {};
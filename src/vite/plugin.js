@@ -7,6 +7,13 @@ export function debug(message) {
 class NAPIWrapper {
     _module = null;
     _loading = null;
+    _options = {};
+    configure(options) {
+        this._options = options;
+        if (this._module) {
+            this._module.configureAnalyzer(this._options);
+        }
+    }
     async getModule() {
         if (this._module) {
             return this._module;
@@ -16,6 +23,7 @@ class NAPIWrapper {
         }
         this._loading = this.loadModule();
         this._module = await this._loading;
+        this._module.configureAnalyzer(this._options);
         return this._module;
     }
     async loadModule() {
@@ -39,27 +47,36 @@ class NAPIWrapper {
             throw error;
         }
     }
-    async analyzeAndTransformCode(code, filePath) {
+    async transformWithDiagnostics(code, filePath) {
         const module = await this.getModule();
         debug(`NAPI module available functions: ${Object.keys(module).join(", ")}`);
-        if (typeof module.analyzeAndTransformCode !== "function") {
-            debug(`analyzeAndTransformCode is not a function, it's a ${typeof module.analyzeAndTransformCode}`);
-            throw new Error("analyzeAndTransformCode is not a function");
+        if (typeof module.transformWithDiagnostics !== "function") {
+            debug(`transformWithDiagnostics is not a function, it's a ${typeof module.transformWithDiagnostics}`);
+            throw new Error("transformWithDiagnostics is not a function");
         }
-        debug(`Calling analyzeAndTransformCode with file: ${filePath}`);
-        return module.analyzeAndTransformCode(code, filePath);
+        debug(`Calling transformWithDiagnostics with file: ${filePath}`);
+        return module.transformWithDiagnostics(code, filePath);
     }
     async analyzeFileChanged(filePath, event) {
         const module = await this.getModule();
         return module.analyzeFileChanged(filePath, event);
     }
+    async collectDependencies(filePath) {
+        const module = await this.getModule();
+        return module.collectDependencies(filePath);
+    }
+    async dependentsOf(path) {
+        const module = await this.getModule();
+        return module.dependentsOf(path);
+    }
 }
 const napiWrapper = new NAPIWrapper();
 /**
  * Utility function to check if a component is present in the current component tree.
  * This function is analyzed at build time by qwik-analyzer.
  *
- * @param component - The component reference to check for
+ * @param component - The component reference to check for, or an array of references any of
+ *   which counts as present
  * @param injectedValue - Optional boolean value injected by qwik-analyzer at build time
  * @returns boolean indicating if the component is present
  */
@@ -69,21 +86,67 @@ export function isComponentPresent(component, injectedValue) {
     }
     return false;
 }
+/**
+ * Hook form of `isComponentPresent` for components that read presence through `.value`.
+ * This function is analyzed at build time by qwik-analyzer.
+ *
+ * @param component - The component reference to check for, or an array of references any of
+ *   which counts as present
+ * @param injectedValue - Optional boolean value injected by qwik-analyzer at build time
+ * @returns a read-only holder whose `value` is the component's presence on first render
+ */
+export function usePresence(component, injectedValue) {
+    return { value: isComponentPresent(component, injectedValue) };
+}
 export default function qwikAnalyzer(options = {}) {
     isDebugMode = options.debug ?? false;
+    napiWrapper.configure({
+        propsParameter: options.propsParameter,
+        propsType: options.propsType,
+        sourceExtensions: options.sourceExtensions,
+        existingProps: options.existingProps,
+        injectionPosition: options.injectionPosition,
+        maxSourceBytes: options.maxSourceBytes,
+        projectRoot: options.projectRoot,
+        injectOnlyWhenPresent: options.injectOnlyWhenPresent,
+        presenceCallTemplate: options.presenceCallTemplate,
+        strictScoping: options.strictScoping,
+        namedSlots: options.namedSlots,
+        propNaming: options.propNaming,
+        sandboxRoot: options.sandboxRoot,
+        assumePresent: options.assumePresent,
+        onlyComponents: options.onlyComponents,
+        annotateTransformations: options.annotateTransformations,
+        transformSide: options.transformSide,
+        maxComponentDepth: options.maxComponentDepth,
+        verifyManifest: options.verifyManifest,
+        runtimeModules: options.runtimeModules,
+    });
+    const extensions = [
+        ".tsx",
+        ".ts",
+        ...Object.keys(options.sourceExtensions ?? {}).map((extension) => `.${extension.replace(/^\./, "")}`),
+    ];
     return {
         name: "qwik-analyzer",
         enforce: "pre",
         async transform(code, id) {
             const cleanedId = id.split("?")[0];
-            if ((!cleanedId.endsWith(".tsx") && !cleanedId.endsWith(".ts")) ||
+            if (!extensions.some((extension) => cleanedId.endsWith(extension)) ||
                 cleanedId.includes("node_modules")) {
                 return null;
             }
             debug(`Transforming ${cleanedId}`);
             try {
                 console.log("Analyzing and transforming code");
-                const transformedCode = await napiWrapper.analyzeAndTransformCode(code, cleanedId);
+                const { code: transformedCode, diagnostics } = await napiWrapper.transformWithDiagnostics(code, cleanedId);
+                for (const diagnostic of diagnostics) {
+                    this.warn(`${diagnostic.code}: ${diagnostic.message}`);
+                }
+                // A change to a component library this file renders can change its transformation
+                for (const dependency of await napiWrapper.collectDependencies(cleanedId)) {
+                    this.addWatchFile(dependency);
+                }
                 if (transformedCode !== code) {
                     debug(`Transformed ${cleanedId}`);
                     return {
@@ -97,16 +160,16 @@ export default function qwikAnalyzer(options = {}) {
             }
             return null;
         },
-        watchChange(id) {
+        watchChange(id, change) {
             debug(`File changed: ${id}`);
             try {
-                napiWrapper.analyzeFileChanged(id, "change");
+                napiWrapper.analyzeFileChanged(id, change.event);
             }
             catch (error) {
                 debug(`Error processing file change: ${error}`);
             }
         },
-        handleHotUpdate(ctx) {
+        async handleHotUpdate(ctx) {
             const { file, server } = ctx;
             const module = server.moduleGraph.getModuleById(file);
             if (module) {
@@ -115,6 +178,23 @@ export default function qwikAnalyzer(options = {}) {
                     debug(`Invalidated importer: ${importer.id}`);
                 }
             }
+            // Pages rendering a Root whose children live in the changed file need new presence props,
+            // even when they don't import it
+            const dependents = [];
+            try {
+                for (const dependent of await napiWrapper.dependentsOf(file)) {
+                    const dependentModule = server.moduleGraph.getModuleById(dependent);
+                    if (dependentModule) {
+                        server.moduleGraph.invalidateModule(dependentModule);
+                        dependents.push(dependentModule);
+                        debug(`Invalidated dependent: ${dependent}`);
+                    }
+                }
+            }
+            catch (error) {
+                debug(`Error finding dependents of ${file}: ${error}`);
+            }
+            return dependents.length > 0 ? [...ctx.modules, ...dependents] : undefined;
         },
     };
 }
@@ -1,12 +1,166 @@
-import type { PluginOption } from "vite";
+import type { ModuleNode, PluginOption } from "vite";
 
 interface QwikAnalyzerOptions {
 	debug?: boolean;
+	/**
+	 * Parameter text inserted into `component$` arrows that call `isComponentPresent`
+	 * but declare no props, e.g. `"props: PropsOf<typeof Root>"`. It must bind a single name,
+	 * optionally annotated. Defaults to `"props"`.
+	 */
+	propsParameter?: string;
+	/**
+	 * Annotation of an inserted `propsParameter` in TypeScript files that doesn't carry its own:
+	 * none (`"none"`, the default), `Record<string, unknown>` (`"record"`), the component's own
+	 * props type such as `component$<RootProps>` or `RootProps` (`"declared"`), or a given type.
+	 */
+	propsType?: "none" | "record" | "declared" | (string & {});
+	/**
+	 * Extra file extensions to analyze and the syntax to parse them as, e.g. `{ qwik: "tsx" }`.
+	 */
+	sourceExtensions?: Record<string, "js" | "jsx" | "ts" | "tsx">;
+	/**
+	 * What to do when a Root already has a presence prop written by hand: keep it (`"respect"`,
+	 * the default) or replace its value with the analyzed one (`"override"`).
+	 */
+	existingProps?: "respect" | "override";
+	/**
+	 * Where injected presence props go among a Root's attributes: after the user's attributes
+	 * (`"last"`, the default) or right after the element name (`"first"`).
+	 */
+	injectionPosition?: "first" | "last";
+	/**
+	 * Files larger than this many bytes are skipped instead of analyzed, so generated or minified
+	 * sources don't stall the build. Defaults to 2 MiB; `0` disables the limit.
+	 */
+	maxSourceBytes?: number;
+	/**
+	 * Directory that `~/` imports resolve against (`<projectRoot>/src`) and where `qwik-analyzer.json`
+	 * is read from. Defaults to the nearest directory with a `package.json`, which in a monorepo
+	 * may be an inner package rather than the intended root.
+	 */
+	projectRoot?: string;
+	/**
+	 * Leave a Root untouched when none of the components it checks for are present, instead of
+	 * injecting all-`false` props. A missing prop already reads as absent. Defaults to `false`.
+	 */
+	injectOnlyWhenPresent?: boolean;
+	/**
+	 * Code each `isComponentPresent` call is rewritten to. `{arg}` is the original argument, `{prop}`
+	 * the injected prop name and `{props}` the component's props binding, or `propsParameter`, e.g.
+	 * `'isComponentPresent({arg}, {props}["{prop}"])'`. Defaults to
+	 * `"isComponentPresent({arg}, {props}.{prop})"`.
+	 */
+	presenceCallTemplate?: string;
+	/**
+	 * Report presence checks the analyzer could only settle heuristically, such as a child rendered
+	 * next to its Root instead of inside it, with a `QA008` diagnostic and leave their props
+	 * uninjected. Defaults to `false`.
+	 */
+	strictScoping?: boolean;
+	/**
+	 * Follow Qwik's named slots: a child inside an element marked `q:slot="name"` only counts as
+	 * present when the Root renders `<Slot name="name" />`. Defaults to `false`.
+	 */
+	namedSlots?: boolean;
+	/**
+	 * How injected presence props are named: `"readable"` (`__qwik_analyzer_has_Description`),
+	 * `"hashed"` (a short stable token such as `__qa_96405e0e27`), `"data-attribute"`
+	 * (`data-qwik-analyzer-has-description`), or a template with `{name}` and/or
+	 * `{hash}` placeholders, e.g. `"qa_{hash}"`. Defaults to `"readable"`.
+	 */
+	propNaming?: "readable" | "hashed" | "data-attribute" | (string & {});
+	/**
+	 * Directory the analyzer may read from. Imports that resolve outside it are reported with a
+	 * `QA010` diagnostic and never read, for analyzing untrusted code. Defaults to no limit.
+	 */
+	sandboxRoot?: string;
+	/**
+	 * Children always counted as present, named as the Root checks for them, e.g.
+	 * `["Checkbox.Description"]`. An escape hatch for children provided at runtime, such as through
+	 * a context, that static analysis can't see. Defaults to `[]`.
+	 */
+	assumePresent?: string[];
+	/**
+	 * Only analyze JSX elements rendering these components or namespaces, e.g. `["Checkbox"]` for
+	 * every `Checkbox.*` part. Narrows the work on large pages that use one design system. Children
+	 * rendered through components outside the list aren't seen. Defaults to all components.
+	 */
+	onlyComponents?: string[];
+	/**
+	 * Follow each injected prop, rewritten `isComponentPresent` call and added props parameter with
+	 * a `/* qwik-analyzer: ... *\/` comment explaining it, e.g. `Description present`. Useful while
+	 * debugging transformed output. Defaults to `false`.
+	 */
+	annotateTransformations?: boolean;
+	/**
+	 * Which side of a compound component to transform: `"definition"` only rewrites Root
+	 * definitions (their `isComponentPresent` calls and props parameter), `"consumer"` only injects
+	 * presence props into the Roots a file renders. Split the two between a library build and an
+	 * app build. Defaults to `"both"`.
+	 */
+	transformSide?: "definition" | "consumer" | "both";
+	/**
+	 * How many levels of nested components are searched for a child, e.g. Root renders Panel
+	 * renders Description. Defaults to 8.
+	 */
+	maxComponentDepth?: number;
+	/**
+	 * Parse the definition of each Root declared in `qwik-analyzer.json` and report injected
+	 * props it never checks for with a `QA005` diagnostic. Defaults to `false`, since skipping
+	 * that parse is what the manifest is for.
+	 */
+	verifyManifest?: boolean;
+	/**
+	 * Other packages `isComponentPresent` and `usePresence` may be imported from, e.g. a design
+	 * system re-exporting them. Calls of same-named functions from any other package are left
+	 * alone. `@jackshelton/qwik-analyzer` is always accepted, and so are local files re-exporting
+	 * the functions from an accepted package.
+	 */
+	runtimeModules?: string[];
+}
+
+interface NAPIAnalyzerOptions {
+	propsParameter?: string;
+	propsType?: string;
+	sourceExtensions?: Record<string, string>;
+	existingProps?: string;
+	injectionPosition?: string;
+	maxSourceBytes?: number;
+	projectRoot?: string;
+	injectOnlyWhenPresent?: boolean;
+	presenceCallTemplate?: string;
+	strictScoping?: boolean;
+	namedSlots?: boolean;
+	propNaming?: string;
+	sandboxRoot?: string;
+	assumePresent?: string[];
+	onlyComponents?: string[];
+	annotateTransformations?: boolean;
+	transformSide?: string;
+	maxComponentDepth?: number;
+	verifyManifest?: boolean;
+	runtimeModules?: string[];
+}
+
+interface NAPIDiagnostic {
+	code: string;
+	message: string;
+	filePath: string;
+	start: number;
+	end: number;
+}
+
+interface NAPITransformOutput {
+	code: string;
+	diagnostics: NAPIDiagnostic[];
 }
 
 interface NAPIModule {
-	analyzeAndTransformCode: (code: string, filePath: string) => string;
+	configureAnalyzer: (options: NAPIAnalyzerOptions) => void;
+	transformWithDiagnostics: (code: string, filePath: string) => NAPITransformOutput;
 	analyzeFileChanged: (filePath: string, event: string) => void;
+	collectDependencies: (filePath: string) => string[];
+	dependentsOf: (path: string) => string[];
 }
 
 let isDebugMode = false;
@@ -20,6 +174,14 @@ export function debug(message: string): void {
 class NAPIWrapper {
 	private _module: NAPIModule | null = null;
 	private _loading: Promise<NAPIModule> | null = null;
+	private _options: NAPIAnalyzerOptions = {};
+
+	configure(options: NAPIAnalyzerOptions): void {
+		this._options = options;
+		if (this._module) {
+			this._module.configureAnalyzer(this._options);
+		}
+	}
 
 	async getModule(): Promise<NAPIModule> {
 		if (this._module) {
@@ -32,6 +194,7 @@ class NAPIWrapper {
 
 		this._loading = this.loadModule();
 		this._module = await this._loading;
+		this._module.configureAnalyzer(this._options);
 		return this._module;
 	}
 
@@ -57,28 +220,38 @@ class NAPIWrapper {
 		}
 	}
 
-	async analyzeAndTransformCode(
+	async transformWithDiagnostics(
 		code: string,
 		filePath: string,
-	): Promise<string> {
+	): Promise<NAPITransformOutput> {
 		const module = await this.getModule();
 		debug(`NAPI module available functions: ${Object.keys(module).join(", ")}`);
 
-		if (typeof module.analyzeAndTransformCode !== "function") {
+		if (typeof module.transformWithDiagnostics !== "function") {
 			debug(
-				`analyzeAndTransformCode is not a function, it's a ${typeof module.analyzeAndTransformCode}`,
+				`transformWithDiagnostics is not a function, it's a ${typeof module.transformWithDiagnostics}`,
 			);
-			throw new Error("analyzeAndTransformCode is not a function");
+			throw new Error("transformWithDiagnostics is not a function");
 		}
 
-		debug(`Calling analyzeAndTransformCode with file: ${filePath}`);
-		return module.analyzeAndTransformCode(code, filePath);
+		debug(`Calling transformWithDiagnostics with file: ${filePath}`);
+		return module.transformWithDiagnostics(code, filePath);
 	}
 
 	async analyzeFileChanged(filePath: string, event: string): Promise<void> {
 		const module = await this.getModule();
 		return module.analyzeFileChanged(filePath, event);
 	}
+
+	async collectDependencies(filePath: string): Promise<string[]> {
+		const module = await this.getModule();
+		return module.collectDependencies(filePath);
+	}
+
+	async dependentsOf(path: string): Promise<string[]> {
+		const module = await this.getModule();
+		return module.dependentsOf(path);
+	}
 }
 
 const napiWrapper = new NAPIWrapper();
@@ -87,7 +260,8 @@ const napiWrapper = new NAPIWrapper();
  * Utility function to check if a component is present in the current component tree.
  * This function is analyzed at build time by qwik-analyzer.
  *
- * @param component - The component reference to check for
+ * @param component - The component reference to check for, or an array of references any of
+ *   which counts as present
  * @param injectedValue - Optional boolean value injected by qwik-analyzer at build time
  * @returns boolean indicating if the component is present
  */
@@ -102,10 +276,56 @@ export function isComponentPresent<T>(
 	return false;
 }
 
+/**
+ * Hook form of `isComponentPresent` for components that read presence through `.value`.
+ * This function is analyzed at build time by qwik-analyzer.
+ *
+ * @param component - The component reference to check for, or an array of references any of
+ *   which counts as present
+ * @param injectedValue - Optional boolean value injected by qwik-analyzer at build time
+ * @returns a read-only holder whose `value` is the component's presence on first render
+ */
+export function usePresence<T>(
+	component: unknown,
+	injectedValue?: boolean,
+): { readonly value: boolean } {
+	return { value: isComponentPresent(component, injectedValue) };
+}
+
 export default function qwikAnalyzer(
 	options: QwikAnalyzerOptions = {},
 ): PluginOption {
 	isDebugMode = options.debug ?? false;
+	napiWrapper.configure({
+		propsParameter: options.propsParameter,
+		propsType: options.propsType,
+		sourceExtensions: options.sourceExtensions,
+		existingProps: options.existingProps,
+		injectionPosition: options.injectionPosition,
+		maxSourceBytes: options.maxSourceBytes,
+		projectRoot: options.projectRoot,
+		injectOnlyWhenPresent: options.injectOnlyWhenPresent,
+		presenceCallTemplate: options.presenceCallTemplate,
+		strictScoping: options.strictScoping,
+		namedSlots: options.namedSlots,
+		propNaming: options.propNaming,
+		sandboxRoot: options.sandboxRoot,
+		assumePresent: options.assumePresent,
+		onlyComponents: options.onlyComponents,
+		annotateTransformations: options.annotateTransformations,
+		transformSide: options.transformSide,
+		maxComponentDepth: options.maxComponentDepth,
+		verifyManifest: options.verifyManifest,
+		runtimeModules: options.runtimeModules,
+	});
+
+	const extensions = [
+		".tsx",
+		".ts",
+		...Object.keys(options.sourceExtensions ?? {}).map(
+			(extension) => `.${extension.replace(/^\./, "")}`,
+		),
+	];
 
 	return {
 		name: "qwik-analyzer",
@@ -115,7 +335,7 @@ export default function qwikAnalyzer(
 			const cleanedId = id.split("?")[0];
 
 			if (
-				(!cleanedId.endsWith(".tsx") && !cleanedId.endsWith(".ts")) ||
+				!extensions.some((extension) => cleanedId.endsWith(extension)) ||
 				cleanedId.includes("node_modules")
 			) {
 				return null;
@@ -125,10 +345,17 @@ export default function qwikAnalyzer(
 
 			try {
 				console.log("Analyzing and transforming code");
-				const transformedCode = await napiWrapper.analyzeAndTransformCode(
-					code,
-					cleanedId,
-				);
+				const { code: transformedCode, diagnostics } =
+					await napiWrapper.transformWithDiagnostics(code, cleanedId);
+
+				for (const diagnostic of diagnostics) {
+					this.warn(`${diagnostic.code}: ${diagnostic.message}`);
+				}
+
+				// A change to a component library this file renders can change its transformation
+				for (const dependency of await napiWrapper.collectDependencies(cleanedId)) {
+					this.addWatchFile(dependency);
+				}
 
 				if (transformedCode !== code) {
 					debug(`Transformed ${cleanedId}`);
@@ -144,17 +371,17 @@ export default function qwikAnalyzer(
 			return null;
 		},
 
-		watchChange(id) {
+		watchChange(id, change) {
 			debug(`File changed: ${id}`);
 
 			try {
-				napiWrapper.analyzeFileChanged(id, "change");
+				napiWrapper.analyzeFileChanged(id, change.event);
 			} catch (error) {
 				debug(`Error processing file change: ${error}`);
 			}
 		},
 
-		handleHotUpdate(ctx) {
+		async handleHotUpdate(ctx) {
 			const { file, server } = ctx;
 
 			const module = server.moduleGraph.getModuleById(file);
@@ -164,6 +391,24 @@ export default function qwikAnalyzer(
 					debug(`Invalidated importer: ${importer.id}`);
 				}
 			}
+
+			// Pages rendering a Root whose children live in the changed file need new presence props,
+			// even when they don't import it
+			const dependents: ModuleNode[] = [];
+			try {
+				for (const dependent of await napiWrapper.dependentsOf(file)) {
+					const dependentModule = server.moduleGraph.getModuleById(dependent);
+					if (dependentModule) {
+						server.moduleGraph.invalidateModule(dependentModule);
+						dependents.push(dependentModule);
+						debug(`Invalidated dependent: ${dependent}`);
+					}
+				}
+			} catch (error) {
+				debug(`Error finding dependents of ${file}: ${error}`);
+			}
+
+			return dependents.length > 0 ? [...ctx.modules, ...dependents] : undefined;
 		},
 	};
 }